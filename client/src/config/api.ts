@@ -0,0 +1,72 @@
+// Read path for game history and stats: the morra-api service, serving the
+// indexer's database. Games are looked up by their PDA, so the Firestore
+// game flow keeps reading its own documents until it moves on chain.
+export const API_URL: string =
+  import.meta.env.VITE_MORRA_API_URL ?? 'http://127.0.0.1:3000';
+
+export type IndexedGameStatus =
+  | 'waiting_for_opponent'
+  | 'waiting_for_commits'
+  | 'waiting_for_reveal'
+  | 'draw'
+  | 'completed'
+  | 'settled'
+  | 'cancelled';
+
+export interface IndexedMove {
+  seat: 'creator' | 'opponent';
+  player: string;
+  committed: boolean;
+  revealed: { card: number; prediction: number } | null;
+}
+
+export interface IndexedGame {
+  address: string;
+  creator: string;
+  game_id: number;
+  opponent: string | null;
+  bet_amount: number;
+  ranked: boolean;
+  status: IndexedGameStatus;
+  winner: string | null;
+  created_at: number;
+  last_action_at: number;
+  moves: IndexedMove[];
+}
+
+export interface PlayerStats {
+  player: string;
+  games_played: number;
+  wins: number;
+  losses: number;
+  draws: number;
+  forfeits: number;
+  total_wagered: number;
+  net_winnings: number;
+  current_streak: number;
+  rating: number;
+}
+
+async function get<T>(path: string): Promise<T> {
+  const response = await fetch(`${API_URL}${path}`);
+  if (!response.ok) {
+    const body = await response.json().catch(() => ({}));
+    throw new Error(body.error ?? `${response.status} ${response.statusText}`);
+  }
+  return response.json();
+}
+
+export function fetchGames(filter: { status?: IndexedGameStatus; creator?: string } = {}) {
+  const query = new URLSearchParams(
+    Object.entries(filter).filter((entry): entry is [string, string] => !!entry[1])
+  );
+  return get<IndexedGame[]>(`/games?${query}`);
+}
+
+export const fetchGame = (pda: string) => get<IndexedGame>(`/games/${pda}`);
+
+export const fetchPlayerStats = (pubkey: string) =>
+  get<PlayerStats>(`/players/${pubkey}/stats`);
+
+export const fetchLeaderboard = () =>
+  get<(PlayerStats & { rank: number })[]>('/leaderboard');
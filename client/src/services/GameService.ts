@@ -8,6 +8,7 @@ import {
   updateDoc,
   onSnapshot
 } from 'firebase/firestore';
+import { fetchLeaderboard, fetchPlayerStats } from '../config/api';
 import { GameState, GameConfig } from '../types/game';
 
 export class GameService {
@@ -134,6 +135,17 @@ export class GameService {
     }
   }
 
+  // Stats are keyed by wallet, so they come from the API. Games stay on
+  // Firestore, keyed by document id, until moves are sent to the program
+  // and games can be looked up by their PDA.
+  getPlayerStats(player: string) {
+    return fetchPlayerStats(player);
+  }
+
+  getLeaderboard() {
+    return fetchLeaderboard();
+  }
+
   subscribeToGameState(gameId: string, callback: (state: GameState) => void): () => void {
     const gameRef = doc(db, 'games', gameId);
     return onSnapshot(gameRef, (doc) => {
@@ -0,0 +1,8 @@
+//! Read-only HTTP API over the tables `morra-indexer` maintains, so
+//! clients can list games and stats without scanning the chain.
+
+mod queries;
+mod routes;
+
+pub use queries::*;
+pub use routes::*;
@@ -0,0 +1,32 @@
+//! Serves the indexer's database over HTTP.
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Parser;
+use rusqlite::Connection;
+
+#[derive(Parser)]
+#[command(about = "Serve indexed Morra games over HTTP")]
+struct Args {
+    /// Database `morra-indexer` writes to.
+    #[arg(long, default_value = "morra.db")]
+    database: PathBuf,
+    #[arg(long, default_value = "127.0.0.1:3000")]
+    listen: SocketAddr,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let conn = Connection::open(&args.database)?;
+    // Wait out the indexer's writes instead of failing reads.
+    conn.busy_timeout(Duration::from_secs(5))?;
+    conn.execute_batch(morra_indexer::SCHEMA)?;
+
+    let listener = tokio::net::TcpListener::bind(args.listen).await?;
+    println!("serving {} on {}", args.database.display(), args.listen);
+    axum::serve(listener, morra_api::router(conn)).await?;
+    Ok(())
+}
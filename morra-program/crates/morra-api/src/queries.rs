@@ -0,0 +1,204 @@
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::Serialize;
+
+/// Most rows a list endpoint returns, whatever `limit` asks for.
+pub const MAX_LIMIT: u32 = 200;
+pub const DEFAULT_LIMIT: u32 = 50;
+
+#[derive(Debug, Serialize)]
+pub struct GameView {
+    pub address: String,
+    pub creator: String,
+    pub game_id: u64,
+    pub opponent: Option<String>,
+    pub bet_amount: u64,
+    pub ranked: bool,
+    pub status: String,
+    pub winner: Option<String>,
+    pub created_at: i64,
+    pub last_action_at: i64,
+    pub moves: Vec<MoveView>,
+}
+
+/// One seat's move. The card and prediction stay hidden until the player
+/// reveals them on chain.
+#[derive(Debug, Serialize)]
+pub struct MoveView {
+    pub seat: String,
+    pub player: String,
+    pub committed: bool,
+    pub revealed: Option<Reveal>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct Reveal {
+    pub card: u8,
+    pub prediction: u8,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GameDetail {
+    #[serde(flatten)]
+    pub game: GameView,
+    pub payouts: Vec<PayoutView>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PayoutView {
+    pub recipient: String,
+    pub kind: String,
+    pub amount: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlayerStats {
+    pub player: String,
+    pub games_played: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub draws: u64,
+    pub forfeits: u64,
+    pub total_wagered: u64,
+    pub net_winnings: i64,
+    pub current_streak: i32,
+    pub rating: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    #[serde(flatten)]
+    pub stats: PlayerStats,
+}
+
+const GAME_COLUMNS: &str = "address, creator, game_id, opponent, bet_amount, ranked, status,
+    winner, created_at, last_action_at";
+
+const PLAYER_COLUMNS: &str = "player, games_played, wins, losses, draws, forfeits,
+    total_wagered, net_winnings, current_streak, rating";
+
+fn game_row(row: &Row) -> rusqlite::Result<GameView> {
+    Ok(GameView {
+        address: row.get(0)?,
+        creator: row.get(1)?,
+        game_id: row.get(2)?,
+        opponent: row.get(3)?,
+        bet_amount: row.get(4)?,
+        ranked: row.get(5)?,
+        status: row.get(6)?,
+        winner: row.get(7)?,
+        created_at: row.get(8)?,
+        last_action_at: row.get(9)?,
+        moves: Vec::new(),
+    })
+}
+
+fn player_row(row: &Row) -> rusqlite::Result<PlayerStats> {
+    Ok(PlayerStats {
+        player: row.get(0)?,
+        games_played: row.get(1)?,
+        wins: row.get(2)?,
+        losses: row.get(3)?,
+        draws: row.get(4)?,
+        forfeits: row.get(5)?,
+        total_wagered: row.get(6)?,
+        net_winnings: row.get(7)?,
+        current_streak: row.get(8)?,
+        rating: row.get(9)?,
+    })
+}
+
+fn with_moves(conn: &Connection, mut game: GameView) -> rusqlite::Result<GameView> {
+    let mut statement = conn.prepare_cached(
+        "SELECT seat, player, commitment, card, prediction FROM moves
+         WHERE game = ?1 ORDER BY seat",
+    )?;
+    game.moves = statement
+        .query_map([&game.address], |row| {
+            let card: Option<u8> = row.get(3)?;
+            let prediction: Option<u8> = row.get(4)?;
+            Ok(MoveView {
+                seat: row.get(0)?,
+                player: row.get(1)?,
+                committed: row.get::<_, Option<String>>(2)?.is_some(),
+                revealed: card
+                    .zip(prediction)
+                    .map(|(card, prediction)| Reveal { card, prediction }),
+            })
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+    Ok(game)
+}
+
+/// Newest games first, optionally narrowed to one status or creator.
+pub fn list_games(
+    conn: &Connection,
+    status: Option<&str>,
+    creator: Option<&str>,
+    limit: u32,
+) -> rusqlite::Result<Vec<GameView>> {
+    let mut statement = conn.prepare_cached(&format!(
+        "SELECT {GAME_COLUMNS} FROM games
+         WHERE (?1 IS NULL OR status = ?1) AND (?2 IS NULL OR creator = ?2)
+         ORDER BY created_at DESC, address LIMIT ?3"
+    ))?;
+    let games = statement
+        .query_map(params![status, creator, limit], game_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    games.into_iter().map(|game| with_moves(conn, game)).collect()
+}
+
+pub fn game_detail(conn: &Connection, address: &str) -> rusqlite::Result<Option<GameDetail>> {
+    let game = conn
+        .query_row(
+            &format!("SELECT {GAME_COLUMNS} FROM games WHERE address = ?1"),
+            [address],
+            game_row,
+        )
+        .optional()?;
+    let Some(game) = game else {
+        return Ok(None);
+    };
+    let mut statement = conn.prepare_cached(
+        "SELECT recipient, kind, amount FROM payouts WHERE game = ?1 ORDER BY kind DESC",
+    )?;
+    let payouts = statement
+        .query_map([address], |row| {
+            Ok(PayoutView {
+                recipient: row.get(0)?,
+                kind: row.get(1)?,
+                amount: row.get(2)?,
+            })
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+    Ok(Some(GameDetail {
+        game: with_moves(conn, game)?,
+        payouts,
+    }))
+}
+
+pub fn player_stats(conn: &Connection, player: &str) -> rusqlite::Result<Option<PlayerStats>> {
+    conn.query_row(
+        &format!("SELECT {PLAYER_COLUMNS} FROM players WHERE player = ?1"),
+        [player],
+        player_row,
+    )
+    .optional()
+}
+
+/// Players who have finished a game, by rating, then wins.
+pub fn leaderboard(conn: &Connection, limit: u32) -> rusqlite::Result<Vec<LeaderboardEntry>> {
+    let mut statement = conn.prepare_cached(&format!(
+        "SELECT {PLAYER_COLUMNS} FROM players WHERE games_played > 0
+         ORDER BY rating DESC, wins DESC, player LIMIT ?1"
+    ))?;
+    let rows = statement.query_map([limit], player_row)?;
+    rows.enumerate()
+        .map(|(index, stats)| {
+            Ok(LeaderboardEntry {
+                rank: index + 1,
+                stats: stats?,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,124 @@
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use morra_indexer::status_name;
+use morra_program::GameStatus;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+use crate::queries::{self, DEFAULT_LIMIT, MAX_LIMIT};
+
+type Db = Arc<Mutex<Connection>>;
+
+#[derive(Debug)]
+pub enum ApiError {
+    BadRequest(String),
+    NotFound,
+    Database(rusqlite::Error),
+}
+
+impl From<rusqlite::Error> for ApiError {
+    fn from(err: rusqlite::Error) -> Self {
+        Self::Database(err)
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error) = match self {
+            Self::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
+            Self::NotFound => (StatusCode::NOT_FOUND, "not found".to_string()),
+            Self::Database(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+        };
+        (status, Json(ErrorBody { error })).into_response()
+    }
+}
+
+/// Pubkeys are stored base58; parse them first so malformed ones are a 400
+/// rather than an empty result.
+fn pubkey(value: &str) -> Result<String, ApiError> {
+    Pubkey::from_str(value)
+        .map(|key| key.to_string())
+        .map_err(|_| ApiError::BadRequest(format!("invalid pubkey: {value}")))
+}
+
+fn limit(requested: Option<u32>) -> u32 {
+    requested.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT)
+}
+
+#[derive(Deserialize)]
+pub struct GameFilter {
+    pub status: Option<String>,
+    pub creator: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[derive(Deserialize)]
+pub struct Page {
+    pub limit: Option<u32>,
+}
+
+async fn list_games(
+    State(db): State<Db>,
+    Query(filter): Query<GameFilter>,
+) -> Result<Json<Vec<queries::GameView>>, ApiError> {
+    let status = filter.status.as_deref();
+    if let Some(status) = status {
+        if !GameStatus::ALL.iter().any(|known| status_name(*known) == status) {
+            return Err(ApiError::BadRequest(format!("unknown status: {status}")));
+        }
+    }
+    let creator = filter.creator.as_deref().map(pubkey).transpose()?;
+    let conn = db.lock().unwrap();
+    let games = queries::list_games(&conn, status, creator.as_deref(), limit(filter.limit))?;
+    Ok(Json(games))
+}
+
+async fn game(
+    State(db): State<Db>,
+    Path(address): Path<String>,
+) -> Result<Json<queries::GameDetail>, ApiError> {
+    let address = pubkey(&address)?;
+    let conn = db.lock().unwrap();
+    let game = queries::game_detail(&conn, &address)?.ok_or(ApiError::NotFound)?;
+    Ok(Json(game))
+}
+
+async fn player_stats(
+    State(db): State<Db>,
+    Path(player): Path<String>,
+) -> Result<Json<queries::PlayerStats>, ApiError> {
+    let player = pubkey(&player)?;
+    let conn = db.lock().unwrap();
+    let stats = queries::player_stats(&conn, &player)?.ok_or(ApiError::NotFound)?;
+    Ok(Json(stats))
+}
+
+async fn leaderboard(
+    State(db): State<Db>,
+    Query(page): Query<Page>,
+) -> Result<Json<Vec<queries::LeaderboardEntry>>, ApiError> {
+    let conn = db.lock().unwrap();
+    Ok(Json(queries::leaderboard(&conn, limit(page.limit))?))
+}
+
+/// The API's routes over a connection to the indexer's database.
+pub fn router(conn: Connection) -> Router {
+    Router::new()
+        .route("/games", get(list_games))
+        .route("/games/{pda}", get(game))
+        .route("/players/{pubkey}/stats", get(player_stats))
+        .route("/leaderboard", get(leaderboard))
+        .with_state(Arc::new(Mutex::new(conn)))
+}
@@ -0,0 +1,191 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use morra_indexer::Store;
+use morra_program::{Game, GameStatus, PlayerMove, PlayerProfile};
+use serde_json::{json, Value};
+use solana_pubkey::Pubkey;
+use tower::ServiceExt;
+
+const BET: u64 = 1_000_000_000;
+
+fn game(creator: Pubkey, status: GameStatus, created_at: i64) -> Game {
+    Game {
+        creator,
+        game_id: 0,
+        bump: 0,
+        opponent: Pubkey::new_unique(),
+        bet_amount: BET,
+        ranked: true,
+        lobby_page: 0,
+        status,
+        creator_move: PlayerMove {
+            committed_hash: Some([1; 32]),
+            card: Some(3),
+            prediction: Some(5),
+        },
+        opponent_move: PlayerMove::committed([2; 32]),
+        winner: None,
+        created_at,
+        last_action_at: created_at,
+        creator_sponsor: None,
+        opponent_sponsor: None,
+        queue: None,
+    }
+}
+
+struct Fixture {
+    store: Store,
+    creator: Pubkey,
+    settled: Pubkey,
+}
+
+fn fixture() -> Fixture {
+    let mut store = Store::open_in_memory().unwrap();
+    let creator = Pubkey::new_unique();
+
+    let waiting = game(creator, GameStatus::WaitingForReveal, 20);
+    store.upsert_game(&Pubkey::new_unique(), &waiting, 1).unwrap();
+
+    let settled = Pubkey::new_unique();
+    let mut won = game(creator, GameStatus::Settled, 10);
+    won.opponent_move.card = Some(2);
+    won.opponent_move.prediction = Some(7);
+    won.winner = Some(creator);
+    store.upsert_game(&settled, &won, 1).unwrap();
+
+    let other = game(Pubkey::new_unique(), GameStatus::WaitingForReveal, 30);
+    store.upsert_game(&Pubkey::new_unique(), &other, 1).unwrap();
+
+    for (rating, wins) in [(1_100, 3), (1_300, 1), (1_100, 5)] {
+        let mut profile = PlayerProfile::new(Pubkey::new_unique(), 0);
+        profile.rating = rating;
+        for _ in 0..wins {
+            profile.record_win(BET);
+        }
+        store.upsert_profile(&profile, 1).unwrap();
+    }
+    let mut profile = PlayerProfile::new(creator, 0);
+    profile.record_win(BET);
+    store.upsert_profile(&profile, 1).unwrap();
+    store
+        .upsert_profile(&PlayerProfile::new(Pubkey::new_unique(), 0), 1)
+        .unwrap();
+
+    Fixture {
+        store,
+        creator,
+        settled,
+    }
+}
+
+async fn get(store: Store, uri: &str) -> (StatusCode, Value) {
+    let response = morra_api::router(store.into_connection())
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn games_filter_by_status_and_creator() {
+    let fixture = fixture();
+    let uri = format!("/games?status=waiting_for_reveal&creator={}", fixture.creator);
+
+    let (status, body) = get(fixture.store, &uri).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let games = body.as_array().unwrap();
+    assert_eq!(games.len(), 1);
+    assert_eq!(games[0]["status"], "waiting_for_reveal");
+    assert_eq!(games[0]["creator"], fixture.creator.to_string());
+}
+
+#[tokio::test]
+async fn games_list_newest_first_within_limit() {
+    let (status, body) = get(fixture().store, "/games?limit=2").await;
+
+    assert_eq!(status, StatusCode::OK);
+    let created: Vec<i64> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|game| game["created_at"].as_i64().unwrap())
+        .collect();
+    assert_eq!(created, [30, 20]);
+}
+
+#[tokio::test]
+async fn moves_show_only_once_revealed() {
+    let (_, body) = get(fixture().store, "/games?status=waiting_for_reveal&limit=1").await;
+    let moves = &body[0]["moves"];
+
+    assert_eq!(moves[0]["seat"], "creator");
+    assert_eq!(moves[0]["revealed"], json!({ "card": 3, "prediction": 5 }));
+    assert_eq!(moves[1]["committed"], true);
+    assert_eq!(moves[1]["revealed"], Value::Null);
+}
+
+#[tokio::test]
+async fn game_detail_includes_payouts() {
+    let fixture = fixture();
+    let uri = format!("/games/{}", fixture.settled);
+
+    let (status, body) = get(fixture.store, &uri).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "settled");
+    assert_eq!(body["winner"], fixture.creator.to_string());
+    assert_eq!(
+        body["payouts"],
+        json!([{
+            "recipient": fixture.creator.to_string(),
+            "kind": "winnings",
+            "amount": 2 * BET,
+        }])
+    );
+}
+
+#[tokio::test]
+async fn player_stats_and_leaderboard() {
+    let Fixture { store, creator, .. } = fixture();
+    let uri = format!("/players/{creator}/stats");
+    let (status, body) = get(store, &uri).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["wins"], 1);
+    assert_eq!(body["net_winnings"], BET);
+
+    let (status, body) = get(fixture().store, "/leaderboard").await;
+    assert_eq!(status, StatusCode::OK);
+    let table: Vec<(u64, u64, u64)> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| {
+            let field = |name: &str| row[name].as_u64().unwrap();
+            (field("rank"), field("rating"), field("wins"))
+        })
+        .collect();
+    // Players without a finished game stay off the board.
+    assert_eq!(table, [(1, 1_300, 1), (2, 1_200, 1), (3, 1_100, 5), (4, 1_100, 3)]);
+}
+
+#[tokio::test]
+async fn bad_requests_are_rejected() {
+    let (status, body) = get(fixture().store, "/games?status=finished").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "unknown status: finished");
+
+    let (status, _) = get(fixture().store, "/games?creator=nope").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let uri = format!("/games/{}", Pubkey::new_unique());
+    let (status, _) = get(fixture().store, &uri).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let uri = format!("/players/{}/stats", Pubkey::new_unique());
+    let (status, _) = get(fixture().store, &uri).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,12 @@
+//! Framework for Morra bots: implement [`Strategy`] and hand it to a
+//! [`Runner`], which finds games, commits, reveals and settles on chain so
+//! the strategy only has to pick moves. [`Uniform`], [`Frequency`] and
+//! [`Markov`] are baselines to measure new strategies against.
+
+mod runner;
+mod strategies;
+mod strategy;
+
+pub use runner::*;
+pub use strategies::*;
+pub use strategy::*;
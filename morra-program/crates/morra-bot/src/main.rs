@@ -0,0 +1,68 @@
+//! Bot CLI: play a baseline strategy for a number of games and report how
+//! it did.
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use morra_bot::{Baseline, Runner};
+use morra_core::RuleSet;
+use solana_keypair::read_keypair_file;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+
+#[derive(Parser)]
+#[command(about = "Play Morra games with a baseline strategy")]
+struct Args {
+    /// uniform, frequency or markov.
+    #[arg(long, default_value_t = Baseline::Uniform)]
+    strategy: Baseline,
+    #[arg(long)]
+    keypair: PathBuf,
+    #[arg(long, default_value = "http://127.0.0.1:8899")]
+    rpc_url: String,
+    /// Lamports staked per game.
+    #[arg(long)]
+    bet: u64,
+    #[arg(long)]
+    ranked: bool,
+    #[arg(long, default_value_t = 10)]
+    games: u32,
+    /// Seed the strategy's randomness, for reproducible runs.
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let keypair = read_keypair_file(&args.keypair)
+        .map_err(|err| anyhow::anyhow!("reading {}: {err}", args.keypair.display()))?;
+    let strategy = args.strategy.build(RuleSet::STANDARD, args.seed);
+    let mut runner = Runner::new(
+        RpcClient::new(args.rpc_url),
+        keypair,
+        strategy,
+        args.bet,
+        args.ranked,
+    );
+
+    let (mut wins, mut losses, mut draws) = (0, 0, 0);
+    for game in 1..=args.games {
+        match runner.play().await {
+            Ok(Some(round)) => {
+                match round.won() {
+                    Some(true) => wins += 1,
+                    Some(false) => losses += 1,
+                    None => draws += 1,
+                }
+                println!(
+                    "game {game}: {:?} against {}'s {:?}",
+                    round.own, round.opponent, round.theirs
+                );
+            }
+            Ok(None) => println!("game {game}: no result"),
+            Err(err) => eprintln!("game {game} failed: {err:#}"),
+        }
+    }
+    println!("{wins} won, {losses} lost, {draws} drawn");
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use morra_client::{
+    cancel_game, chain_time, claim_winnings, commit_move, create_game, create_lobby_page,
+    create_profile, fetch_game, fetch_lobby, fetch_profile, forfeit_game, game_address, join_game,
+    lobby_address, refund_draw, reveal_move, Instruction, Secret,
+};
+use morra_keeper::{next_action, Action};
+use morra_program::{Game, GameStatus};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+use crate::strategy::{Round, Strategy};
+
+/// Plays a [`Strategy`] on chain, one game at a time, keeping the history
+/// it chooses from. The runner generates and stores each move's salt, so
+/// nothing is revealed before both players have committed.
+pub struct Runner<S> {
+    rpc: RpcClient,
+    keypair: Keypair,
+    strategy: S,
+    bet: u64,
+    ranked: bool,
+    /// How often to poll the game while waiting on the other player.
+    pub poll_interval: Duration,
+    /// How long a hosted game waits for an opponent before it's cancelled.
+    pub join_wait: Duration,
+    history: Vec<Round>,
+}
+
+impl<S: Strategy> Runner<S> {
+    pub fn new(rpc: RpcClient, keypair: Keypair, strategy: S, bet: u64, ranked: bool) -> Self {
+        Self {
+            rpc,
+            keypair,
+            strategy,
+            bet,
+            ranked,
+            poll_interval: Duration::from_secs(2),
+            join_wait: Duration::from_secs(300),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn history(&self) -> &[Round] {
+        &self.history
+    }
+
+    pub fn strategy(&self) -> &S {
+        &self.strategy
+    }
+
+    /// Play one game: join an open game at the runner's bet if the lobby
+    /// has one, otherwise host one. Returns the finished round, or `None`
+    /// if nobody joined or the game ended without both moves revealed.
+    pub async fn play(&mut self) -> anyhow::Result<Option<Round>> {
+        let chosen = self.strategy.choose(&self.history);
+        let secret = Secret::new(chosen.card, chosen.prediction);
+        let address = match self.open_game().await? {
+            Some((address, game)) => {
+                let me = self.keypair.pubkey();
+                self.send(join_game(&address, &game, &me, &me, &secret, 0))
+                    .await?;
+                address
+            }
+            None => match self.host(&secret).await? {
+                Some(address) => address,
+                None => return Ok(None),
+            },
+        };
+        let round = self.finish(&address, &secret).await?;
+        self.history.extend(round);
+        Ok(round)
+    }
+
+    async fn send(&self, instruction: Instruction) -> anyhow::Result<()> {
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.keypair.pubkey()),
+            &[&self.keypair],
+            blockhash,
+        );
+        self.rpc.send_and_confirm_transaction(&transaction).await?;
+        Ok(())
+    }
+
+    /// Someone else's game waiting in the lobby at the runner's bet.
+    async fn open_game(&self) -> anyhow::Result<Option<(Pubkey, Game)>> {
+        let me = self.keypair.pubkey();
+        let mut index = 0;
+        while let Some(page) = fetch_lobby(&self.rpc, self.ranked, index).await? {
+            for entry in page.open_games(self.bet..=self.bet) {
+                if entry.creator == me {
+                    continue;
+                }
+                if let Some(game) = fetch_game(&self.rpc, &entry.game).await? {
+                    return Ok(Some((entry.game, game)));
+                }
+            }
+            index += 1;
+        }
+        Ok(None)
+    }
+
+    /// Open a game and wait for an opponent, cancelling it if none comes
+    /// within [`Runner::join_wait`].
+    async fn host(&self, secret: &Secret) -> anyhow::Result<Option<Pubkey>> {
+        let me = self.keypair.pubkey();
+        let profile = match fetch_profile(&self.rpc, &me).await? {
+            Some(profile) => profile,
+            None => {
+                self.send(create_profile(&me, &me)).await?;
+                fetch_profile(&self.rpc, &me)
+                    .await?
+                    .context("profile missing after creation")?
+            }
+        };
+        let mut index = 0;
+        loop {
+            match fetch_lobby(&self.rpc, self.ranked, index).await? {
+                Some(page) if page.is_full() => index += 1,
+                Some(_) => break,
+                None => {
+                    self.send(create_lobby_page(&me, self.ranked, index))
+                        .await?;
+                    break;
+                }
+            }
+        }
+        let lobby = lobby_address(self.ranked, index);
+        self.send(create_game(
+            &me,
+            &me,
+            profile.next_game_id,
+            &lobby,
+            self.bet,
+            secret,
+            self.ranked,
+            0,
+        ))
+        .await?;
+
+        let address = game_address(&me, profile.next_game_id);
+        let deadline = Instant::now() + self.join_wait;
+        loop {
+            let game = fetch_game(&self.rpc, &address)
+                .await?
+                .context("hosted game disappeared")?;
+            if game.status != GameStatus::WaitingForOpponent {
+                return Ok(Some(address));
+            }
+            if Instant::now() >= deadline {
+                self.send(cancel_game(&address, &game)).await?;
+                return Ok(None);
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Reveal, then settle however the game ends.
+    async fn finish(&self, address: &Pubkey, secret: &Secret) -> anyhow::Result<Option<Round>> {
+        let me = self.keypair.pubkey();
+        loop {
+            let game = fetch_game(&self.rpc, address)
+                .await?
+                .context("game disappeared")?;
+            let now = chain_time(&self.rpc).await?;
+            match next_action(address, &game, &me, Some(secret), now) {
+                Some(Action::Commit) => {
+                    self.send(commit_move(address, &me, &me, None, secret))
+                        .await?
+                }
+                Some(Action::Reveal) => {
+                    self.send(reveal_move(address, &game, &me, None, secret))
+                        .await?
+                }
+                Some(Action::Claim) => self.send(claim_winnings(address, &game, &me)).await?,
+                Some(Action::RefundDraw) => self.send(refund_draw(address, &game)).await?,
+                Some(Action::ClaimTimeout) => {
+                    self.send(forfeit_game(address, &game, &me, false)).await?
+                }
+                Some(Action::Forget) => return Ok(round(&game, &me)),
+                None => tokio::time::sleep(self.poll_interval).await,
+            }
+        }
+    }
+}
+
+fn round(game: &Game, me: &Pubkey) -> Option<Round> {
+    let (own, theirs, opponent) = if *me == game.creator {
+        (&game.creator_move, &game.opponent_move, game.opponent)
+    } else {
+        (&game.opponent_move, &game.creator_move, game.creator)
+    };
+    Some(Round {
+        opponent,
+        own: own.revealed()?,
+        theirs: theirs.revealed()?,
+    })
+}
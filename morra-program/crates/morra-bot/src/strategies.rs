@@ -0,0 +1,205 @@
+use std::fmt;
+use std::str::FromStr;
+
+use morra_core::{Move, RuleSet, MAX_CARD};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::strategy::{Round, Strategy};
+
+/// Counts indexed by card, with room for zero under rule sets that allow it.
+type Counts = [u64; MAX_CARD as usize + 1];
+
+fn random_card(rng: &mut StdRng, rules: &RuleSet) -> u8 {
+    rng.gen_range(rules.cards())
+}
+
+/// Best response to an opponent expected to show cards with relative
+/// frequencies `counts`: show any card and call it plus their likeliest
+/// card, breaking ties at random. With no counts at all, guess.
+fn respond(rng: &mut StdRng, rules: &RuleSet, counts: &Counts) -> Move {
+    let card = random_card(rng, rules);
+    let best = rules
+        .cards()
+        .map(|theirs| counts[usize::from(theirs)])
+        .max()
+        .unwrap_or(0);
+    let likeliest: Vec<u8> = rules
+        .cards()
+        .filter(|&theirs| counts[usize::from(theirs)] == best)
+        .collect();
+    let theirs = likeliest[rng.gen_range(0..likeliest.len())];
+    Move::new(card, card + theirs)
+}
+
+fn rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Shows and guesses uniformly at random. Unexploitable on its card, and the
+/// baseline every other strategy should beat.
+pub struct Uniform {
+    rng: StdRng,
+    rules: RuleSet,
+}
+
+impl Uniform {
+    pub fn new(seed: Option<u64>) -> Self {
+        Self {
+            rng: rng(seed),
+            rules: RuleSet::STANDARD,
+        }
+    }
+
+    /// Play under `rules` rather than the program's.
+    pub fn with_rules(self, rules: RuleSet) -> Self {
+        Self { rules, ..self }
+    }
+}
+
+impl Strategy for Uniform {
+    fn choose(&mut self, _history: &[Round]) -> Move {
+        let card = random_card(&mut self.rng, &self.rules);
+        Move::new(card, card + random_card(&mut self.rng, &self.rules))
+    }
+}
+
+/// Expects opponents to show whichever card they've shown most often.
+pub struct Frequency {
+    rng: StdRng,
+    rules: RuleSet,
+    counts: Counts,
+    seen: usize,
+}
+
+impl Frequency {
+    pub fn new(seed: Option<u64>) -> Self {
+        Self {
+            rng: rng(seed),
+            rules: RuleSet::STANDARD,
+            counts: Counts::default(),
+            seen: 0,
+        }
+    }
+
+    /// Play under `rules` rather than the program's.
+    pub fn with_rules(self, rules: RuleSet) -> Self {
+        Self { rules, ..self }
+    }
+}
+
+impl Strategy for Frequency {
+    fn choose(&mut self, history: &[Round]) -> Move {
+        // A shorter history is a fresh one; start counting over.
+        if history.len() < self.seen {
+            self.counts = Counts::default();
+            self.seen = 0;
+        }
+        for round in &history[self.seen..] {
+            self.counts[usize::from(round.theirs.card)] += 1;
+        }
+        self.seen = history.len();
+        respond(&mut self.rng, &self.rules, &self.counts)
+    }
+}
+
+/// First-order Markov model of the opponents' cards: expects the card that
+/// most often followed the last one they showed, falling back to
+/// [`Frequency`]'s guess before that card has been followed by anything.
+pub struct Markov {
+    rng: StdRng,
+    rules: RuleSet,
+    counts: Counts,
+    transitions: [Counts; MAX_CARD as usize + 1],
+    seen: usize,
+}
+
+impl Markov {
+    pub fn new(seed: Option<u64>) -> Self {
+        Self {
+            rng: rng(seed),
+            rules: RuleSet::STANDARD,
+            counts: Counts::default(),
+            transitions: Default::default(),
+            seen: 0,
+        }
+    }
+
+    /// Play under `rules` rather than the program's.
+    pub fn with_rules(self, rules: RuleSet) -> Self {
+        Self { rules, ..self }
+    }
+}
+
+impl Strategy for Markov {
+    fn choose(&mut self, history: &[Round]) -> Move {
+        if history.len() < self.seen {
+            self.counts = Counts::default();
+            self.transitions = Default::default();
+            self.seen = 0;
+        }
+        let mut last = self
+            .seen
+            .checked_sub(1)
+            .map(|index| history[index].theirs.card);
+        for round in &history[self.seen..] {
+            let card = round.theirs.card;
+            if let Some(last) = last {
+                self.transitions[usize::from(last)][usize::from(card)] += 1;
+            }
+            self.counts[usize::from(card)] += 1;
+            last = Some(card);
+        }
+        self.seen = history.len();
+        let next = last
+            .map(|last| self.transitions[usize::from(last)])
+            .filter(|next| next.iter().any(|&count| count > 0));
+        respond(&mut self.rng, &self.rules, &next.unwrap_or(self.counts))
+    }
+}
+
+/// The baseline strategies by name, for command lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Baseline {
+    Uniform,
+    Frequency,
+    Markov,
+}
+
+impl Baseline {
+    pub const ALL: [Baseline; 3] = [Baseline::Uniform, Baseline::Frequency, Baseline::Markov];
+
+    pub fn build(self, rules: RuleSet, seed: Option<u64>) -> Box<dyn Strategy> {
+        match self {
+            Baseline::Uniform => Box::new(Uniform::new(seed).with_rules(rules)),
+            Baseline::Frequency => Box::new(Frequency::new(seed).with_rules(rules)),
+            Baseline::Markov => Box::new(Markov::new(seed).with_rules(rules)),
+        }
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Baseline::Uniform => "uniform",
+            Baseline::Frequency => "frequency",
+            Baseline::Markov => "markov",
+        })
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Baseline::ALL
+            .into_iter()
+            .find(|baseline| baseline.to_string() == name)
+            .ok_or_else(|| {
+                format!("unknown strategy {name:?}; expected uniform, frequency or markov")
+            })
+    }
+}
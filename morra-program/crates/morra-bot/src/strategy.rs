@@ -0,0 +1,37 @@
+use morra_core::{resolve, Move, Outcome};
+use solana_pubkey::Pubkey;
+
+/// A finished game from the bot's side of the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Pubkey,
+    pub own: Move,
+    pub theirs: Move,
+}
+
+impl Round {
+    /// `Some(true)` if the bot won, `Some(false)` if it lost, `None` on a
+    /// draw. Resolution is symmetric, so the bot's seat doesn't matter.
+    pub fn won(&self) -> Option<bool> {
+        match resolve(self.own, self.theirs) {
+            Outcome::CreatorWins => Some(true),
+            Outcome::OpponentWins => Some(false),
+            Outcome::Draw => None,
+        }
+    }
+}
+
+/// Picks the bot's next move.
+pub trait Strategy {
+    /// The move for the next game, given every finished game so far, oldest
+    /// first. `history` only grows between calls, so strategies may count
+    /// it incrementally. Strategies that model one opponent can filter it
+    /// by [`Round::opponent`].
+    fn choose(&mut self, history: &[Round]) -> Move;
+}
+
+impl<S: Strategy + ?Sized> Strategy for Box<S> {
+    fn choose(&mut self, history: &[Round]) -> Move {
+        (**self).choose(history)
+    }
+}
@@ -0,0 +1,85 @@
+use morra_bot::{Baseline, Frequency, Markov, Round, Strategy, Uniform};
+use morra_core::{is_valid_card, is_valid_prediction, Move, RuleSet};
+use solana_pubkey::Pubkey;
+
+fn against(cards: &[u8]) -> Vec<Round> {
+    let opponent = Pubkey::new_unique();
+    cards
+        .iter()
+        .map(|&card| Round {
+            opponent,
+            own: Move::new(1, 2),
+            theirs: Move::new(card, card + 1),
+        })
+        .collect()
+}
+
+#[test]
+fn baselines_only_choose_legal_moves() {
+    let strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(Uniform::new(Some(1))),
+        Box::new(Frequency::new(Some(2))),
+        Box::new(Markov::new(Some(3))),
+    ];
+    let history = against(&[1, 5, 2, 2, 4, 3]);
+    for mut strategy in strategies {
+        for seen in 0..=history.len() {
+            let chosen = strategy.choose(&history[..seen]);
+            assert!(is_valid_card(chosen.card), "{chosen:?}");
+            assert!(is_valid_prediction(chosen.prediction), "{chosen:?}");
+        }
+    }
+}
+
+#[test]
+fn baselines_show_zero_when_the_rules_allow_it() {
+    let rules = RuleSet {
+        zero_allowed: true,
+        ..RuleSet::STANDARD
+    };
+    let mut strategy = Baseline::Uniform.build(rules, Some(4));
+    let moves: Vec<Move> = (0..200).map(|_| strategy.choose(&[])).collect();
+    assert!(moves.iter().all(|&chosen| rules.is_valid(chosen)));
+    assert!(moves.iter().any(|chosen| chosen.card == 0));
+
+    let mut strategy = Frequency::new(Some(5)).with_rules(rules);
+    let chosen = strategy.choose(&against(&[0, 0, 3]));
+    assert_eq!(chosen.prediction, chosen.card);
+}
+
+#[test]
+fn frequency_calls_the_favourite_card() {
+    let history = against(&[4, 2, 4, 1, 4]);
+    let mut strategy = Frequency::new(Some(7));
+    for _ in 0..20 {
+        let chosen = strategy.choose(&history);
+        assert_eq!(chosen.prediction, chosen.card + 4);
+    }
+}
+
+#[test]
+fn markov_follows_the_opponents_cycle() {
+    // Every card has shown up equally often, so only the order gives the
+    // next one away.
+    let history = against(&[1, 2, 3, 4, 5, 1, 2, 3, 4, 5, 1, 2]);
+    let mut strategy = Markov::new(Some(7));
+    for _ in 0..20 {
+        let chosen = strategy.choose(&history);
+        assert_eq!(chosen.prediction, chosen.card + 3);
+    }
+
+    let mut won = 0;
+    let mut history = Vec::new();
+    for turn in 0..50u8 {
+        let theirs = Move::new(turn % 5 + 1, 2);
+        let own = strategy.choose(&history);
+        let round = Round {
+            opponent: Pubkey::default(),
+            own,
+            theirs,
+        };
+        won += u32::from(round.won() == Some(true));
+        history.push(round);
+    }
+    assert!(won >= 40, "won {won} of 50");
+}
@@ -0,0 +1,58 @@
+use morra_program::{
+    BANKROLL_SEED, CONFIG_SEED, GAME_SEED, HOUSE_SEED, LOBBY_SEED, LP_SEED, PROFILE_SEED,
+    SESSION_SEED, SIDE_BET_SEED,
+};
+use solana_pubkey::Pubkey;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &morra_program::ID).0
+}
+
+pub fn profile_address(player: &Pubkey) -> Pubkey {
+    find(&[PROFILE_SEED, player.as_ref()])
+}
+
+pub fn game_address(creator: &Pubkey, game_id: u64) -> Pubkey {
+    find(&[GAME_SEED, creator.as_ref(), &game_id.to_le_bytes()])
+}
+
+/// The system-owned PDA holding a game's stakes.
+pub fn escrow_address(game: &Pubkey) -> Pubkey {
+    find(&[GAME_SEED, game.as_ref()])
+}
+
+pub fn lobby_address(ranked: bool, index: u32) -> Pubkey {
+    find(&[LOBBY_SEED, &[u8::from(ranked)], &index.to_le_bytes()])
+}
+
+pub fn session_address(player: &Pubkey, session_key: &Pubkey) -> Pubkey {
+    find(&[SESSION_SEED, player.as_ref(), session_key.as_ref()])
+}
+
+pub fn side_bet_pool_address(game: &Pubkey) -> Pubkey {
+    find(&[SIDE_BET_SEED, game.as_ref()])
+}
+
+pub fn side_bet_address(pool: &Pubkey, bettor: &Pubkey) -> Pubkey {
+    find(&[SIDE_BET_SEED, pool.as_ref(), bettor.as_ref()])
+}
+
+pub fn config_address() -> Pubkey {
+    find(&[CONFIG_SEED])
+}
+
+pub fn house_address() -> Pubkey {
+    find(&[HOUSE_SEED])
+}
+
+pub fn bankroll_address() -> Pubkey {
+    find(&[BANKROLL_SEED])
+}
+
+pub fn lp_address(owner: &Pubkey) -> Pubkey {
+    find(&[LP_SEED, owner.as_ref()])
+}
+
+pub fn house_game_address(player: &Pubkey, game_id: u64) -> Pubkey {
+    find(&[HOUSE_SEED, player.as_ref(), &game_id.to_le_bytes()])
+}
@@ -0,0 +1,405 @@
+use anchor_lang::prelude::AccountMeta;
+pub use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use morra_program::{accounts, instruction, Game, HouseGame};
+use solana_pubkey::Pubkey;
+
+use crate::address::{
+    bankroll_address, config_address, escrow_address, game_address, house_address,
+    house_game_address, lobby_address, lp_address, profile_address, side_bet_address,
+    side_bet_pool_address,
+};
+use crate::secret::Secret;
+
+/// A session key signing moves for a player: its session account and the
+/// tournament or league it's scoped to, unless it's scoped to the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionAccounts {
+    pub session: Pubkey,
+    pub scope: Option<Pubkey>,
+}
+
+fn program_instruction(accounts: Vec<AccountMeta>, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: morra_program::ID,
+        accounts,
+        data: data.data(),
+    }
+}
+
+pub fn create_profile(player: &Pubkey, payer: &Pubkey) -> Instruction {
+    program_instruction(
+        accounts::CreateProfile {
+            profile: profile_address(player),
+            player: *player,
+            payer: *payer,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::CreateProfile {},
+    )
+}
+
+pub fn create_lobby_page(payer: &Pubkey, ranked: bool, index: u32) -> Instruction {
+    program_instruction(
+        accounts::CreateLobbyPage {
+            lobby: lobby_address(ranked, index),
+            payer: *payer,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::CreateLobbyPage { ranked, index },
+    )
+}
+
+/// Open the creator's game number `game_id` (their profile's
+/// `next_game_id`) on a lobby page with room.
+#[allow(clippy::too_many_arguments)]
+pub fn create_game(
+    creator: &Pubkey,
+    payer: &Pubkey,
+    game_id: u64,
+    lobby: &Pubkey,
+    bet_amount: u64,
+    secret: &Secret,
+    ranked: bool,
+    reimbursement: u64,
+) -> Instruction {
+    let game = game_address(creator, game_id);
+    program_instruction(
+        accounts::CreateGame {
+            creator: *creator,
+            payer: *payer,
+            creator_profile: profile_address(creator),
+            game,
+            lobby: *lobby,
+            game_account: escrow_address(&game),
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        instruction::CreateGame {
+            bet_amount,
+            commitment: secret.commitment(creator, &game),
+            ranked,
+            reimbursement,
+        },
+    )
+}
+
+pub fn join_game(
+    address: &Pubkey,
+    game: &Game,
+    opponent: &Pubkey,
+    payer: &Pubkey,
+    secret: &Secret,
+    reimbursement: u64,
+) -> Instruction {
+    program_instruction(
+        accounts::JoinGame {
+            game: *address,
+            opponent: *opponent,
+            payer: *payer,
+            opponent_profile: profile_address(opponent),
+            lobby: lobby_address(game.ranked, game.lobby_page),
+            game_account: escrow_address(address),
+            system_program: system_program::ID,
+            side_bet_pool: side_bet_pool_address(address),
+            opponent_side_bet: side_bet_address(&side_bet_pool_address(address), opponent),
+        }
+        .to_account_metas(None),
+        instruction::JoinGame {
+            commitment: secret.commitment(opponent, address),
+            reimbursement,
+        },
+    )
+}
+
+pub fn cancel_game(address: &Pubkey, game: &Game) -> Instruction {
+    program_instruction(
+        accounts::CancelGame {
+            game: *address,
+            creator: game.creator,
+            lobby: lobby_address(game.ranked, game.lobby_page),
+            game_account: escrow_address(address),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::CancelGame {},
+    )
+}
+
+/// Commit `player`'s move to a game spawned with both seats filled.
+/// `signer` is the player, or a session key when `session` is set.
+pub fn commit_move(
+    address: &Pubkey,
+    player: &Pubkey,
+    signer: &Pubkey,
+    session: Option<SessionAccounts>,
+    secret: &Secret,
+) -> Instruction {
+    program_instruction(
+        accounts::CommitMove {
+            game: *address,
+            player: *signer,
+            session: session.map(|session| session.session),
+            session_scope: session.and_then(|session| session.scope),
+        }
+        .to_account_metas(None),
+        instruction::CommitMove {
+            commitment: secret.commitment(player, address),
+        },
+    )
+}
+
+pub fn reveal_move(
+    address: &Pubkey,
+    game: &Game,
+    signer: &Pubkey,
+    session: Option<SessionAccounts>,
+    secret: &Secret,
+) -> Instruction {
+    program_instruction(
+        accounts::RevealMove {
+            game: *address,
+            player: *signer,
+            creator_profile: profile_address(&game.creator),
+            opponent_profile: profile_address(&game.opponent),
+            session: session.map(|session| session.session),
+            session_scope: session.and_then(|session| session.scope),
+        }
+        .to_account_metas(None),
+        instruction::RevealMove {
+            card: secret.card,
+            prediction: secret.prediction,
+            salt: secret.salt,
+        },
+    )
+}
+
+/// Claim a won game, passing the winner's sponsor if they owe one.
+pub fn claim_winnings(address: &Pubkey, game: &Game, winner: &Pubkey) -> Instruction {
+    program_instruction(
+        accounts::ClaimWinnings {
+            game: *address,
+            winner: *winner,
+            game_account: escrow_address(address),
+            sponsor: game.sponsor_of(winner).map(|sponsor| sponsor.relayer),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::ClaimWinnings {},
+    )
+}
+
+/// Return both stakes of a drawn game. Anyone may send it.
+pub fn refund_draw(address: &Pubkey, game: &Game) -> Instruction {
+    program_instruction(
+        accounts::RefundDraw {
+            game: *address,
+            creator: game.creator,
+            opponent: game.opponent,
+            game_account: escrow_address(address),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::RefundDraw {},
+    )
+}
+
+/// Cancel a game nobody joined in time. `rewarded` passes the program
+/// config so `cranker` collects the crank reward; leave it off when the
+/// config doesn't exist.
+pub fn expire_game(address: &Pubkey, game: &Game, cranker: &Pubkey, rewarded: bool) -> Instruction {
+    program_instruction(
+        accounts::ExpireGame {
+            game: *address,
+            creator: game.creator,
+            lobby: lobby_address(game.ranked, game.lobby_page),
+            game_account: escrow_address(address),
+            config: rewarded.then(config_address),
+            cranker: *cranker,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::ExpireGame {},
+    )
+}
+
+/// Forfeit whoever is holding up a stalled game, or refund both players if
+/// neither moved.
+pub fn forfeit_game(
+    address: &Pubkey,
+    game: &Game,
+    cranker: &Pubkey,
+    rewarded: bool,
+) -> Instruction {
+    program_instruction(
+        accounts::ForfeitGame {
+            game: *address,
+            creator: game.creator,
+            opponent: game.opponent,
+            creator_profile: profile_address(&game.creator),
+            opponent_profile: profile_address(&game.opponent),
+            game_account: escrow_address(address),
+            config: rewarded.then(config_address),
+            cranker: *cranker,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::ForfeitGame {},
+    )
+}
+
+/// Stake `bet_amount` against the house as the player's game number
+/// `game_id` (their profile's `next_game_id`).
+/// Carry a bracket match's resolved or cancelled game into `tournament`.
+pub fn advance_tournament(tournament: &Pubkey, game: &Pubkey, match_index: u8) -> Instruction {
+    program_instruction(
+        accounts::AdvanceTournament {
+            tournament: *tournament,
+            game: *game,
+        }
+        .to_account_metas(None),
+        instruction::AdvanceTournament { match_index },
+    )
+}
+
+pub fn create_house_game(player: &Pubkey, game_id: u64, bet_amount: u64) -> Instruction {
+    let house_game = house_game_address(player, game_id);
+    program_instruction(
+        accounts::CreateHouseGame {
+            player: *player,
+            player_profile: profile_address(player),
+            house: house_address(),
+            house_game,
+            game_account: escrow_address(&house_game),
+            bankroll: bankroll_address(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::CreateHouseGame { bet_amount },
+    )
+}
+
+pub fn house_commit(address: &Pubkey, operator: &Pubkey, secret: &Secret) -> Instruction {
+    program_instruction(
+        accounts::HouseCommit {
+            house_game: *address,
+            house: house_address(),
+            operator: *operator,
+        }
+        .to_account_metas(None),
+        instruction::HouseCommit {
+            commitment: secret.commitment(&house_address(), address),
+        },
+    )
+}
+
+pub fn play_house_game(address: &Pubkey, player: &Pubkey, card: u8, prediction: u8) -> Instruction {
+    program_instruction(
+        accounts::PlayHouseGame {
+            house_game: *address,
+            player: *player,
+        }
+        .to_account_metas(None),
+        instruction::PlayHouseGame { card, prediction },
+    )
+}
+
+pub fn house_reveal(
+    address: &Pubkey,
+    game: &HouseGame,
+    operator: &Pubkey,
+    secret: &Secret,
+) -> Instruction {
+    program_instruction(
+        accounts::HouseReveal {
+            house_game: *address,
+            house: house_address(),
+            operator: *operator,
+            player: game.player,
+            player_profile: profile_address(&game.player),
+            game_account: escrow_address(address),
+            bankroll: bankroll_address(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::HouseReveal {
+            card: secret.card,
+            prediction: secret.prediction,
+            salt: secret.salt,
+        },
+    )
+}
+
+/// End a stalled house game: refund a player the house never answered,
+/// take the stake of a player who never moved, or pay a player whose
+/// house never revealed.
+pub fn forfeit_house_game(address: &Pubkey, game: &HouseGame, cranker: &Pubkey) -> Instruction {
+    program_instruction(
+        accounts::ForfeitHouseGame {
+            house_game: *address,
+            house: house_address(),
+            player: game.player,
+            player_profile: profile_address(&game.player),
+            game_account: escrow_address(address),
+            bankroll: bankroll_address(),
+            cranker: *cranker,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::ForfeitHouseGame {},
+    )
+}
+
+pub fn create_lp_position(owner: &Pubkey) -> Instruction {
+    program_instruction(
+        accounts::CreateLpPosition {
+            owner: *owner,
+            position: lp_address(owner),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::CreateLpPosition {},
+    )
+}
+
+/// Add `amount` lamports to the bankroll for shares at its current value.
+pub fn deposit_bankroll(owner: &Pubkey, amount: u64) -> Instruction {
+    program_instruction(
+        accounts::DepositBankroll {
+            owner: *owner,
+            position: lp_address(owner),
+            bankroll: bankroll_address(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        instruction::DepositBankroll { amount },
+    )
+}
+
+/// Queue `shares` for withdrawal, or cancel the queued request with zero.
+pub fn request_withdrawal(owner: &Pubkey, shares: u64) -> Instruction {
+    program_instruction(
+        accounts::RequestWithdrawal {
+            owner: *owner,
+            position: lp_address(owner),
+        }
+        .to_account_metas(None),
+        instruction::RequestWithdrawal { shares },
+    )
+}
+
+pub fn withdraw_bankroll(owner: &Pubkey) -> Instruction {
+    program_instruction(
+        accounts::WithdrawBankroll {
+            owner: *owner,
+            position: lp_address(owner),
+            bankroll: bankroll_address(),
+        }
+        .to_account_metas(None),
+        instruction::WithdrawBankroll {},
+    )
+}
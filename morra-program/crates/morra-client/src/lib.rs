@@ -0,0 +1,12 @@
+//! What off-chain tools need to talk to `morra_program`: account
+//! addresses, instruction builders, move secrets and account lookups.
+
+mod address;
+mod instructions;
+mod lookup;
+mod secret;
+
+pub use address::*;
+pub use instructions::*;
+pub use lookup::*;
+pub use secret::*;
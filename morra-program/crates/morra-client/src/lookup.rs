@@ -0,0 +1,197 @@
+use anchor_lang::{AccountDeserialize, Discriminator};
+use morra_program::{
+    Bankroll, Config, Game, GameStatus, House, HouseGame, HouseGameStatus, LobbyPage, LpPosition,
+    PlayerProfile, Tournament, TournamentStatus,
+};
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_pubkey::Pubkey;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Result as ClientResult;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+
+use crate::address::{
+    bankroll_address, config_address, house_address, lobby_address, lp_address, profile_address,
+};
+
+/// Byte offsets of a `Game` account's seats, past the discriminator:
+/// `creator`, then `game_id` and `bump` before `opponent`.
+pub const CREATOR_OFFSET: usize = 8;
+pub const OPPONENT_OFFSET: usize = CREATOR_OFFSET + 32 + 8 + 1;
+/// Byte offset of `status`, past `opponent`, `bet_amount`, `ranked` and
+/// `lobby_page`.
+pub const STATUS_OFFSET: usize = OPPONENT_OFFSET + 32 + 8 + 1 + 4;
+
+/// Decode a `Game` account's data, or `None` if it isn't one.
+pub fn decode_game(data: &[u8]) -> Option<Game> {
+    Game::try_deserialize(&mut &data[..]).ok()
+}
+
+/// Byte offset of a `HouseGame`'s `status`, past `player`, `game_id`,
+/// `bump` and `bet_amount`.
+pub const HOUSE_STATUS_OFFSET: usize = 8 + 32 + 8 + 1 + 8;
+
+/// Byte offset of a `Tournament`'s `status`, past `organizer`, `id`,
+/// `bump`, `entry_fee`, `max_players` and `registration_ends_at`.
+pub const TOURNAMENT_STATUS_OFFSET: usize = 8 + 32 + 8 + 1 + 8 + 1 + 8;
+
+/// `Game` accounts matching `filters` as well as the discriminator.
+pub fn game_filter(filters: Vec<RpcFilterType>) -> RpcProgramAccountsConfig {
+    account_filter(Game::DISCRIMINATOR, filters)
+}
+
+fn account_filter(
+    discriminator: &[u8],
+    mut filters: Vec<RpcFilterType>,
+) -> RpcProgramAccountsConfig {
+    filters.insert(
+        0,
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, discriminator.to_vec())),
+    );
+    RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    }
+}
+
+/// Every game matching `filters`.
+pub async fn find_games(
+    rpc: &RpcClient,
+    filters: Vec<RpcFilterType>,
+) -> ClientResult<Vec<(Pubkey, Game)>> {
+    let accounts = rpc
+        .get_program_accounts_with_config(&morra_program::ID, game_filter(filters))
+        .await?;
+    Ok(accounts
+        .into_iter()
+        .filter_map(|(address, account)| Some((address, decode_game(&account.data)?)))
+        .collect())
+}
+
+/// Every game `player` sits in, as creator or opponent.
+pub async fn player_games(rpc: &RpcClient, player: &Pubkey) -> ClientResult<Vec<(Pubkey, Game)>> {
+    let mut games = Vec::new();
+    for offset in [CREATOR_OFFSET, OPPONENT_OFFSET] {
+        let seat = Memcmp::new_raw_bytes(offset, player.to_bytes().to_vec());
+        games.extend(find_games(rpc, vec![RpcFilterType::Memcmp(seat)]).await?);
+    }
+    Ok(games)
+}
+
+/// Every game currently in `status`.
+pub async fn games_in(rpc: &RpcClient, status: GameStatus) -> ClientResult<Vec<(Pubkey, Game)>> {
+    let status = Memcmp::new_raw_bytes(STATUS_OFFSET, vec![status as u8]);
+    find_games(rpc, vec![RpcFilterType::Memcmp(status)]).await
+}
+
+/// Every house game currently in `status`.
+pub async fn house_games_in(
+    rpc: &RpcClient,
+    status: HouseGameStatus,
+) -> ClientResult<Vec<(Pubkey, HouseGame)>> {
+    let status = Memcmp::new_raw_bytes(HOUSE_STATUS_OFFSET, vec![status as u8]);
+    let config = account_filter(
+        HouseGame::DISCRIMINATOR,
+        vec![RpcFilterType::Memcmp(status)],
+    );
+    let accounts = rpc
+        .get_program_accounts_with_config(&morra_program::ID, config)
+        .await?;
+    Ok(accounts
+        .into_iter()
+        .filter_map(|(address, account)| {
+            let game = HouseGame::try_deserialize(&mut &account.data[..]).ok()?;
+            Some((address, game))
+        })
+        .collect())
+}
+
+/// Every tournament currently in `status`.
+pub async fn tournaments_in(
+    rpc: &RpcClient,
+    status: TournamentStatus,
+) -> ClientResult<Vec<(Pubkey, Tournament)>> {
+    let status = Memcmp::new_raw_bytes(TOURNAMENT_STATUS_OFFSET, vec![status as u8]);
+    let config = account_filter(
+        Tournament::DISCRIMINATOR,
+        vec![RpcFilterType::Memcmp(status)],
+    );
+    let accounts = rpc
+        .get_program_accounts_with_config(&morra_program::ID, config)
+        .await?;
+    Ok(accounts
+        .into_iter()
+        .filter_map(|(address, account)| {
+            let tournament = Tournament::try_deserialize(&mut &account.data[..]).ok()?;
+            Some((address, tournament))
+        })
+        .collect())
+}
+
+/// Decode the account at `address` as `T`, or `None` if it doesn't exist
+/// or holds something else.
+async fn fetch<T: AccountDeserialize>(
+    rpc: &RpcClient,
+    address: &Pubkey,
+) -> ClientResult<Option<T>> {
+    let account = rpc
+        .get_account_with_commitment(address, rpc.commitment())
+        .await?
+        .value;
+    Ok(account.and_then(|account| T::try_deserialize(&mut &account.data[..]).ok()))
+}
+
+pub async fn fetch_game(rpc: &RpcClient, address: &Pubkey) -> ClientResult<Option<Game>> {
+    fetch(rpc, address).await
+}
+
+pub async fn fetch_profile(
+    rpc: &RpcClient,
+    player: &Pubkey,
+) -> ClientResult<Option<PlayerProfile>> {
+    fetch(rpc, &profile_address(player)).await
+}
+
+pub async fn fetch_lobby(
+    rpc: &RpcClient,
+    ranked: bool,
+    index: u32,
+) -> ClientResult<Option<LobbyPage>> {
+    fetch(rpc, &lobby_address(ranked, index)).await
+}
+
+pub async fn fetch_house(rpc: &RpcClient) -> ClientResult<Option<House>> {
+    fetch(rpc, &house_address()).await
+}
+
+pub async fn fetch_bankroll(rpc: &RpcClient) -> ClientResult<Option<Bankroll>> {
+    fetch(rpc, &bankroll_address()).await
+}
+
+pub async fn fetch_lp_position(
+    rpc: &RpcClient,
+    owner: &Pubkey,
+) -> ClientResult<Option<LpPosition>> {
+    fetch(rpc, &lp_address(owner)).await
+}
+
+pub async fn fetch_house_game(
+    rpc: &RpcClient,
+    address: &Pubkey,
+) -> ClientResult<Option<HouseGame>> {
+    fetch(rpc, address).await
+}
+
+/// The program config, or `None` if it hasn't been initialized.
+pub async fn fetch_config(rpc: &RpcClient) -> ClientResult<Option<Config>> {
+    fetch(rpc, &config_address()).await
+}
+
+/// The cluster's clock, which timeouts are measured against.
+pub async fn chain_time(rpc: &RpcClient) -> ClientResult<i64> {
+    rpc.get_block_time(rpc.get_slot().await?).await
+}
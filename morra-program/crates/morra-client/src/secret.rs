@@ -0,0 +1,47 @@
+use morra_core::{Move, Salt};
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+/// A move and the salt that hides it until reveal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Secret {
+    pub card: u8,
+    pub prediction: u8,
+    pub salt: Salt,
+}
+
+impl Secret {
+    /// A move with a fresh random salt.
+    pub fn new(card: u8, prediction: u8) -> Self {
+        Self {
+            card,
+            prediction,
+            salt: rand::random(),
+        }
+    }
+
+    pub fn to_move(self) -> Move {
+        Move::new(self.card, self.prediction)
+    }
+
+    /// The hash `player` commits on chain for this move in the game at
+    /// `scope`, or the match queue when they commit by enqueueing.
+    pub fn commitment(&self, player: &Pubkey, scope: &Pubkey) -> [u8; 32] {
+        morra_core::commitment(
+            &player.to_bytes(),
+            &scope.to_bytes(),
+            self.to_move(),
+            &self.salt,
+        )
+    }
+
+    pub fn opens(&self, committed: &[u8; 32], player: &Pubkey, scope: &Pubkey) -> bool {
+        morra_core::verify_commitment(
+            committed,
+            &player.to_bytes(),
+            &scope.to_bytes(),
+            self.to_move(),
+            &self.salt,
+        )
+    }
+}
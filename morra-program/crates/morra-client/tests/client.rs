@@ -0,0 +1,127 @@
+use anchor_lang::AccountSerialize;
+use morra_client::{
+    claim_winnings, decode_game, escrow_address, Secret, CREATOR_OFFSET, HOUSE_STATUS_OFFSET,
+    OPPONENT_OFFSET, STATUS_OFFSET, TOURNAMENT_STATUS_OFFSET,
+};
+use morra_program::{
+    Game, GameStatus, HouseGame, HouseGameStatus, PlayerMove, Sponsor, Tournament, TournamentStatus,
+};
+use solana_pubkey::Pubkey;
+
+const BET: u64 = 1_000_000_000;
+
+fn completed() -> Game {
+    Game {
+        creator: Pubkey::new_unique(),
+        game_id: 7,
+        bump: 255,
+        opponent: Pubkey::new_unique(),
+        bet_amount: BET,
+        ranked: false,
+        lobby_page: 0,
+        status: GameStatus::Completed,
+        creator_move: PlayerMove::committed([1; 32]),
+        opponent_move: PlayerMove::committed([2; 32]),
+        winner: None,
+        created_at: 0,
+        last_action_at: 0,
+        creator_sponsor: None,
+        opponent_sponsor: None,
+        queue: None,
+    }
+}
+
+#[test]
+fn seat_offsets_match_the_layout() {
+    let game = completed();
+    let mut data = Vec::new();
+    game.try_serialize(&mut data).unwrap();
+
+    let seat = |offset: usize| &data[offset..offset + 32];
+    assert_eq!(seat(CREATOR_OFFSET), game.creator.as_ref());
+    assert_eq!(seat(OPPONENT_OFFSET), game.opponent.as_ref());
+    assert_eq!(data[STATUS_OFFSET], GameStatus::Completed as u8);
+    assert_eq!(decode_game(&data).unwrap().game_id, game.game_id);
+    assert!(decode_game(&data[1..]).is_none());
+
+    let house_game = HouseGame {
+        player: Pubkey::new_unique(),
+        game_id: 3,
+        bump: 254,
+        bet_amount: BET,
+        status: HouseGameStatus::WaitingForReveal,
+        player_move: PlayerMove::default(),
+        house_move: PlayerMove::committed([3; 32]),
+        outcome: None,
+        created_at: 0,
+        last_action_at: 0,
+        house_edge_bps: 0,
+    };
+    let mut data = Vec::new();
+    house_game.try_serialize(&mut data).unwrap();
+    assert_eq!(
+        data[HOUSE_STATUS_OFFSET],
+        HouseGameStatus::WaitingForReveal as u8
+    );
+
+    let tournament = Tournament {
+        organizer: Pubkey::new_unique(),
+        id: 1,
+        bump: 253,
+        entry_fee: BET,
+        max_players: 8,
+        registration_ends_at: 0,
+        status: TournamentStatus::InProgress,
+        players: Vec::new(),
+        matches: Vec::new(),
+        prize_pool: 0,
+        prizes_claimed: 0,
+    };
+    let mut data = Vec::new();
+    tournament.try_serialize(&mut data).unwrap();
+    assert_eq!(
+        data[TOURNAMENT_STATUS_OFFSET],
+        TournamentStatus::InProgress as u8
+    );
+}
+
+#[test]
+fn secret_opens_only_its_commitment() {
+    let (player, game) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let secret = Secret::new(3, 5);
+    let committed = secret.commitment(&player, &game);
+    assert!(secret.opens(&committed, &player, &game));
+
+    let other = Secret { card: 4, ..secret };
+    assert!(!other.opens(&committed, &player, &game));
+    // A copied commitment opens for no other player or game.
+    assert!(!secret.opens(&committed, &Pubkey::new_unique(), &game));
+    assert!(!secret.opens(&committed, &player, &Pubkey::new_unique()));
+    assert_ne!(Secret::new(3, 5).salt, secret.salt);
+}
+
+#[test]
+fn claim_passes_the_winners_sponsor() {
+    let address = Pubkey::new_unique();
+    let mut game = completed();
+    let relayer = Pubkey::new_unique();
+    game.winner = Some(game.opponent);
+    game.opponent_sponsor = Some(Sponsor {
+        relayer,
+        reimbursement: 5_000,
+    });
+
+    let accounts = |winner: &Pubkey| -> Vec<Pubkey> {
+        claim_winnings(&address, &game, winner)
+            .accounts
+            .iter()
+            .map(|meta| meta.pubkey)
+            .collect()
+    };
+    let sponsored = accounts(&game.opponent);
+    assert_eq!(sponsored[2], escrow_address(&address));
+    assert_eq!(sponsored[3], relayer);
+
+    // The creator owes nothing, so the optional slot holds the program id.
+    assert_eq!(accounts(&game.creator)[3], morra_program::ID);
+}
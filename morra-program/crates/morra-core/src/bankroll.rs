@@ -0,0 +1,37 @@
+//! Share accounting for the house bankroll's liquidity providers.
+//!
+//! Prices are taken as if the pool always held `VIRTUAL_SHARES` more shares
+//! backed by `VIRTUAL_ASSETS` more lamports. Nobody owns the virtual stake,
+//! but it takes its cut of anything sent to the pool, so inflating the
+//! share price with a direct transfer, to round later deposits down to
+//! nothing, costs the donor far more than it can take.
+
+/// Denominator for rates quoted in basis points.
+pub const BASIS_POINTS: u16 = 10_000;
+
+pub const VIRTUAL_SHARES: u64 = 1_000;
+pub const VIRTUAL_ASSETS: u64 = 1;
+
+/// `bps` basis points of `amount`, rounded down.
+pub fn bps_of(amount: u64, bps: u16) -> u64 {
+    (u128::from(amount) * u128::from(bps) / u128::from(BASIS_POINTS)) as u64
+}
+
+/// Shares minted for depositing `amount` into a pool of `assets` backed by
+/// `total_shares`, rounded down so existing holders never lose to the
+/// deposit. An empty pool mints `VIRTUAL_SHARES` per lamport. `None` if the
+/// count doesn't fit.
+pub fn shares_for_deposit(amount: u64, total_shares: u64, assets: u64) -> Option<u64> {
+    let shares = u128::from(amount) * (u128::from(total_shares) + u128::from(VIRTUAL_SHARES))
+        / (u128::from(assets) + u128::from(VIRTUAL_ASSETS));
+    u64::try_from(shares).ok()
+}
+
+/// Lamports `shares` redeem for out of a pool of `assets` backed by
+/// `total_shares`, rounded down so remaining holders never lose to the
+/// withdrawal. Never more than `assets`.
+pub fn redemption_value(shares: u64, total_shares: u64, assets: u64) -> u64 {
+    let value = u128::from(shares) * (u128::from(assets) + u128::from(VIRTUAL_ASSETS))
+        / (u128::from(total_shares) + u128::from(VIRTUAL_SHARES));
+    value as u64
+}
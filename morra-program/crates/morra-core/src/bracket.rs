@@ -0,0 +1,76 @@
+//! Single-elimination bracket arithmetic.
+//!
+//! A bracket of `size` leaves (a power of two) has `size - 1` matches,
+//! stored round by round: the first round's `size / 2` matches come first
+//! and the final is last. First-round match `m` is fed by leaves `2m` and
+//! `2m + 1`; every later match is fed by two matches of the round before.
+
+/// Share of the prize pool per placement, in basis points: champion,
+/// runner-up, and the two losing semifinalists.
+pub const PRIZE_SPLIT_BPS: [u64; 4] = [6_000, 2_500, 750, 750];
+
+pub fn is_valid_bracket_size(size: usize) -> bool {
+    size >= 2 && size.is_power_of_two()
+}
+
+pub fn match_count(size: usize) -> usize {
+    size - 1
+}
+
+/// Index of the first match of `round`, counting the first round as 0.
+pub fn round_start(size: usize, round: u32) -> usize {
+    size - (size >> round)
+}
+
+/// Round that match `index` belongs to.
+pub fn round_of(size: usize, index: usize) -> u32 {
+    let mut round = 0;
+    while round_start(size, round + 1) <= index {
+        round += 1;
+    }
+    round
+}
+
+/// The match the winner of `index` advances to, and which of its two seats
+/// they take. `None` for the final.
+pub fn next_match(size: usize, index: usize) -> Option<(usize, usize)> {
+    if index + 2 >= size {
+        return None;
+    }
+    let round = round_of(size, index);
+    let position = index - round_start(size, round);
+    Some((round_start(size, round + 1) + position / 2, position % 2))
+}
+
+/// Fill `leaves[..size]` with the seed placed at each leaf, 0 being the top
+/// seed, so that seeds `s` and `size - 1 - s` meet in the first round and
+/// the top two seeds can only meet in the final.
+pub fn seed_leaves(size: usize, leaves: &mut [u8]) {
+    leaves[0] = 0;
+    let mut filled = 1;
+    while filled < size {
+        // Each seed `s` of the smaller bracket expands into `s` versus its
+        // mirror in the doubled one, working backwards to reuse the buffer.
+        for i in (0..filled).rev() {
+            let seed = leaves[i];
+            leaves[2 * i] = seed;
+            leaves[2 * i + 1] = (2 * filled - 1) as u8 - seed;
+        }
+        filled *= 2;
+    }
+}
+
+/// Split `pool` across the four placements. A placement nobody holds, for
+/// example a semifinal won on a bye, folds its share into the champion's,
+/// as does any rounding dust, so the shares always sum to `pool`.
+pub fn placement_prizes(pool: u64, present: [bool; 4]) -> [u64; 4] {
+    let mut prizes = [0; 4];
+    for place in 1..4 {
+        if present[place] {
+            prizes[place] =
+                (u128::from(pool) * u128::from(PRIZE_SPLIT_BPS[place]) / 10_000) as u64;
+        }
+    }
+    prizes[0] = pool - prizes[1..].iter().sum::<u64>();
+    prizes
+}
@@ -0,0 +1,55 @@
+//! The commit-reveal encoding.
+
+use sha2::{Digest, Sha256};
+
+use crate::Move;
+
+/// Random bytes a player mixes into their commitment so the small move
+/// space can't be brute-forced.
+pub type Salt = [u8; 32];
+
+/// A 32-byte account address, as the raw bytes of a Solana pubkey.
+pub type Address = [u8; 32];
+
+/// Byte length of [`commitment_preimage`]: player, game, card, prediction,
+/// salt.
+pub const COMMITMENT_PREIMAGE_LEN: usize = 32 + 32 + 2 + 32;
+
+/// The bytes a commitment hashes: `player || game || card || prediction ||
+/// salt`.
+///
+/// Binding the player and the game means a commitment copied from another
+/// seat or another game can't be opened by whoever copied it.
+///
+/// The on-chain program hashes this with the sha256 syscall rather than
+/// [`commitment`], so both must stay in step with this layout.
+pub fn commitment_preimage(
+    player: &Address,
+    game: &Address,
+    mv: Move,
+    salt: &Salt,
+) -> [u8; COMMITMENT_PREIMAGE_LEN] {
+    let mut preimage = [0; COMMITMENT_PREIMAGE_LEN];
+    preimage[..32].copy_from_slice(player);
+    preimage[32..64].copy_from_slice(game);
+    preimage[64] = mv.card;
+    preimage[65] = mv.prediction;
+    preimage[66..].copy_from_slice(salt);
+    preimage
+}
+
+/// The sha256 commitment `player` submits before revealing `mv` in `game`.
+pub fn commitment(player: &Address, game: &Address, mv: Move, salt: &Salt) -> [u8; 32] {
+    Sha256::digest(commitment_preimage(player, game, mv, salt)).into()
+}
+
+/// Whether `player` revealing `mv` with `salt` in `game` opens `committed`.
+pub fn verify_commitment(
+    committed: &[u8; 32],
+    player: &Address,
+    game: &Address,
+    mv: Move,
+    salt: &Salt,
+) -> bool {
+    commitment(player, game, mv, salt) == *committed
+}
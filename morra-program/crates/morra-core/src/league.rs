@@ -0,0 +1,59 @@
+//! Round-robin league scheduling and scoring.
+
+use crate::Outcome;
+
+pub const POINTS_FOR_WIN: u16 = 3;
+pub const POINTS_FOR_DRAW: u16 = 1;
+
+/// Number of fixtures in a single round robin of `players`.
+pub fn fixture_count(players: usize) -> usize {
+    players * players.saturating_sub(1) / 2
+}
+
+/// Write every pairing of `players` into `fixtures`, as indexes into the
+/// roster with the first taking the creator seat, and return how many were
+/// written (`fixture_count(players)`).
+///
+/// Fixtures are grouped into rounds by the circle method, so nobody plays
+/// twice in a round, and the fixed player alternates seats between rounds.
+pub fn schedule(players: usize, fixtures: &mut [(u8, u8)]) -> usize {
+    // Pad to an even count; pairings with the phantom player are byes.
+    let slots = players + players % 2;
+    let mut written = 0;
+    for round in 0..slots.saturating_sub(1) {
+        let at = |slot: usize| {
+            if slot == 0 {
+                0
+            } else {
+                (slot - 1 + round) % (slots - 1) + 1
+            }
+        };
+        for pair in 0..slots / 2 {
+            let (mut home, mut away) = (at(pair), at(slots - 1 - pair));
+            if home >= players || away >= players {
+                continue;
+            }
+            if pair == 0 && round % 2 == 1 {
+                core::mem::swap(&mut home, &mut away);
+            }
+            fixtures[written] = (home as u8, away as u8);
+            written += 1;
+        }
+    }
+    written
+}
+
+/// Points earned by the creator and opponent seats for a result.
+pub fn fixture_points(outcome: Outcome) -> (u16, u16) {
+    match outcome {
+        Outcome::CreatorWins => (POINTS_FOR_WIN, 0),
+        Outcome::OpponentWins => (0, POINTS_FOR_WIN),
+        Outcome::Draw => (POINTS_FOR_DRAW, POINTS_FOR_DRAW),
+    }
+}
+
+/// Each of `leaders` players' share of `pot`, and the rounding dust left
+/// over, which goes to the first leader in roster order.
+pub fn pot_share(pot: u64, leaders: u64) -> (u64, u64) {
+    (pot / leaders, pot % leaders)
+}
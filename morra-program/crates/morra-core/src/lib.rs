@@ -0,0 +1,31 @@
+//! Pure Morra rules, kept free of Anchor so the on-chain program and
+//! off-chain tools share one definition of the game.
+//!
+//! The crate is `no_std` and allocation-free: every function works on plain
+//! values and can't disagree with the chain because the chain calls it too.
+
+#![no_std]
+
+mod bankroll;
+mod bracket;
+mod commitment;
+mod league;
+mod matchmaking;
+mod payout;
+mod rating;
+mod rules;
+mod side_bet;
+mod status;
+mod variant;
+
+pub use bankroll::*;
+pub use bracket::*;
+pub use commitment::*;
+pub use league::*;
+pub use matchmaking::*;
+pub use payout::*;
+pub use rating::*;
+pub use rules::*;
+pub use side_bet::*;
+pub use status::*;
+pub use variant::*;
@@ -0,0 +1,17 @@
+//! Queue pairing for quick play.
+
+/// Indexes of the two oldest compatible entries in a FIFO queue: the oldest
+/// entry that has any partner, paired with its oldest partner.
+///
+/// `compatible` is called with the older entry first.
+pub fn oldest_compatible_pair<T>(
+    entries: &[T],
+    compatible: impl Fn(&T, &T) -> bool,
+) -> Option<(usize, usize)> {
+    entries.iter().enumerate().find_map(|(older, first)| {
+        entries[older + 1..]
+            .iter()
+            .position(|second| compatible(first, second))
+            .map(|offset| (older, older + 1 + offset))
+    })
+}
@@ -0,0 +1,38 @@
+//! How the escrowed pot is split once a game ends.
+
+use crate::Outcome;
+
+/// Lamports owed to each seat out of the escrowed pot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Payout {
+    pub creator: u64,
+    pub opponent: u64,
+}
+
+impl Payout {
+    pub fn total(&self) -> u64 {
+        self.creator + self.opponent
+    }
+}
+
+/// Split the pot of two `bet`s for `outcome`: the winner takes both bets
+/// and a draw hands each player their own bet back.
+///
+/// Returns `None` if the pot doesn't fit in a `u64`.
+pub fn payout(outcome: Outcome, bet: u64) -> Option<Payout> {
+    let pot = bet.checked_mul(2)?;
+    Some(match outcome {
+        Outcome::CreatorWins => Payout {
+            creator: pot,
+            opponent: 0,
+        },
+        Outcome::OpponentWins => Payout {
+            creator: 0,
+            opponent: pot,
+        },
+        Outcome::Draw => Payout {
+            creator: bet,
+            opponent: bet,
+        },
+    })
+}
@@ -0,0 +1,74 @@
+//! Integer Elo ratings for ranked games.
+//!
+//! Everything is fixed-point so the program and off-chain previews compute
+//! bit-identical ratings without floats.
+
+use crate::Outcome;
+
+/// Rating every new player starts at.
+pub const INITIAL_RATING: u32 = 1200;
+/// Most a single game can move a rating.
+pub const K_FACTOR: i64 = 32;
+/// Scores and expectations are expressed in basis points of a full win.
+pub const SCORE_SCALE: i64 = 10_000;
+
+/// Rating gap covered by each step of [`EXPECTED_SCORE`].
+const STEP: u32 = 25;
+
+/// Expected score in basis points for the higher-rated player at a rating
+/// lead of `index * STEP`, i.e. `1 / (1 + 10^(-lead / 400))`. Leads past
+/// the end of the table are treated as the last entry.
+const EXPECTED_SCORE: [i64; 33] = [
+    5000, 5359, 5715, 6063, 6401, 6725, 7034, 7325, 7597, 7850, 8083, 8296, 8490, 8666, 8823,
+    8965, 9091, 9203, 9302, 9390, 9468, 9536, 9595, 9648, 9693, 9733, 9768, 9799, 9825, 9848,
+    9868, 9886, 9901,
+];
+
+/// Expected score of a player rated `rating` against `opponent`, in basis
+/// points. The two sides' expectations always sum to [`SCORE_SCALE`].
+pub fn expected_score(rating: u32, opponent: u32) -> i64 {
+    let lead = rating.abs_diff(opponent);
+    let index = (lead / STEP) as usize;
+    let leader = if index + 1 >= EXPECTED_SCORE.len() {
+        EXPECTED_SCORE[EXPECTED_SCORE.len() - 1]
+    } else {
+        // Interpolate between table entries for leads off the grid
+        let (low, high) = (EXPECTED_SCORE[index], EXPECTED_SCORE[index + 1]);
+        low + (high - low) * i64::from(lead % STEP) / i64::from(STEP)
+    };
+    if rating >= opponent {
+        leader
+    } else {
+        SCORE_SCALE - leader
+    }
+}
+
+/// Rating change for a player rated `rating` who scored `score` basis
+/// points against `opponent`, rounded half away from zero.
+pub fn rating_delta(rating: u32, opponent: u32, score: i64) -> i64 {
+    let swing = K_FACTOR * (score - expected_score(rating, opponent));
+    let half = SCORE_SCALE / 2;
+    if swing >= 0 {
+        (swing + half) / SCORE_SCALE
+    } else {
+        (swing - half) / SCORE_SCALE
+    }
+}
+
+/// New `(creator, opponent)` ratings after a ranked game.
+///
+/// The deltas are equal and opposite, so rating points are conserved unless
+/// a rating would drop below zero.
+pub fn update_ratings(creator: u32, opponent: u32, outcome: Outcome) -> (u32, u32) {
+    let score = match outcome {
+        Outcome::CreatorWins => SCORE_SCALE,
+        Outcome::OpponentWins => 0,
+        Outcome::Draw => SCORE_SCALE / 2,
+    };
+    let delta = rating_delta(creator, opponent, score);
+    (apply(creator, delta), apply(opponent, -delta))
+}
+
+fn apply(rating: u32, delta: i64) -> u32 {
+    (i64::from(rating) + delta).clamp(0, i64::from(u32::MAX)) as u32
+}
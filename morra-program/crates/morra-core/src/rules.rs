@@ -0,0 +1,57 @@
+//! Move bounds and the winning rule.
+
+/// Smallest card a player may show.
+pub const MIN_CARD: u8 = 1;
+/// Largest card a player may show.
+pub const MAX_CARD: u8 = 5;
+/// Smallest total a player may call: both players showing [`MIN_CARD`].
+pub const MIN_PREDICTION: u8 = MIN_CARD * 2;
+/// Largest total a player may call: both players showing [`MAX_CARD`].
+pub const MAX_PREDICTION: u8 = MAX_CARD * 2;
+
+/// A revealed card and the total its player called.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub card: u8,
+    pub prediction: u8,
+}
+
+impl Move {
+    pub fn new(card: u8, prediction: u8) -> Self {
+        Self { card, prediction }
+    }
+}
+
+/// How a game ends once both moves are known.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    CreatorWins,
+    OpponentWins,
+    Draw,
+}
+
+pub fn is_valid_card(card: u8) -> bool {
+    (MIN_CARD..=MAX_CARD).contains(&card)
+}
+
+pub fn is_valid_prediction(prediction: u8) -> bool {
+    (MIN_PREDICTION..=MAX_PREDICTION).contains(&prediction)
+}
+
+/// A player wins by alone calling the total of both cards. If both or
+/// neither call it, the game is a draw.
+///
+/// Any `u8` input is accepted so the outcome stays defined even for moves
+/// that skipped validation.
+pub fn resolve(creator: Move, opponent: Move) -> Outcome {
+    // Widen before adding so two large cards can't overflow
+    let total = u16::from(creator.card) + u16::from(opponent.card);
+    let creator_wins = u16::from(creator.prediction) == total;
+    let opponent_wins = u16::from(opponent.prediction) == total;
+
+    match (creator_wins, opponent_wins) {
+        (true, false) => Outcome::CreatorWins,
+        (false, true) => Outcome::OpponentWins,
+        _ => Outcome::Draw,
+    }
+}
@@ -0,0 +1,25 @@
+//! Parimutuel settlement for spectator side bets.
+
+/// Proportional share of `pool` for `stake` out of the `winning` side's
+/// total stake, rounded down.
+pub fn parimutuel_share(stake: u64, winning: u64, pool: u64) -> u64 {
+    (u128::from(stake) * u128::from(pool) / u128::from(winning)) as u64
+}
+
+/// What one winning bettor is paid as they claim, given how much winning
+/// stake has claimed before them and how much of the pool it took. The
+/// claim that completes the winning side takes whatever rounding left
+/// behind, so the pool always pays out exactly.
+pub fn parimutuel_claim(
+    stake: u64,
+    winning: u64,
+    pool: u64,
+    claimed_stake: u64,
+    paid_out: u64,
+) -> u64 {
+    if claimed_stake + stake == winning {
+        pool - paid_out
+    } else {
+        parimutuel_share(stake, winning, pool)
+    }
+}
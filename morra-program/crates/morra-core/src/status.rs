@@ -0,0 +1,62 @@
+//! The game lifecycle as an explicit transition table.
+
+/// Where a game is in its lifecycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    WaitingForOpponent,
+    WaitingForReveal,
+    Draw,
+    Completed,
+    /// The escrow has been paid out.
+    Settled,
+    /// The game ended without a result: the creator withdrew or nobody
+    /// joined in time, or neither player moved before the move timeout.
+    Cancelled,
+    /// Both seats are filled but neither player has committed yet, as
+    /// with games spawned for a tournament match.
+    WaitingForCommits,
+}
+
+/// Every legal `(from, to)` status change. Anything not listed is rejected.
+pub const TRANSITIONS: &[(Status, Status)] = &[
+    // An opponent joins and escrows their bet.
+    (Status::WaitingForOpponent, Status::WaitingForReveal),
+    // The creator takes their bet back from an unjoined game.
+    (Status::WaitingForOpponent, Status::Cancelled),
+    // The second player commits to a pre-seated game.
+    (Status::WaitingForCommits, Status::WaitingForReveal),
+    // The second reveal resolves the game, as does a timeout when only one
+    // player has revealed: they win by forfeit.
+    (Status::WaitingForReveal, Status::Completed),
+    (Status::WaitingForReveal, Status::Draw),
+    // A timeout with only one commit forfeits the other player.
+    (Status::WaitingForCommits, Status::Completed),
+    // A timeout with neither player moving calls the game off.
+    (Status::WaitingForReveal, Status::Cancelled),
+    (Status::WaitingForCommits, Status::Cancelled),
+    // The escrow is paid out to the winner, or back to both players.
+    (Status::Completed, Status::Settled),
+    (Status::Draw, Status::Settled),
+];
+
+impl Status {
+    pub const ALL: [Status; 7] = [
+        Status::WaitingForOpponent,
+        Status::WaitingForReveal,
+        Status::Draw,
+        Status::Completed,
+        Status::Settled,
+        Status::Cancelled,
+        Status::WaitingForCommits,
+    ];
+
+    /// Whether the table allows moving from `self` to `to`.
+    pub fn can_transition_to(self, to: Status) -> bool {
+        TRANSITIONS.contains(&(self, to))
+    }
+
+    /// A terminal game has no outgoing transitions.
+    pub fn is_terminal(self) -> bool {
+        !TRANSITIONS.iter().any(|&(from, _)| from == self)
+    }
+}
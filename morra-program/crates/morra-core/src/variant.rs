@@ -0,0 +1,138 @@
+//! Rule variants for offline play and analysis. The program only plays
+//! [`RuleSet::STANDARD`], which resolves exactly like [`resolve`].
+
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use crate::rules::{resolve, Move, Outcome, MAX_CARD, MIN_CARD};
+
+/// How to split a game that [`resolve`] would call a draw: both players
+/// called the total, or neither did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Tiebreak {
+    /// Leave it drawn.
+    Draw,
+    /// The call nearer the total wins; equally near stays drawn.
+    Closest,
+    /// The smaller card wins; equal cards stay drawn.
+    LowerCard,
+}
+
+impl Tiebreak {
+    pub const ALL: [Tiebreak; 3] = [Tiebreak::Draw, Tiebreak::Closest, Tiebreak::LowerCard];
+
+    fn split(self, creator: Move, opponent: Move) -> Outcome {
+        let (creator_key, opponent_key) = match self {
+            Tiebreak::Draw => return Outcome::Draw,
+            Tiebreak::Closest => {
+                let total = i16::from(creator.card) + i16::from(opponent.card);
+                (
+                    (i16::from(creator.prediction) - total).abs(),
+                    (i16::from(opponent.prediction) - total).abs(),
+                )
+            }
+            Tiebreak::LowerCard => (i16::from(creator.card), i16::from(opponent.card)),
+        };
+        match creator_key.cmp(&opponent_key) {
+            core::cmp::Ordering::Less => Outcome::CreatorWins,
+            core::cmp::Ordering::Greater => Outcome::OpponentWins,
+            core::cmp::Ordering::Equal => Outcome::Draw,
+        }
+    }
+}
+
+impl fmt::Display for Tiebreak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Tiebreak::Draw => "draw",
+            Tiebreak::Closest => "closest",
+            Tiebreak::LowerCard => "lower-card",
+        })
+    }
+}
+
+/// Which cards may be shown and how ties are broken.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RuleSet {
+    /// Whether a closed fist, showing zero, is a legal card.
+    pub zero_allowed: bool,
+    /// What happens when both players call the total.
+    pub both_called: Tiebreak,
+    /// What happens when neither does.
+    pub neither_called: Tiebreak,
+}
+
+impl RuleSet {
+    /// The rules the program enforces.
+    pub const STANDARD: RuleSet = RuleSet {
+        zero_allowed: false,
+        both_called: Tiebreak::Draw,
+        neither_called: Tiebreak::Draw,
+    };
+
+    /// Every combination of the options, [`RuleSet::STANDARD`] first.
+    pub fn variants() -> impl Iterator<Item = RuleSet> {
+        [false, true].into_iter().flat_map(|zero_allowed| {
+            Tiebreak::ALL.into_iter().flat_map(move |both_called| {
+                Tiebreak::ALL
+                    .into_iter()
+                    .map(move |neither_called| RuleSet {
+                        zero_allowed,
+                        both_called,
+                        neither_called,
+                    })
+            })
+        })
+    }
+
+    pub fn cards(&self) -> RangeInclusive<u8> {
+        let min = if self.zero_allowed { 0 } else { MIN_CARD };
+        min..=MAX_CARD
+    }
+
+    /// Every total worth calling: the sums of two legal cards.
+    pub fn predictions(&self) -> RangeInclusive<u8> {
+        let cards = self.cards();
+        cards.start() * 2..=cards.end() * 2
+    }
+
+    pub fn is_valid(&self, mv: Move) -> bool {
+        self.cards().contains(&mv.card) && self.predictions().contains(&mv.prediction)
+    }
+
+    /// Like [`resolve`], with this rule set's tiebreaks applied to its
+    /// draws.
+    pub fn resolve(&self, creator: Move, opponent: Move) -> Outcome {
+        let outcome = resolve(creator, opponent);
+        if outcome != Outcome::Draw {
+            return outcome;
+        }
+        let total = u16::from(creator.card) + u16::from(opponent.card);
+        let tiebreak = if u16::from(creator.prediction) == total {
+            self.both_called
+        } else {
+            self.neither_called
+        };
+        tiebreak.split(creator, opponent)
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards = self.cards();
+        write!(
+            f,
+            "cards {}-{}, both called: {}, neither called: {}",
+            cards.start(),
+            cards.end(),
+            self.both_called,
+            self.neither_called
+        )
+    }
+}
@@ -0,0 +1,75 @@
+use morra_core::{bps_of, redemption_value, shares_for_deposit, VIRTUAL_SHARES};
+use proptest::prelude::*;
+
+#[test]
+fn shares_track_the_pool_value() {
+    assert_eq!(shares_for_deposit(100, 0, 0), Some(100 * VIRTUAL_SHARES));
+    assert_eq!(redemption_value(100 * VIRTUAL_SHARES, 100 * VIRTUAL_SHARES, 100), 100);
+    // The pool has roughly doubled, so a deposit buys about half as many
+    // shares, rounded down.
+    let shares = shares_for_deposit(100, 100 * VIRTUAL_SHARES, 200).unwrap();
+    assert_eq!(shares, 50_248);
+    assert_eq!(redemption_value(shares, 150_248, 300), 99);
+    assert_eq!(shares_for_deposit(u64::MAX, 0, 0), None);
+}
+
+#[test]
+fn rates_round_down() {
+    assert_eq!(bps_of(1_000, 250), 25);
+    assert_eq!(bps_of(39, 250), 0);
+    assert_eq!(bps_of(u64::MAX, 10_000), u64::MAX);
+}
+
+/// Whether a pool of `new_assets` backed by `new_shares` is worth at least
+/// as much per share as one of `assets` backed by `shares`, by
+/// cross-multiplying with the virtual stake included.
+fn no_worse(shares: u64, assets: u64, new_shares: u64, new_assets: u64) -> bool {
+    let value = |shares: u64, assets: u64| {
+        (u128::from(assets) + 1, u128::from(shares) + u128::from(VIRTUAL_SHARES))
+    };
+    let (old_assets, old_shares) = value(shares, assets);
+    let (new_assets, new_shares) = value(new_shares, new_assets);
+    new_assets * old_shares >= old_assets * new_shares
+}
+
+proptest! {
+    #[test]
+    fn deposits_withdrawals_and_donations_never_dilute_other_holders(
+        total_shares in 0..=u64::from(u32::MAX) * 1_000,
+        assets in 0..=u64::from(u32::MAX),
+        amount in 0..=u64::from(u32::MAX),
+        redeemed in 0..=u64::from(u32::MAX) * 1_000,
+        donation in 0..=u64::from(u32::MAX),
+    ) {
+        let minted = shares_for_deposit(amount, total_shares, assets).unwrap();
+        prop_assert!(no_worse(total_shares, assets, total_shares + minted, assets + amount));
+
+        let redeemed = redeemed.min(total_shares);
+        let paid = redemption_value(redeemed, total_shares, assets);
+        prop_assert!(paid <= assets);
+        prop_assert!(no_worse(total_shares, assets, total_shares - redeemed, assets - paid));
+
+        prop_assert!(no_worse(total_shares, assets, total_shares, assets + donation));
+    }
+
+    #[test]
+    fn inflating_the_price_with_a_donation_does_not_pay(
+        seed in 1..=u64::from(u32::MAX),
+        donation in 0..=u64::from(u32::MAX),
+        deposit in 1..=u64::from(u32::MAX),
+    ) {
+        // The attacker opens the pool, donates, and a victim deposits.
+        let attacker = shares_for_deposit(seed, 0, 0).unwrap();
+        let assets = seed + donation;
+        let victim = shares_for_deposit(deposit, attacker, assets).unwrap();
+        let total_shares = attacker + victim;
+        let assets = assets + deposit;
+
+        let taken = redemption_value(attacker, total_shares, assets);
+        prop_assert!(taken <= seed + donation);
+        // The victim loses at most what one share rounds away, which the
+        // donation has to pay a thousandfold for.
+        let kept = redemption_value(victim, total_shares, assets);
+        prop_assert!(deposit - kept <= 2 + donation / VIRTUAL_SHARES);
+    }
+}
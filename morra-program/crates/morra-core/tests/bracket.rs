@@ -0,0 +1,93 @@
+use morra_core::{
+    is_valid_bracket_size, match_count, next_match, placement_prizes, round_of, seed_leaves,
+};
+use proptest::prelude::*;
+
+#[test]
+fn sizes_must_be_powers_of_two() {
+    assert!(!is_valid_bracket_size(0));
+    assert!(!is_valid_bracket_size(1));
+    assert!(is_valid_bracket_size(2));
+    assert!(!is_valid_bracket_size(6));
+    assert!(is_valid_bracket_size(16));
+}
+
+#[test]
+fn winners_feed_the_next_round() {
+    // 8 players: quarterfinals 0..4, semifinals 4..6, final 6.
+    assert_eq!(match_count(8), 7);
+    assert_eq!(next_match(8, 0), Some((4, 0)));
+    assert_eq!(next_match(8, 1), Some((4, 1)));
+    assert_eq!(next_match(8, 2), Some((5, 0)));
+    assert_eq!(next_match(8, 3), Some((5, 1)));
+    assert_eq!(next_match(8, 4), Some((6, 0)));
+    assert_eq!(next_match(8, 5), Some((6, 1)));
+    assert_eq!(next_match(8, 6), None);
+    assert_eq!(next_match(2, 0), None);
+    assert_eq!(round_of(8, 5), 1);
+}
+
+#[test]
+fn top_seeds_are_kept_apart() {
+    let mut leaves = [0; 8];
+    seed_leaves(8, &mut leaves);
+    assert_eq!(leaves, [0, 7, 3, 4, 1, 6, 2, 5]);
+
+    let mut leaves = [0; 4];
+    seed_leaves(4, &mut leaves);
+    assert_eq!(leaves, [0, 3, 1, 2]);
+}
+
+#[test]
+fn missing_placements_fold_into_the_champion() {
+    assert_eq!(
+        placement_prizes(10_000, [true; 4]),
+        [6_000, 2_500, 750, 750]
+    );
+    assert_eq!(
+        placement_prizes(10_000, [true, true, false, true]),
+        [6_750, 2_500, 0, 750]
+    );
+    assert_eq!(
+        placement_prizes(10_000, [true, true, false, false]),
+        [7_500, 2_500, 0, 0]
+    );
+}
+
+proptest! {
+    #[test]
+    fn every_match_but_the_final_feeds_one_seat(exponent in 1..6u32) {
+        let size = 1usize << exponent;
+        let mut seats = vec![0; match_count(size)];
+        for index in 0..match_count(size) - 1 {
+            let (next, seat) = next_match(size, index).unwrap();
+            prop_assert!(next > index);
+            seats[next] |= 1 << seat;
+        }
+        prop_assert_eq!(next_match(size, match_count(size) - 1), None);
+        // Later-round matches are fed into both seats exactly once.
+        for (index, &fed) in seats.iter().enumerate().skip(size / 2) {
+            prop_assert_eq!(fed, 0b11, "match {}", index);
+        }
+    }
+
+    #[test]
+    fn seeding_is_a_permutation_with_mirrored_pairs(exponent in 1..6u32) {
+        let size = 1usize << exponent;
+        let mut leaves = [0u8; 32];
+        seed_leaves(size, &mut leaves);
+        let mut sorted = leaves[..size].to_vec();
+        sorted.sort();
+        prop_assert_eq!(sorted, (0..size as u8).collect::<Vec<_>>());
+        for pair in leaves[..size].chunks(2) {
+            prop_assert_eq!(usize::from(pair[0] + pair[1]), size - 1);
+        }
+    }
+
+    #[test]
+    fn prizes_pay_out_the_whole_pool(pool in any::<u64>(), present in any::<[bool; 3]>()) {
+        let prizes = placement_prizes(pool, [true, present[0], present[1], present[2]]);
+        let total: u128 = prizes.iter().map(|&prize| u128::from(prize)).sum();
+        prop_assert_eq!(total, u128::from(pool));
+    }
+}
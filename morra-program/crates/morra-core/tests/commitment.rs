@@ -0,0 +1,55 @@
+use morra_core::{commitment, commitment_preimage, verify_commitment, Move};
+use proptest::prelude::*;
+
+const PLAYER: [u8; 32] = [1; 32];
+const GAME: [u8; 32] = [2; 32];
+
+#[test]
+fn preimage_is_player_game_card_prediction_salt() {
+    let preimage = commitment_preimage(&PLAYER, &GAME, Move::new(3, 7), &[9; 32]);
+
+    assert_eq!(preimage[..32], PLAYER);
+    assert_eq!(preimage[32..64], GAME);
+    assert_eq!(preimage[64], 3);
+    assert_eq!(preimage[65], 7);
+    assert_eq!(preimage[66..], [9; 32]);
+}
+
+#[test]
+fn commitment_matches_known_digest() {
+    // sha256 of 32 bytes of 0x01, 32 of 0x02, 0x03 0x07, then 32 of 0x09.
+    let expected = [
+        0x75, 0x11, 0xe1, 0xb3, 0x30, 0x84, 0x68, 0x7f, 0x48, 0xf6, 0xe5, 0xcb, 0xd2, 0x0d,
+        0x02, 0x8b, 0x97, 0x7b, 0x1e, 0x47, 0x1e, 0xf8, 0x7c, 0xf8, 0xb8, 0x8f, 0x0c, 0xbc,
+        0xd4, 0x51, 0x6d, 0x2a,
+    ];
+    assert_eq!(commitment(&PLAYER, &GAME, Move::new(3, 7), &[9; 32]), expected);
+}
+
+#[test]
+fn copied_commitment_opens_for_nobody_else() {
+    let mv = Move::new(3, 7);
+    let committed = commitment(&PLAYER, &GAME, mv, &[9; 32]);
+
+    assert!(!verify_commitment(&committed, &[3; 32], &GAME, mv, &[9; 32]));
+    assert!(!verify_commitment(&committed, &PLAYER, &[3; 32], mv, &[9; 32]));
+}
+
+proptest! {
+    #[test]
+    fn only_the_committed_move_opens(
+        card in any::<u8>(),
+        prediction in any::<u8>(),
+        salt in any::<[u8; 32]>(),
+        other in any::<(u8, u8, [u8; 32])>(),
+    ) {
+        let mv = Move::new(card, prediction);
+        let committed = commitment(&PLAYER, &GAME, mv, &salt);
+        prop_assert!(verify_commitment(&committed, &PLAYER, &GAME, mv, &salt));
+
+        let (other_card, other_prediction, other_salt) = other;
+        let other_mv = Move::new(other_card, other_prediction);
+        prop_assume!((other_mv, other_salt) != (mv, salt));
+        prop_assert!(!verify_commitment(&committed, &PLAYER, &GAME, other_mv, &other_salt));
+    }
+}
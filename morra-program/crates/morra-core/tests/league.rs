@@ -0,0 +1,61 @@
+use morra_core::{fixture_count, fixture_points, pot_share, schedule, Outcome};
+use proptest::prelude::*;
+
+#[test]
+fn every_pair_meets_once() {
+    for players in 0..=8 {
+        let mut fixtures = [(0, 0); 28];
+        let written = schedule(players, &mut fixtures);
+        assert_eq!(written, fixture_count(players));
+
+        let mut met = [[false; 8]; 8];
+        for &(home, away) in &fixtures[..written] {
+            let (home, away) = (usize::from(home), usize::from(away));
+            assert!(home < players && away < players && home != away);
+            assert!(!met[home][away], "{home} and {away} meet twice");
+            met[home][away] = true;
+            met[away][home] = true;
+        }
+    }
+}
+
+#[test]
+fn nobody_plays_twice_in_a_round() {
+    let mut fixtures = [(0, 0); 28];
+    schedule(8, &mut fixtures);
+    for round in fixtures.chunks(4) {
+        let mut seen = [false; 8];
+        for &(home, away) in round {
+            for player in [home, away] {
+                assert!(!seen[usize::from(player)]);
+                seen[usize::from(player)] = true;
+            }
+        }
+    }
+}
+
+#[test]
+fn the_fixed_player_alternates_seats() {
+    let mut fixtures = [(0, 0); 6];
+    schedule(4, &mut fixtures);
+    assert_eq!(fixtures[0].0, 0);
+    assert_eq!(fixtures[2].1, 0);
+    assert_eq!(fixtures[4].0, 0);
+}
+
+#[test]
+fn wins_outscore_draws() {
+    assert_eq!(fixture_points(Outcome::CreatorWins), (3, 0));
+    assert_eq!(fixture_points(Outcome::OpponentWins), (0, 3));
+    assert_eq!(fixture_points(Outcome::Draw), (1, 1));
+}
+
+proptest! {
+    #[test]
+    fn pot_shares_account_for_every_lamport(pot in any::<u64>(), leaders in 1..=8u64) {
+        let (share, dust) = pot_share(pot, leaders);
+        prop_assert!(dust < leaders);
+        let total = u128::from(share) * u128::from(leaders) + u128::from(dust);
+        prop_assert_eq!(total, u128::from(pot));
+    }
+}
@@ -0,0 +1,57 @@
+use morra_core::{payout, Outcome, Payout, Status, TRANSITIONS};
+use proptest::prelude::*;
+
+#[test]
+fn only_settled_or_cancelled_games_are_terminal() {
+    for status in Status::ALL {
+        assert_eq!(
+            status.is_terminal(),
+            matches!(status, Status::Settled | Status::Cancelled)
+        );
+    }
+}
+
+#[test]
+fn table_has_no_self_loops() {
+    for &(from, to) in TRANSITIONS {
+        assert_ne!(from, to);
+    }
+    for status in Status::ALL {
+        assert!(!status.can_transition_to(status));
+    }
+}
+
+#[test]
+fn winner_takes_the_pot_and_draw_refunds() {
+    assert_eq!(
+        payout(Outcome::CreatorWins, 5),
+        Some(Payout { creator: 10, opponent: 0 })
+    );
+    assert_eq!(
+        payout(Outcome::OpponentWins, 5),
+        Some(Payout { creator: 0, opponent: 10 })
+    );
+    assert_eq!(
+        payout(Outcome::Draw, 5),
+        Some(Payout { creator: 5, opponent: 5 })
+    );
+}
+
+#[test]
+fn oversized_pot_is_rejected() {
+    assert_eq!(payout(Outcome::Draw, u64::MAX / 2 + 1), None);
+}
+
+proptest! {
+    #[test]
+    fn payout_pays_out_exactly_the_pot(
+        bet in 0..=u64::MAX / 2,
+        outcome in prop_oneof![
+            Just(Outcome::CreatorWins),
+            Just(Outcome::OpponentWins),
+            Just(Outcome::Draw),
+        ],
+    ) {
+        prop_assert_eq!(payout(outcome, bet).unwrap().total(), 2 * bet);
+    }
+}
@@ -0,0 +1,46 @@
+use morra_core::oldest_compatible_pair;
+use proptest::prelude::*;
+
+/// `(player, ranked)` as queued.
+type Entry = (u8, bool);
+
+fn compatible(older: &Entry, newer: &Entry) -> bool {
+    older.0 != newer.0 && older.1 == newer.1
+}
+
+#[test]
+fn pairs_the_two_oldest_entries() {
+    let queue = [(1, false), (2, false), (3, false)];
+    assert_eq!(oldest_compatible_pair(&queue, compatible), Some((0, 1)));
+}
+
+#[test]
+fn skips_incompatible_entries() {
+    let queue = [(1, true), (2, false), (1, false), (3, false)];
+    assert_eq!(oldest_compatible_pair(&queue, compatible), Some((1, 2)));
+
+    let queue = [(1, false), (1, false), (2, false)];
+    assert_eq!(oldest_compatible_pair(&queue, compatible), Some((0, 2)));
+}
+
+#[test]
+fn no_pair_without_a_partner() {
+    assert_eq!(oldest_compatible_pair::<Entry>(&[], compatible), None);
+    assert_eq!(oldest_compatible_pair(&[(1, false)], compatible), None);
+    assert_eq!(
+        oldest_compatible_pair(&[(1, false), (2, true)], compatible),
+        None
+    );
+}
+
+proptest! {
+    #[test]
+    fn pair_is_the_oldest_compatible_one(
+        queue in prop::collection::vec((0..4u8, any::<bool>()), 0..12),
+    ) {
+        let expected = (0..queue.len())
+            .flat_map(|i| (i + 1..queue.len()).map(move |j| (i, j)))
+            .find(|&(i, j)| compatible(&queue[i], &queue[j]));
+        prop_assert_eq!(oldest_compatible_pair(&queue, compatible), expected);
+    }
+}
@@ -0,0 +1,66 @@
+use morra_core::{
+    expected_score, rating_delta, update_ratings, Outcome, INITIAL_RATING, K_FACTOR,
+    SCORE_SCALE,
+};
+use proptest::prelude::*;
+
+#[test]
+fn equal_ratings_split_the_k_factor() {
+    assert_eq!(expected_score(INITIAL_RATING, INITIAL_RATING), SCORE_SCALE / 2);
+    assert_eq!(
+        update_ratings(1200, 1200, Outcome::CreatorWins),
+        (1216, 1184)
+    );
+    assert_eq!(
+        update_ratings(1200, 1200, Outcome::OpponentWins),
+        (1184, 1216)
+    );
+    assert_eq!(update_ratings(1200, 1200, Outcome::Draw), (1200, 1200));
+}
+
+#[test]
+fn upsets_move_ratings_further() {
+    // A 400-point favorite is expected to score about 91%.
+    assert_eq!(expected_score(1600, 1200), 9091);
+    assert_eq!(update_ratings(1600, 1200, Outcome::CreatorWins), (1603, 1197));
+    assert_eq!(update_ratings(1600, 1200, Outcome::OpponentWins), (1571, 1229));
+    assert_eq!(update_ratings(1600, 1200, Outcome::Draw), (1587, 1213));
+}
+
+#[test]
+fn huge_gaps_are_capped() {
+    assert_eq!(expected_score(3000, 0), expected_score(2000, 1200));
+    assert_eq!(update_ratings(5, 3000, Outcome::OpponentWins), (5, 3000));
+}
+
+#[test]
+fn ratings_never_go_negative() {
+    assert_eq!(update_ratings(10, 10, Outcome::OpponentWins), (0, 26));
+}
+
+proptest! {
+    #[test]
+    fn expectations_are_complementary(a in 0..4000u32, b in 0..4000u32) {
+        prop_assert_eq!(expected_score(a, b) + expected_score(b, a), SCORE_SCALE);
+    }
+
+    #[test]
+    fn expectation_grows_with_the_lead(a in 0..4000u32, b in 0..4000u32) {
+        prop_assert!(expected_score(a + 1, b) >= expected_score(a, b));
+    }
+
+    #[test]
+    fn ratings_are_zero_sum(
+        a in 100..4000u32,
+        b in 100..4000u32,
+        outcome in prop_oneof![
+            Just(Outcome::CreatorWins),
+            Just(Outcome::OpponentWins),
+            Just(Outcome::Draw),
+        ],
+    ) {
+        let (new_a, new_b) = update_ratings(a, b, outcome);
+        prop_assert_eq!(i64::from(new_a) + i64::from(new_b), i64::from(a) + i64::from(b));
+        prop_assert!(rating_delta(a, b, SCORE_SCALE).abs() <= K_FACTOR);
+    }
+}
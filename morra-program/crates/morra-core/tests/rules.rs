@@ -0,0 +1,52 @@
+use morra_core::{
+    is_valid_card, is_valid_prediction, resolve, Move, Outcome, MAX_CARD, MAX_PREDICTION,
+    MIN_CARD, MIN_PREDICTION,
+};
+use proptest::prelude::*;
+
+#[test]
+fn card_and_prediction_bounds() {
+    assert!(!is_valid_card(MIN_CARD - 1));
+    assert!(is_valid_card(MIN_CARD));
+    assert!(is_valid_card(MAX_CARD));
+    assert!(!is_valid_card(MAX_CARD + 1));
+
+    assert!(!is_valid_prediction(MIN_PREDICTION - 1));
+    assert!(is_valid_prediction(MIN_PREDICTION));
+    assert!(is_valid_prediction(MAX_PREDICTION));
+    assert!(!is_valid_prediction(MAX_PREDICTION + 1));
+}
+
+#[test]
+fn lone_correct_call_wins() {
+    assert_eq!(resolve(Move::new(3, 5), Move::new(2, 7)), Outcome::CreatorWins);
+    assert_eq!(resolve(Move::new(3, 7), Move::new(2, 5)), Outcome::OpponentWins);
+}
+
+#[test]
+fn shared_or_missed_call_draws() {
+    assert_eq!(resolve(Move::new(2, 5), Move::new(3, 5)), Outcome::Draw);
+    assert_eq!(resolve(Move::new(2, 9), Move::new(3, 2)), Outcome::Draw);
+}
+
+#[test]
+fn large_cards_do_not_overflow() {
+    assert_eq!(resolve(Move::new(255, 254), Move::new(255, 255)), Outcome::Draw);
+}
+
+proptest! {
+    #[test]
+    fn swapping_seats_swaps_the_outcome(
+        creator in any::<(u8, u8)>(),
+        opponent in any::<(u8, u8)>(),
+    ) {
+        let creator = Move::new(creator.0, creator.1);
+        let opponent = Move::new(opponent.0, opponent.1);
+        let swapped = match resolve(opponent, creator) {
+            Outcome::CreatorWins => Outcome::OpponentWins,
+            Outcome::OpponentWins => Outcome::CreatorWins,
+            Outcome::Draw => Outcome::Draw,
+        };
+        prop_assert_eq!(resolve(creator, opponent), swapped);
+    }
+}
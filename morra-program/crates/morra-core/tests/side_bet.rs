@@ -0,0 +1,36 @@
+use morra_core::{parimutuel_claim, parimutuel_share};
+use proptest::prelude::*;
+
+#[test]
+fn winners_split_the_pool_by_stake() {
+    // 1 and 3 backed the winner, 2 backed the loser.
+    assert_eq!(parimutuel_share(1, 4, 6), 1);
+    assert_eq!(parimutuel_share(3, 4, 6), 4);
+    assert_eq!(parimutuel_claim(1, 4, 6, 0, 0), 1);
+    assert_eq!(parimutuel_claim(3, 4, 6, 1, 1), 5);
+}
+
+#[test]
+fn a_lone_winner_takes_everything() {
+    assert_eq!(parimutuel_claim(7, 7, 100, 0, 0), 100);
+}
+
+proptest! {
+    #[test]
+    fn claims_pay_out_the_pool_exactly(
+        winners in prop::collection::vec(1..=u64::from(u32::MAX), 1..8),
+        losing in 0..=u64::from(u32::MAX),
+    ) {
+        let winning: u64 = winners.iter().sum();
+        let pool = winning + losing;
+        let (mut claimed_stake, mut paid_out) = (0, 0);
+        for &stake in &winners {
+            let paid = parimutuel_claim(stake, winning, pool, claimed_stake, paid_out);
+            prop_assert!(paid >= stake);
+            prop_assert!(paid <= parimutuel_share(stake, winning, pool) + winners.len() as u64);
+            claimed_stake += stake;
+            paid_out += paid;
+        }
+        prop_assert_eq!(paid_out, pool);
+    }
+}
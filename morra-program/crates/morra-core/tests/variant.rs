@@ -0,0 +1,89 @@
+use morra_core::{resolve, Move, Outcome, RuleSet, Tiebreak};
+use proptest::prelude::*;
+
+#[test]
+fn variants_cover_every_option_once() {
+    let variants: Vec<RuleSet> = RuleSet::variants().collect();
+    assert_eq!(variants.len(), 18);
+    assert_eq!(variants[0], RuleSet::STANDARD);
+    for (index, rules) in variants.iter().enumerate() {
+        assert!(!variants[index + 1..].contains(rules));
+    }
+}
+
+#[test]
+fn zero_widens_cards_and_calls() {
+    let zero = RuleSet {
+        zero_allowed: true,
+        ..RuleSet::STANDARD
+    };
+    assert_eq!(RuleSet::STANDARD.cards(), 1..=5);
+    assert_eq!(RuleSet::STANDARD.predictions(), 2..=10);
+    assert_eq!(zero.cards(), 0..=5);
+    assert_eq!(zero.predictions(), 0..=10);
+    assert!(zero.is_valid(Move::new(0, 0)));
+    assert!(!RuleSet::STANDARD.is_valid(Move::new(0, 3)));
+}
+
+#[test]
+fn tiebreaks_split_draws() {
+    let closest = RuleSet {
+        neither_called: Tiebreak::Closest,
+        ..RuleSet::STANDARD
+    };
+    // Total 5: 6 misses by one, 9 by four.
+    assert_eq!(
+        closest.resolve(Move::new(2, 6), Move::new(3, 9)),
+        Outcome::CreatorWins
+    );
+    assert_eq!(
+        closest.resolve(Move::new(2, 4), Move::new(3, 6)),
+        Outcome::Draw
+    );
+    // Both calling the total is never split by closeness.
+    assert_eq!(
+        closest.resolve(Move::new(2, 5), Move::new(3, 5)),
+        Outcome::Draw
+    );
+
+    let lower = RuleSet {
+        both_called: Tiebreak::LowerCard,
+        ..RuleSet::STANDARD
+    };
+    assert_eq!(
+        lower.resolve(Move::new(2, 5), Move::new(3, 5)),
+        Outcome::CreatorWins
+    );
+    // Neither called it, which lower's rules leave drawn.
+    assert_eq!(
+        lower.resolve(Move::new(2, 6), Move::new(3, 6)),
+        Outcome::Draw
+    );
+}
+
+proptest! {
+    #[test]
+    fn standard_rules_resolve_like_the_program(
+        creator in any::<(u8, u8)>(),
+        opponent in any::<(u8, u8)>(),
+    ) {
+        let creator = Move::new(creator.0, creator.1);
+        let opponent = Move::new(opponent.0, opponent.1);
+        prop_assert_eq!(RuleSet::STANDARD.resolve(creator, opponent), resolve(creator, opponent));
+    }
+
+    #[test]
+    fn tiebreaks_only_change_draws(
+        creator in (0u8..=5, 0u8..=10),
+        opponent in (0u8..=5, 0u8..=10),
+        rules in 0usize..18,
+    ) {
+        let creator = Move::new(creator.0, creator.1);
+        let opponent = Move::new(opponent.0, opponent.1);
+        let rules = RuleSet::variants().nth(rules).unwrap();
+        let standard = resolve(creator, opponent);
+        if standard != Outcome::Draw {
+            prop_assert_eq!(rules.resolve(creator, opponent), standard);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Permissionless crank for Morra timeouts: finds games nobody joined or
+//! that stalled waiting on a move, and ends them so their escrows don't
+//! sit locked forever. The program config may pay a reward per timeout.
+//!
+//! Tournament brackets are crank-driven too: a match game's result only
+//! reaches the bracket when someone sends `advance_tournament`, so the
+//! crank sends it for every match whose game has ended.
+
+mod plan;
+
+pub use plan::*;
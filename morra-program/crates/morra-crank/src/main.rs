@@ -0,0 +1,158 @@
+//! Crank CLI: poll for games past their timeout and send `expire_game` or
+//! `forfeit_game` for each, and `forfeit_house_game` for stalled house
+//! games. It also sends `advance_tournament` for bracket matches whose game
+//! has ended, which is the only way results reach a bracket. Anyone can run
+//! it; the reward, if the program config offers one, goes to the crank's
+//! key. House forfeits and bracket advances pay no reward.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Parser;
+use morra_client::{
+    advance_tournament, chain_time, expire_game, fetch_config, fetch_game, forfeit_game,
+    forfeit_house_game, games_in, house_games_in, tournaments_in, Instruction,
+};
+use morra_crank::{advanceable, live_matches, overdue, Timeout};
+use morra_program::{GameStatus, HouseGameStatus, TournamentStatus};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+#[derive(Parser)]
+#[command(about = "Enforce timeouts on stalled Morra games and advance tournament brackets")]
+struct Args {
+    /// Keypair that pays fees and collects crank rewards.
+    #[arg(long)]
+    keypair: PathBuf,
+    #[arg(long, default_value = "http://127.0.0.1:8899")]
+    rpc_url: String,
+    /// Seconds between scans.
+    #[arg(long, default_value_t = 30)]
+    interval: u64,
+    /// Most timeouts to send per scan.
+    #[arg(long, default_value_t = 50)]
+    max_per_scan: usize,
+    /// Only crank while the config pays at least this many lamports.
+    #[arg(long, default_value_t = 0)]
+    min_reward: u64,
+}
+
+struct Crank {
+    rpc: RpcClient,
+    keypair: Keypair,
+}
+
+impl Crank {
+    async fn send(&self, instruction: Instruction) -> anyhow::Result<()> {
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.keypair.pubkey()),
+            &[&self.keypair],
+            blockhash,
+        );
+        let signature = self.rpc.send_and_confirm_transaction(&transaction).await?;
+        println!("  {signature}");
+        Ok(())
+    }
+
+    async fn scan(&self, args: &Args) -> anyhow::Result<()> {
+        let config = fetch_config(&self.rpc).await?;
+        let reward = config.as_ref().map_or(0, |config| config.crank_reward);
+        if reward < args.min_reward {
+            return Ok(());
+        }
+
+        let mut games = Vec::new();
+        for status in [
+            GameStatus::WaitingForOpponent,
+            GameStatus::WaitingForCommits,
+            GameStatus::WaitingForReveal,
+        ] {
+            games.extend(games_in(&self.rpc, status).await?);
+        }
+        let now = chain_time(&self.rpc).await?;
+
+        let cranker = self.keypair.pubkey();
+        let rewarded = config.is_some();
+        for (address, game, timeout) in overdue(&games, now, args.max_per_scan) {
+            println!("{address}: {timeout:?}");
+            let instruction = match timeout {
+                Timeout::Expire => expire_game(address, game, &cranker, rewarded),
+                Timeout::Forfeit => forfeit_game(address, game, &cranker, rewarded),
+            };
+            // Another crank may have got there first; move on either way.
+            if let Err(err) = self.send(instruction).await {
+                eprintln!("{address}: {timeout:?} failed: {err:#}");
+            }
+        }
+
+        let mut house_games = Vec::new();
+        for status in [
+            HouseGameStatus::WaitingForHouse,
+            HouseGameStatus::WaitingForPlayer,
+            HouseGameStatus::WaitingForReveal,
+        ] {
+            house_games.extend(house_games_in(&self.rpc, status).await?);
+        }
+        let stalled = house_games
+            .iter()
+            .filter(|(_, game)| game.timed_out(now))
+            .take(args.max_per_scan);
+        for (address, game) in stalled {
+            println!("{address}: house forfeit");
+            if let Err(err) = self.send(forfeit_house_game(address, game, &cranker)).await {
+                eprintln!("{address}: house forfeit failed: {err:#}");
+            }
+        }
+
+        self.advance_brackets(args.max_per_scan).await
+    }
+
+    /// Carry every ended match game into its bracket, up to `limit` of them.
+    async fn advance_brackets(&self, limit: usize) -> anyhow::Result<()> {
+        let mut sent = 0;
+        for (tournament, state) in tournaments_in(&self.rpc, TournamentStatus::InProgress).await? {
+            for (match_index, game) in live_matches(&state) {
+                if sent == limit {
+                    return Ok(());
+                }
+                let Some(game_state) = fetch_game(&self.rpc, &game).await? else {
+                    continue;
+                };
+                if !advanceable(&game_state) {
+                    continue;
+                }
+                println!("{tournament}: advance match {match_index}");
+                sent += 1;
+                let instruction = advance_tournament(&tournament, &game, match_index);
+                if let Err(err) = self.send(instruction).await {
+                    eprintln!("{tournament}: advance match {match_index} failed: {err:#}");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let keypair = read_keypair_file(&args.keypair)
+        .map_err(|err| anyhow::anyhow!("reading {}: {err}", args.keypair.display()))?;
+    let crank = Crank {
+        rpc: RpcClient::new(args.rpc_url.clone()),
+        keypair,
+    };
+    println!("cranking as {}", crank.keypair.pubkey());
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(args.interval));
+    loop {
+        ticker.tick().await;
+        if let Err(err) = crank.scan(&args).await {
+            eprintln!("scan failed: {err:#}");
+        }
+    }
+}
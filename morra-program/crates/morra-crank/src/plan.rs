@@ -0,0 +1,51 @@
+use morra_program::{Game, GameStatus, Tournament};
+use solana_pubkey::Pubkey;
+
+/// The instruction that ends a timed-out game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timeout {
+    /// Cancel an unjoined game and refund its creator.
+    Expire,
+    /// Forfeit the player who stalled, or refund both if neither moved.
+    Forfeit,
+}
+
+/// The timeout `game` is due for at `now`, if any.
+pub fn due(game: &Game, now: i64) -> Option<Timeout> {
+    if !game.timed_out(now) {
+        return None;
+    }
+    Some(match game.status {
+        GameStatus::WaitingForOpponent => Timeout::Expire,
+        _ => Timeout::Forfeit,
+    })
+}
+
+/// The games due a timeout at `now`, longest overdue first, at most `limit`
+/// of them.
+pub fn overdue(games: &[(Pubkey, Game)], now: i64, limit: usize) -> Vec<(&Pubkey, &Game, Timeout)> {
+    let mut due: Vec<_> = games
+        .iter()
+        .filter_map(|(address, game)| Some((address, game, due(game, now)?)))
+        .collect();
+    due.sort_by_key(|(_, game, _)| game.timeout_at());
+    due.truncate(limit);
+    due
+}
+
+/// The bracket matches of `tournament` with a game in play, as
+/// `(match_index, game)`.
+pub fn live_matches(tournament: &Tournament) -> Vec<(u8, Pubkey)> {
+    tournament
+        .matches
+        .iter()
+        .enumerate()
+        .filter_map(|(index, bracket_match)| Some((u8::try_from(index).ok()?, bracket_match.game?)))
+        .collect()
+}
+
+/// Whether a match's `game` is ready to carry into the bracket: it was
+/// resolved, or called off and needs a rematch.
+pub fn advanceable(game: &Game) -> bool {
+    game.outcome().is_some() || game.status == GameStatus::Cancelled
+}
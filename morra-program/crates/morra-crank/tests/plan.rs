@@ -0,0 +1,111 @@
+use morra_crank::{advanceable, due, live_matches, overdue, Timeout};
+use morra_program::{
+    BracketMatch, Game, GameStatus, PlayerMove, Tournament, TournamentStatus, JOIN_TIMEOUT,
+    MOVE_TIMEOUT,
+};
+use solana_pubkey::Pubkey;
+
+fn game(status: GameStatus, created_at: i64, last_action_at: i64) -> Game {
+    Game {
+        creator: Pubkey::new_unique(),
+        game_id: 0,
+        bump: 0,
+        opponent: Pubkey::new_unique(),
+        bet_amount: 1_000_000_000,
+        ranked: false,
+        lobby_page: 0,
+        status,
+        creator_move: PlayerMove::committed([1; 32]),
+        opponent_move: PlayerMove::default(),
+        winner: None,
+        created_at,
+        last_action_at,
+        creator_sponsor: None,
+        opponent_sponsor: None,
+        queue: None,
+    }
+}
+
+#[test]
+fn timeouts_fall_due_by_phase() {
+    let waiting = game(GameStatus::WaitingForOpponent, 0, 0);
+    assert_eq!(due(&waiting, JOIN_TIMEOUT - 1), None);
+    assert_eq!(due(&waiting, JOIN_TIMEOUT), Some(Timeout::Expire));
+
+    for status in [GameStatus::WaitingForCommits, GameStatus::WaitingForReveal] {
+        let stalled = game(status, 0, 100);
+        assert_eq!(due(&stalled, 100 + MOVE_TIMEOUT - 1), None);
+        assert_eq!(due(&stalled, 100 + MOVE_TIMEOUT), Some(Timeout::Forfeit));
+    }
+
+    for status in [GameStatus::Draw, GameStatus::Completed, GameStatus::Settled] {
+        assert_eq!(due(&game(status, 0, 0), i64::MAX), None);
+    }
+}
+
+#[test]
+fn longest_overdue_goes_first() {
+    let now = JOIN_TIMEOUT + MOVE_TIMEOUT;
+    let games: Vec<_> = [
+        game(GameStatus::WaitingForReveal, 0, JOIN_TIMEOUT),
+        game(GameStatus::WaitingForOpponent, 0, 0),
+        game(GameStatus::WaitingForReveal, 0, now),
+        game(GameStatus::Settled, 0, 0),
+    ]
+    .into_iter()
+    .map(|game| (Pubkey::new_unique(), game))
+    .collect();
+
+    let picked = |limit| -> Vec<(Pubkey, Timeout)> {
+        overdue(&games, now, limit)
+            .into_iter()
+            .map(|(address, _, timeout)| (*address, timeout))
+            .collect()
+    };
+    assert_eq!(
+        picked(10),
+        [
+            (games[1].0, Timeout::Expire),
+            (games[0].0, Timeout::Forfeit)
+        ]
+    );
+    assert_eq!(picked(1), [(games[1].0, Timeout::Expire)]);
+}
+
+#[test]
+fn ended_match_games_are_advanced() {
+    let game_address = Pubkey::new_unique();
+    let tournament = Tournament {
+        organizer: Pubkey::new_unique(),
+        id: 0,
+        bump: 0,
+        entry_fee: 1_000_000_000,
+        max_players: 4,
+        registration_ends_at: 0,
+        status: TournamentStatus::InProgress,
+        players: Vec::new(),
+        matches: vec![
+            BracketMatch::default(),
+            BracketMatch {
+                game: Some(game_address),
+                ..BracketMatch::default()
+            },
+            BracketMatch::default(),
+        ],
+        prize_pool: 0,
+        prizes_claimed: 0,
+    };
+    assert_eq!(live_matches(&tournament), [(1, game_address)]);
+
+    for status in [
+        GameStatus::Completed,
+        GameStatus::Draw,
+        GameStatus::Settled,
+        GameStatus::Cancelled,
+    ] {
+        assert!(advanceable(&game(status, 0, 0)), "{status:?}");
+    }
+    for status in [GameStatus::WaitingForCommits, GameStatus::WaitingForReveal] {
+        assert!(!advanceable(&game(status, 0, 0)), "{status:?}");
+    }
+}
@@ -0,0 +1,136 @@
+use morra_program::{Game, GameStatus, PlayerMove};
+use serde::Serialize;
+use solana_pubkey::Pubkey;
+
+/// Something a player watching a game wants to hear about. Commits and
+/// reveals are named from the other seat's point of view, since a
+/// player's own actions aren't news to them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GameEvent {
+    Joined {
+        game: String,
+        opponent: String,
+    },
+    OpponentCommitted {
+        game: String,
+        player: String,
+    },
+    OpponentRevealed {
+        game: String,
+        player: String,
+        card: u8,
+        prediction: u8,
+    },
+    /// The game resolved; `winner` is `None` on a draw.
+    Resolved {
+        game: String,
+        winner: Option<String>,
+    },
+    /// `player` can now claim `amount` lamports, through `claim_winnings`
+    /// or `refund_draw`.
+    Claimable {
+        game: String,
+        player: String,
+        amount: u64,
+    },
+}
+
+impl GameEvent {
+    /// Whether `viewer` should be sent this event. Spectators, with no
+    /// viewer, see everything.
+    pub fn concerns(&self, viewer: Option<&Pubkey>) -> bool {
+        let Some(viewer) = viewer.map(Pubkey::to_string) else {
+            return true;
+        };
+        match self {
+            Self::OpponentCommitted { player, .. } | Self::OpponentRevealed { player, .. } => {
+                *player != viewer
+            }
+            Self::Claimable { player, .. } => *player == viewer,
+            Self::Joined { .. } | Self::Resolved { .. } => true,
+        }
+    }
+}
+
+fn seats(game: &Game) -> [(Pubkey, &PlayerMove); 2] {
+    [
+        (game.creator, &game.creator_move),
+        (game.opponent, &game.opponent_move),
+    ]
+}
+
+/// What the winner (or, on a draw, each player) can claim from a resolved
+/// game, net of any sponsor's reimbursement.
+fn claimable(game: &Game) -> Vec<(Pubkey, u64)> {
+    let Ok(payout) = game.payout() else {
+        return Vec::new();
+    };
+    match game.winner {
+        None => vec![(game.creator, payout.creator), (game.opponent, payout.opponent)],
+        Some(winner) => {
+            let amount = if winner == game.creator {
+                payout.creator
+            } else {
+                payout.opponent
+            };
+            let reimbursement = game
+                .sponsor_of(&winner)
+                .map_or(0, |sponsor| sponsor.reimbursement);
+            vec![(winner, amount - reimbursement)]
+        }
+    }
+}
+
+/// The events between two snapshots of the game at `address`. A game seen
+/// for the first time has no history to report.
+pub fn diff(address: &Pubkey, before: Option<&Game>, after: &Game) -> Vec<GameEvent> {
+    let Some(before) = before else {
+        return Vec::new();
+    };
+    let game = address.to_string();
+    let mut events = Vec::new();
+
+    let joined = before.opponent == Pubkey::default() && after.opponent != Pubkey::default();
+    if joined {
+        events.push(GameEvent::Joined {
+            game: game.clone(),
+            opponent: after.opponent.to_string(),
+        });
+    }
+
+    for ((_, old), (player, new)) in seats(before).into_iter().zip(seats(after)) {
+        // Joining commits the opponent's move too; `Joined` covers it.
+        let joined_seat = joined && player == after.opponent;
+        if old.committed_hash.is_none() && new.committed_hash.is_some() && !joined_seat {
+            events.push(GameEvent::OpponentCommitted {
+                game: game.clone(),
+                player: player.to_string(),
+            });
+        }
+        if let (None, Some(revealed)) = (old.revealed(), new.revealed()) {
+            events.push(GameEvent::OpponentRevealed {
+                game: game.clone(),
+                player: player.to_string(),
+                card: revealed.card,
+                prediction: revealed.prediction,
+            });
+        }
+    }
+
+    let resolved = matches!(after.status, GameStatus::Completed | GameStatus::Draw);
+    if resolved && before.status != after.status {
+        events.push(GameEvent::Resolved {
+            game: game.clone(),
+            winner: after.winner.map(|winner| winner.to_string()),
+        });
+        events.extend(claimable(after).into_iter().map(|(player, amount)| {
+            GameEvent::Claimable {
+                game: game.clone(),
+                player: player.to_string(),
+                amount,
+            }
+        }));
+    }
+    events
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use morra_program::Game;
+use solana_pubkey::Pubkey;
+use tokio::sync::broadcast;
+
+use crate::events::{diff, GameEvent};
+
+/// Events buffered per connection before a slow one starts missing them.
+const EVENT_BUFFER: usize = 1_024;
+
+/// Last seen state of every game, and the channel their events fan out on.
+pub struct Hub {
+    games: Mutex<HashMap<Pubkey, Game>>,
+    events: broadcast::Sender<(Pubkey, GameEvent)>,
+}
+
+impl Default for Hub {
+    fn default() -> Self {
+        Self {
+            games: Mutex::new(HashMap::new()),
+            events: broadcast::channel(EVENT_BUFFER).0,
+        }
+    }
+}
+
+impl Hub {
+    /// Remember `game` as a baseline without announcing anything, unless a
+    /// newer snapshot already arrived.
+    pub fn seed(&self, address: Pubkey, game: Game) {
+        self.games.lock().unwrap().entry(address).or_insert(game);
+    }
+
+    /// Record a new snapshot and broadcast whatever changed since the last.
+    pub fn observe(&self, address: Pubkey, game: Game) -> Vec<GameEvent> {
+        let mut games = self.games.lock().unwrap();
+        let events = diff(&address, games.get(&address), &game);
+        games.insert(address, game);
+        for event in &events {
+            // Nobody listening is fine.
+            let _ = self.events.send((address, event.clone()));
+        }
+        events
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<(Pubkey, GameEvent)> {
+        self.events.subscribe()
+    }
+}
@@ -0,0 +1,16 @@
+//! Pushes game state changes to players over WebSockets, so nobody has to
+//! refresh to learn their opponent joined or revealed.
+//!
+//! A [`Hub`] keeps the last seen state of every `Game` account and turns
+//! each new snapshot into typed [`GameEvent`]s; connections subscribe to
+//! the games they care about.
+
+mod events;
+mod hub;
+mod server;
+mod source;
+
+pub use events::*;
+pub use hub::*;
+pub use server::*;
+pub use source::*;
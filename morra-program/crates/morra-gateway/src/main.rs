@@ -0,0 +1,32 @@
+//! Gateway CLI: watch `Game` accounts on an RPC node and serve their events
+//! at `ws://<listen>/ws`.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use clap::Parser;
+use morra_gateway::{router, watch, Hub};
+
+#[derive(Parser)]
+#[command(about = "Push Morra game events over WebSockets")]
+struct Args {
+    #[arg(long, default_value = "http://127.0.0.1:8899")]
+    rpc_url: String,
+    #[arg(long, default_value = "ws://127.0.0.1:8900")]
+    ws_url: String,
+    #[arg(long, default_value = "127.0.0.1:3001")]
+    listen: SocketAddr,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let hub = Arc::new(Hub::default());
+
+    let listener = tokio::net::TcpListener::bind(args.listen).await?;
+    println!("pushing game events on ws://{}/ws", args.listen);
+    tokio::select! {
+        watched = watch(hub.clone(), args.rpc_url, &args.ws_url) => watched,
+        served = axum::serve(listener, router(hub)) => Ok(served?),
+    }
+}
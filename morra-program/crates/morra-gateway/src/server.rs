@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::State;
+use axum::response::Response;
+use axum::routing::get;
+use axum::Router;
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::hub::Hub;
+
+/// What clients send: which games to watch, and optionally which seat
+/// they watch as.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ClientMessage {
+    Subscribe { game: String, player: Option<String> },
+    Unsubscribe { game: String },
+}
+
+/// Replies to client messages, sent alongside [`crate::GameEvent`]s.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Notice {
+    Subscribed { game: String },
+    Unsubscribed { game: String },
+    /// The connection fell behind and dropped `missed` events; refetch the
+    /// games to catch up.
+    Lagged { missed: u64 },
+    Error { message: String },
+}
+
+fn parse(key: &str) -> Result<Pubkey, Notice> {
+    Pubkey::from_str(key).map_err(|_| Notice::Error {
+        message: format!("invalid pubkey: {key}"),
+    })
+}
+
+/// Apply a client message to the connection's subscriptions, mapping each
+/// game to the player watching it.
+fn apply(
+    text: &str,
+    subscriptions: &mut HashMap<Pubkey, Option<Pubkey>>,
+) -> Result<Notice, Notice> {
+    let message = serde_json::from_str(text).map_err(|err| Notice::Error {
+        message: err.to_string(),
+    })?;
+    Ok(match message {
+        ClientMessage::Subscribe { game, player } => {
+            let address = parse(&game)?;
+            let player = player.as_deref().map(parse).transpose()?;
+            subscriptions.insert(address, player);
+            Notice::Subscribed { game }
+        }
+        ClientMessage::Unsubscribe { game } => {
+            subscriptions.remove(&parse(&game)?);
+            Notice::Unsubscribed { game }
+        }
+    })
+}
+
+fn text(message: &impl Serialize) -> Message {
+    Message::text(serde_json::to_string(message).expect("events serialize"))
+}
+
+async fn connection(socket: WebSocket, hub: Arc<Hub>) {
+    let (mut sender, mut receiver) = socket.split();
+    let mut events = hub.subscribe();
+    let mut subscriptions = HashMap::new();
+
+    loop {
+        let outgoing = tokio::select! {
+            incoming = receiver.next() => match incoming {
+                Some(Ok(Message::Text(message))) => {
+                    let (Ok(notice) | Err(notice)) = apply(&message, &mut subscriptions);
+                    text(&notice)
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                Some(Ok(_)) => continue,
+            },
+            event = events.recv() => match event {
+                Ok((game, event)) => match subscriptions.get(&game) {
+                    Some(viewer) if event.concerns(viewer.as_ref()) => text(&event),
+                    _ => continue,
+                },
+                Err(RecvError::Lagged(missed)) => text(&Notice::Lagged { missed }),
+                Err(RecvError::Closed) => return,
+            },
+        };
+        if sender.send(outgoing).await.is_err() {
+            return;
+        }
+    }
+}
+
+async fn upgrade(State(hub): State<Arc<Hub>>, socket: WebSocketUpgrade) -> Response {
+    socket.on_upgrade(|socket| connection(socket, hub))
+}
+
+/// Serves the gateway's WebSocket endpoint at `/ws`.
+pub fn router(hub: Arc<Hub>) -> Router {
+    Router::new().route("/ws", get(upgrade)).with_state(hub)
+}
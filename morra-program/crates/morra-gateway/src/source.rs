@@ -0,0 +1,62 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use futures::StreamExt;
+use morra_program::Game;
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_pubkey::Pubkey;
+use solana_pubsub_client::nonblocking::pubsub_client::PubsubClient;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+
+use crate::hub::Hub;
+
+/// Program accounts that start with the `Game` discriminator.
+fn games_only() -> RpcProgramAccountsConfig {
+    RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            Game::DISCRIMINATOR.to_vec(),
+        ))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    }
+}
+
+fn decode(data: &[u8]) -> Option<Game> {
+    Game::try_deserialize(&mut &data[..]).ok()
+}
+
+/// Feed `hub` from an RPC node: subscribe to `Game` account changes, seed
+/// the current state of every game, then observe each change as it lands.
+pub async fn watch(hub: Arc<Hub>, rpc_url: String, ws_url: &str) -> anyhow::Result<()> {
+    let pubsub = PubsubClient::new(ws_url).await?;
+    let (mut updates, _unsubscribe) = pubsub
+        .program_subscribe(&morra_program::ID, Some(games_only()))
+        .await?;
+
+    // Subscribe first so nothing lands between the snapshot and the stream.
+    let rpc = RpcClient::new(rpc_url);
+    for (address, account) in rpc
+        .get_program_accounts_with_config(&morra_program::ID, games_only())
+        .await?
+    {
+        if let Some(game) = decode(&account.data) {
+            hub.seed(address, game);
+        }
+    }
+
+    while let Some(update) = updates.next().await {
+        let address = Pubkey::from_str(&update.value.pubkey)?;
+        let game = update.value.account.data.decode().and_then(|data| decode(&data));
+        if let Some(game) = game {
+            hub.observe(address, game);
+        }
+    }
+    anyhow::bail!("account subscription closed")
+}
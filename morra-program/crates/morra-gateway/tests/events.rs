@@ -0,0 +1,134 @@
+use morra_gateway::{diff, GameEvent};
+use morra_program::{Game, GameStatus, PlayerMove, Sponsor};
+use solana_pubkey::Pubkey;
+
+const BET: u64 = 1_000_000_000;
+
+fn waiting() -> Game {
+    Game {
+        creator: Pubkey::new_unique(),
+        game_id: 0,
+        bump: 0,
+        opponent: Pubkey::default(),
+        bet_amount: BET,
+        ranked: false,
+        lobby_page: 0,
+        status: GameStatus::WaitingForOpponent,
+        creator_move: PlayerMove::committed([1; 32]),
+        opponent_move: PlayerMove::default(),
+        winner: None,
+        created_at: 0,
+        last_action_at: 0,
+        creator_sponsor: None,
+        opponent_sponsor: None,
+        queue: None,
+    }
+}
+
+fn reveal(player_move: &mut PlayerMove, card: u8, prediction: u8) {
+    player_move.card = Some(card);
+    player_move.prediction = Some(prediction);
+}
+
+#[test]
+fn first_sighting_has_no_events() {
+    assert!(diff(&Pubkey::new_unique(), None, &waiting()).is_empty());
+}
+
+#[test]
+fn join_reveal_and_resolution() {
+    let address = Pubkey::new_unique();
+    let game = address.to_string();
+    let before = waiting();
+    let mut joined = before.clone();
+    joined.opponent = Pubkey::new_unique();
+    joined.opponent_move = PlayerMove::committed([2; 32]);
+    joined.status = GameStatus::WaitingForReveal;
+
+    assert_eq!(
+        diff(&address, Some(&before), &joined),
+        [GameEvent::Joined {
+            game: game.clone(),
+            opponent: joined.opponent.to_string(),
+        }]
+    );
+
+    let mut revealed = joined.clone();
+    reveal(&mut revealed.creator_move, 3, 5);
+    assert_eq!(
+        diff(&address, Some(&joined), &revealed),
+        [GameEvent::OpponentRevealed {
+            game: game.clone(),
+            player: joined.creator.to_string(),
+            card: 3,
+            prediction: 5,
+        }]
+    );
+
+    let mut resolved = revealed.clone();
+    reveal(&mut resolved.opponent_move, 2, 7);
+    resolved.status = GameStatus::Completed;
+    resolved.winner = Some(resolved.creator);
+    resolved.creator_sponsor = Some(Sponsor {
+        relayer: Pubkey::new_unique(),
+        reimbursement: 5_000,
+    });
+    let creator = resolved.creator.to_string();
+    assert_eq!(
+        diff(&address, Some(&revealed), &resolved),
+        [
+            GameEvent::OpponentRevealed {
+                game: game.clone(),
+                player: resolved.opponent.to_string(),
+                card: 2,
+                prediction: 7,
+            },
+            GameEvent::Resolved {
+                game: game.clone(),
+                winner: Some(creator.clone()),
+            },
+            GameEvent::Claimable {
+                game,
+                player: creator,
+                amount: 2 * BET - 5_000,
+            },
+        ]
+    );
+}
+
+#[test]
+fn spawned_game_commits_and_draws() {
+    let address = Pubkey::new_unique();
+    let mut spawned = waiting();
+    spawned.opponent = Pubkey::new_unique();
+    spawned.creator_move = PlayerMove::default();
+    spawned.status = GameStatus::WaitingForCommits;
+
+    let mut committed = spawned.clone();
+    committed.opponent_move = PlayerMove::committed([2; 32]);
+    let events = diff(&address, Some(&spawned), &committed);
+    assert_eq!(
+        events,
+        [GameEvent::OpponentCommitted {
+            game: address.to_string(),
+            player: spawned.opponent.to_string(),
+        }]
+    );
+    // The committing player doesn't hear about their own commit.
+    assert!(!events[0].concerns(Some(&spawned.opponent)));
+    assert!(events[0].concerns(Some(&spawned.creator)));
+    assert!(events[0].concerns(None));
+
+    let mut drawn = committed.clone();
+    drawn.creator_move = PlayerMove::committed([1; 32]);
+    reveal(&mut drawn.creator_move, 2, 5);
+    reveal(&mut drawn.opponent_move, 3, 5);
+    drawn.status = GameStatus::Draw;
+    let claimable: Vec<_> = diff(&address, Some(&committed), &drawn)
+        .into_iter()
+        .filter(|event| matches!(event, GameEvent::Claimable { .. }))
+        .collect();
+    assert_eq!(claimable.len(), 2);
+    assert!(claimable[0].concerns(Some(&drawn.creator)));
+    assert!(!claimable[0].concerns(Some(&drawn.opponent)));
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::{SinkExt, StreamExt};
+use morra_gateway::{router, Hub};
+use morra_program::{Game, GameStatus, PlayerMove};
+use serde_json::{json, Value};
+use solana_pubkey::Pubkey;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+type Socket = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+fn waiting(creator: Pubkey) -> Game {
+    Game {
+        creator,
+        game_id: 0,
+        bump: 0,
+        opponent: Pubkey::default(),
+        bet_amount: 1_000_000_000,
+        ranked: false,
+        lobby_page: 0,
+        status: GameStatus::WaitingForOpponent,
+        creator_move: PlayerMove::committed([1; 32]),
+        opponent_move: PlayerMove::default(),
+        winner: None,
+        created_at: 0,
+        last_action_at: 0,
+        creator_sponsor: None,
+        opponent_sponsor: None,
+        queue: None,
+    }
+}
+
+async fn serve(hub: Arc<Hub>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router(hub)).await });
+    format!("ws://{address}/ws")
+}
+
+async fn send(socket: &mut Socket, message: Value) -> Value {
+    socket
+        .send(Message::text(message.to_string()))
+        .await
+        .unwrap();
+    next(socket).await
+}
+
+async fn next(socket: &mut Socket) -> Value {
+    let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+        .await
+        .expect("no message in time")
+        .unwrap()
+        .unwrap();
+    serde_json::from_str(message.to_text().unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn subscribers_hear_about_their_games() {
+    let hub = Arc::new(Hub::default());
+    let url = serve(hub.clone()).await;
+    let (creator, address, other) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    hub.seed(address, waiting(creator));
+    hub.seed(other, waiting(Pubkey::new_unique()));
+
+    let (mut socket, _) = connect_async(&url).await.unwrap();
+    let reply = send(
+        &mut socket,
+        json!({ "action": "subscribe", "game": address.to_string(), "player": creator.to_string() }),
+    )
+    .await;
+    assert_eq!(reply, json!({ "type": "subscribed", "game": address.to_string() }));
+
+    // Another game's events don't reach this connection.
+    let mut elsewhere = waiting(Pubkey::new_unique());
+    elsewhere.opponent = Pubkey::new_unique();
+    hub.observe(other, elsewhere);
+
+    let mut joined = waiting(creator);
+    joined.opponent = Pubkey::new_unique();
+    joined.opponent_move = PlayerMove::committed([2; 32]);
+    joined.status = GameStatus::WaitingForReveal;
+    hub.observe(address, joined.clone());
+
+    assert_eq!(
+        next(&mut socket).await,
+        json!({
+            "type": "joined",
+            "game": address.to_string(),
+            "opponent": joined.opponent.to_string(),
+        })
+    );
+}
+
+#[tokio::test]
+async fn bad_messages_get_an_error() {
+    let url = serve(Arc::new(Hub::default())).await;
+    let (mut socket, _) = connect_async(&url).await.unwrap();
+
+    let reply = send(&mut socket, json!({ "action": "subscribe", "game": "nope" })).await;
+    assert_eq!(reply, json!({ "type": "error", "message": "invalid pubkey: nope" }));
+    let reply = send(&mut socket, json!({ "action": "dance" })).await;
+    assert_eq!(reply["type"], "error");
+}
@@ -0,0 +1,9 @@
+//! House operator for Morra solo games: commits a fresh hidden move to each
+//! game a player opens against the house, keeps it in a local
+//! [`morra_keeper::Vault`], and reveals it once the player has moved. A
+//! missed reveal forfeits the game and slashes the house's bond, so the
+//! operator should run wherever its vault is safe and always on.
+
+mod plan;
+
+pub use plan::*;
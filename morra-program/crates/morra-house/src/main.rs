@@ -0,0 +1,141 @@
+//! House operator CLI: poll for house games waiting on the house, commit a
+//! move drawn from the standard rules' equilibrium to each, and reveal it
+//! once the player has answered.
+//!
+//! The move is saved to the vault before its commitment is sent, so a crash
+//! between the two never leaves a commitment the operator can't open.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::Parser;
+use morra_bot::{Strategy, Uniform};
+use morra_client::{house_commit, house_games_in, house_reveal, Instruction, Secret};
+use morra_house::{next_house_action, HouseAction};
+use morra_keeper::Vault;
+use morra_program::{HouseGame, HouseGameStatus};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_pubkey::Pubkey;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+#[derive(Parser)]
+#[command(about = "Commit and reveal the house's moves in Morra house games")]
+struct Args {
+    /// The house operator's keypair.
+    #[arg(long)]
+    keypair: PathBuf,
+    #[arg(long, default_value = "morra-house-vault.json")]
+    vault: PathBuf,
+    #[arg(long, default_value = "http://127.0.0.1:8899")]
+    rpc_url: String,
+    /// Seconds between polls.
+    #[arg(long, default_value_t = 2)]
+    interval: u64,
+}
+
+struct Operator {
+    rpc: RpcClient,
+    keypair: Keypair,
+    vault: Vault,
+    /// Uniform play is the standard rules' equilibrium, so a player who
+    /// studies the house's moves gains nothing.
+    strategy: Uniform,
+}
+
+impl Operator {
+    async fn send(&self, instruction: Instruction) -> anyhow::Result<()> {
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.keypair.pubkey()),
+            &[&self.keypair],
+            blockhash,
+        );
+        let signature = self.rpc.send_and_confirm_transaction(&transaction).await?;
+        println!("  {signature}");
+        Ok(())
+    }
+
+    async fn act(
+        &mut self,
+        address: &Pubkey,
+        game: &HouseGame,
+        action: HouseAction,
+    ) -> anyhow::Result<()> {
+        println!("{address}: {action:?}");
+        let operator = self.keypair.pubkey();
+        match action {
+            HouseAction::Commit => {
+                let secret = match self.vault.get(address) {
+                    Some(secret) => *secret,
+                    None => {
+                        let mv = self.strategy.choose(&[]);
+                        let secret = Secret::new(mv.card, mv.prediction);
+                        self.vault.insert(address, secret);
+                        self.vault.save()?;
+                        secret
+                    }
+                };
+                self.send(house_commit(address, &operator, &secret)).await?;
+            }
+            HouseAction::Reveal => {
+                let secret = *self.vault.get(address).context("no stored move")?;
+                self.send(house_reveal(address, game, &operator, &secret))
+                    .await?;
+                self.vault.forget(address);
+                self.vault.save()?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn poll(&mut self) -> anyhow::Result<()> {
+        for status in [
+            HouseGameStatus::WaitingForHouse,
+            HouseGameStatus::WaitingForReveal,
+        ] {
+            for (address, game) in house_games_in(&self.rpc, status).await? {
+                let secret = self.vault.get(&address);
+                let Some(action) = next_house_action(&address, &game, secret) else {
+                    continue;
+                };
+                // One stuck game shouldn't hold up the rest.
+                if let Err(err) = self.act(&address, &game, action).await {
+                    eprintln!("{address}: {action:?} failed: {err:#}");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let keypair = read_keypair_file(&args.keypair)
+        .map_err(|err| anyhow::anyhow!("reading {}: {err}", args.keypair.display()))?;
+    let vault =
+        Vault::open(&args.vault).with_context(|| format!("opening {}", args.vault.display()))?;
+    println!(
+        "operating the house as {}, {} moves stored",
+        keypair.pubkey(),
+        vault.len()
+    );
+    let mut operator = Operator {
+        rpc: RpcClient::new(args.rpc_url),
+        keypair,
+        vault,
+        strategy: Uniform::new(None),
+    };
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(args.interval));
+    loop {
+        ticker.tick().await;
+        if let Err(err) = operator.poll().await {
+            eprintln!("poll failed: {err:#}");
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use morra_client::{house_address, Secret};
+use morra_program::{HouseGame, HouseGameStatus};
+use solana_pubkey::Pubkey;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HouseAction {
+    /// Commit the stored move, or a fresh one if there's none yet.
+    Commit,
+    Reveal,
+}
+
+/// What the operator should do next in the house game at `address`,
+/// holding `secret` if the vault has the house's move. A secret that doesn't
+/// open the house's commitment is never revealed.
+pub fn next_house_action(
+    address: &Pubkey,
+    game: &HouseGame,
+    secret: Option<&Secret>,
+) -> Option<HouseAction> {
+    match game.status {
+        HouseGameStatus::WaitingForHouse => Some(HouseAction::Commit),
+        HouseGameStatus::WaitingForReveal => {
+            let committed = game.house_move.committed_hash?;
+            secret
+                .filter(|secret| secret.opens(&committed, &house_address(), address))
+                .map(|_| HouseAction::Reveal)
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,58 @@
+use morra_client::{house_address, Secret};
+use morra_house::{next_house_action, HouseAction};
+use morra_program::{HouseGame, HouseGameStatus, PlayerMove};
+use solana_pubkey::Pubkey;
+
+const GAME: Pubkey = Pubkey::new_from_array([7; 32]);
+
+fn game(status: HouseGameStatus, house_move: PlayerMove) -> HouseGame {
+    HouseGame {
+        player: Pubkey::new_unique(),
+        game_id: 0,
+        bump: 0,
+        bet_amount: 1_000_000_000,
+        status,
+        player_move: PlayerMove::default(),
+        house_move,
+        outcome: None,
+        created_at: 0,
+        last_action_at: 0,
+        house_edge_bps: 0,
+    }
+}
+
+#[test]
+fn commits_then_reveals_only_its_own_move() {
+    let secret = Secret::new(3, 7);
+    let waiting = game(HouseGameStatus::WaitingForHouse, PlayerMove::default());
+    assert_eq!(next_house_action(&GAME, &waiting, None), Some(HouseAction::Commit));
+    // A move saved before a failed commit is committed again.
+    assert_eq!(
+        next_house_action(&GAME, &waiting, Some(&secret)),
+        Some(HouseAction::Commit)
+    );
+
+    let committed = PlayerMove::committed(secret.commitment(&house_address(), &GAME));
+    let answered = game(HouseGameStatus::WaitingForReveal, committed);
+    assert_eq!(
+        next_house_action(&GAME, &answered, Some(&secret)),
+        Some(HouseAction::Reveal)
+    );
+    assert_eq!(next_house_action(&GAME, &answered, Some(&Secret::new(3, 7))), None);
+    assert_eq!(next_house_action(&GAME, &answered, None), None);
+    assert_eq!(
+        next_house_action(&Pubkey::new_unique(), &answered, Some(&secret)),
+        None
+    );
+
+    for status in [
+        HouseGameStatus::WaitingForPlayer,
+        HouseGameStatus::Settled,
+        HouseGameStatus::Cancelled,
+    ] {
+        assert_eq!(
+            next_house_action(&GAME, &game(status, committed), Some(&secret)),
+            None
+        );
+    }
+}
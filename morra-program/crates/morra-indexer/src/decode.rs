@@ -0,0 +1,169 @@
+use anchor_lang::{AccountDeserialize, Discriminator};
+use morra_program::instruction as ix;
+use morra_program::{Game, GameStatus, PlayerProfile};
+use solana_pubkey::Pubkey;
+
+/// A program instruction as the indexer logs it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodedInstruction {
+    pub name: &'static str,
+    /// The game account the instruction touches, if it touches one.
+    pub game: Option<Pubkey>,
+}
+
+/// Each instruction's name and the account slot holding its game.
+const INSTRUCTIONS: &[(&[u8], &str, Option<usize>)] = &[
+    (ix::CreateProfile::DISCRIMINATOR, "create_profile", None),
+    (ix::CreateLobbyPage::DISCRIMINATOR, "create_lobby_page", None),
+    (ix::CreateGame::DISCRIMINATOR, "create_game", Some(3)),
+    (ix::JoinGame::DISCRIMINATOR, "join_game", Some(0)),
+    (ix::CancelGame::DISCRIMINATOR, "cancel_game", Some(0)),
+    (ix::CreateMatchQueue::DISCRIMINATOR, "create_match_queue", None),
+    (ix::Enqueue::DISCRIMINATOR, "enqueue", None),
+    (ix::LeaveQueue::DISCRIMINATOR, "leave_queue", None),
+    (ix::PairMatch::DISCRIMINATOR, "pair_match", Some(5)),
+    (ix::CreateSession::DISCRIMINATOR, "create_session", None),
+    (ix::RevokeSession::DISCRIMINATOR, "revoke_session", None),
+    (ix::CommitMove::DISCRIMINATOR, "commit_move", Some(0)),
+    (ix::RevealMove::DISCRIMINATOR, "reveal_move", Some(0)),
+    (ix::ClaimWinnings::DISCRIMINATOR, "claim_winnings", Some(0)),
+    (ix::RefundDraw::DISCRIMINATOR, "refund_draw", Some(0)),
+    (ix::CreateTournament::DISCRIMINATOR, "create_tournament", None),
+    (ix::RegisterTournament::DISCRIMINATOR, "register_tournament", None),
+    (ix::LeaveTournament::DISCRIMINATOR, "leave_tournament", None),
+    (ix::StartTournament::DISCRIMINATOR, "start_tournament", None),
+    (ix::SpawnMatchGame::DISCRIMINATOR, "spawn_match_game", Some(3)),
+    (ix::AdvanceTournament::DISCRIMINATOR, "advance_tournament", Some(1)),
+    (ix::ClaimTournamentPrize::DISCRIMINATOR, "claim_tournament_prize", None),
+    (ix::CreateLeague::DISCRIMINATOR, "create_league", None),
+    (ix::JoinLeague::DISCRIMINATOR, "join_league", None),
+    (ix::LeaveLeague::DISCRIMINATOR, "leave_league", None),
+    (ix::SpawnFixtureGame::DISCRIMINATOR, "spawn_fixture_game", Some(3)),
+    (ix::RecordFixture::DISCRIMINATOR, "record_fixture", Some(1)),
+    (ix::SettleLeague::DISCRIMINATOR, "settle_league", None),
+    (ix::ClaimLeaguePrize::DISCRIMINATOR, "claim_league_prize", None),
+    (ix::CreateSideBetPool::DISCRIMINATOR, "create_side_bet_pool", Some(1)),
+    (ix::PlaceSideBet::DISCRIMINATOR, "place_side_bet", Some(1)),
+    (ix::ClaimSideBet::DISCRIMINATOR, "claim_side_bet", Some(1)),
+    (ix::InitializeConfig::DISCRIMINATOR, "initialize_config", None),
+    (ix::UpdateConfig::DISCRIMINATOR, "update_config", None),
+    (ix::ExpireGame::DISCRIMINATOR, "expire_game", Some(0)),
+    (ix::ForfeitGame::DISCRIMINATOR, "forfeit_game", Some(0)),
+    (ix::InitializeHouse::DISCRIMINATOR, "initialize_house", None),
+    (ix::FundHouseBond::DISCRIMINATOR, "fund_house_bond", None),
+    (ix::WithdrawHouseBond::DISCRIMINATOR, "withdraw_house_bond", None),
+    (ix::SetHouseOperator::DISCRIMINATOR, "set_house_operator", None),
+    (ix::CreateHouseGame::DISCRIMINATOR, "create_house_game", None),
+    (ix::HouseCommit::DISCRIMINATOR, "house_commit", None),
+    (ix::PlayHouseGame::DISCRIMINATOR, "play_house_game", None),
+    (ix::HouseReveal::DISCRIMINATOR, "house_reveal", None),
+    (ix::ForfeitHouseGame::DISCRIMINATOR, "forfeit_house_game", None),
+    (ix::InitializeBankroll::DISCRIMINATOR, "initialize_bankroll", None),
+    (ix::UpdateBankroll::DISCRIMINATOR, "update_bankroll", None),
+    (ix::CreateLpPosition::DISCRIMINATOR, "create_lp_position", None),
+    (ix::DepositBankroll::DISCRIMINATOR, "deposit_bankroll", None),
+    (ix::RequestWithdrawal::DISCRIMINATOR, "request_withdrawal", None),
+    (ix::WithdrawBankroll::DISCRIMINATOR, "withdraw_bankroll", None),
+];
+
+/// Name a `morra_program` instruction from its data and find its game among
+/// `accounts`. Returns `None` for data no instruction starts with.
+pub fn decode_instruction(data: &[u8], accounts: &[Pubkey]) -> Option<DecodedInstruction> {
+    let (_, name, game_slot) = INSTRUCTIONS
+        .iter()
+        .find(|(discriminator, ..)| data.starts_with(discriminator))?;
+    Some(DecodedInstruction {
+        name,
+        game: game_slot.and_then(|slot| accounts.get(slot).copied()),
+    })
+}
+
+/// A program account the indexer materializes.
+pub enum DecodedAccount {
+    Game(Box<Game>),
+    Profile(PlayerProfile),
+}
+
+/// Decode account data by its Anchor discriminator.
+pub fn decode_account(data: &[u8]) -> Option<DecodedAccount> {
+    if data.starts_with(Game::DISCRIMINATOR) {
+        let game = Game::try_deserialize(&mut &data[..]).ok()?;
+        Some(DecodedAccount::Game(Box::new(game)))
+    } else if data.starts_with(PlayerProfile::DISCRIMINATOR) {
+        let profile = PlayerProfile::try_deserialize(&mut &data[..]).ok()?;
+        Some(DecodedAccount::Profile(profile))
+    } else {
+        None
+    }
+}
+
+/// Why lamports left a game's escrow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayoutKind {
+    Winnings,
+    Refund,
+    Reimbursement,
+}
+
+impl PayoutKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Winnings => "winnings",
+            Self::Refund => "refund",
+            Self::Reimbursement => "reimbursement",
+        }
+    }
+}
+
+/// What the escrow paid out, once `game` is settled or cancelled. Mirrors
+/// `claim_winnings`, `refund_draw` and `cancel_game`.
+pub fn payouts(game: &Game) -> Vec<(Pubkey, u64, PayoutKind)> {
+    match game.status {
+        GameStatus::Cancelled => {
+            // A joined game is only cancelled when both players time out,
+            // and then both get their bets back.
+            let mut refunds = vec![(game.creator, game.bet_amount, PayoutKind::Refund)];
+            if game.opponent != Pubkey::default() {
+                refunds.push((game.opponent, game.bet_amount, PayoutKind::Refund));
+            }
+            refunds
+        }
+        GameStatus::Settled => {
+            let Ok(payout) = game.payout() else {
+                return Vec::new();
+            };
+            let Some(winner) = game.winner else {
+                return vec![
+                    (game.creator, payout.creator, PayoutKind::Refund),
+                    (game.opponent, payout.opponent, PayoutKind::Refund),
+                ];
+            };
+            let amount = if winner == game.creator {
+                payout.creator
+            } else {
+                payout.opponent
+            };
+            let sponsor = game.sponsor_of(&winner);
+            let reimbursement = sponsor.map_or(0, |sponsor| sponsor.reimbursement);
+            let mut paid = vec![(winner, amount - reimbursement, PayoutKind::Winnings)];
+            paid.extend(sponsor.map(|sponsor| {
+                (sponsor.relayer, reimbursement, PayoutKind::Reimbursement)
+            }));
+            paid
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// `GameStatus` as stored and served: its variant name in snake case.
+pub fn status_name(status: GameStatus) -> &'static str {
+    match status {
+        GameStatus::WaitingForOpponent => "waiting_for_opponent",
+        GameStatus::WaitingForReveal => "waiting_for_reveal",
+        GameStatus::Draw => "draw",
+        GameStatus::Completed => "completed",
+        GameStatus::Settled => "settled",
+        GameStatus::Cancelled => "cancelled",
+        GameStatus::WaitingForCommits => "waiting_for_commits",
+    }
+}
@@ -0,0 +1,15 @@
+//! Materializes `morra_program` history into SQLite so readers don't have
+//! to scan the chain.
+//!
+//! Instructions are decoded with the program's own instruction types and
+//! logged per transaction. `Game` and `PlayerProfile` accounts are decoded
+//! with its account types and upserted whenever they change, along with the
+//! moves and payouts they imply.
+
+mod decode;
+mod source;
+mod store;
+
+pub use decode::*;
+pub use source::*;
+pub use store::*;
@@ -0,0 +1,53 @@
+//! Indexer CLI: backfill a slot range, or follow the chain live, into a
+//! SQLite database.
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+use morra_indexer::{Indexer, Store};
+
+#[derive(Parser)]
+#[command(about = "Index Morra games into SQLite")]
+struct Args {
+    #[arg(long, default_value = "morra.db")]
+    database: PathBuf,
+    #[arg(long, default_value = "http://127.0.0.1:8899")]
+    rpc_url: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Index transactions from a slot range, resuming from the newest
+    /// indexed slot when `--from-slot` is omitted. Re-indexing is harmless.
+    Backfill {
+        #[arg(long)]
+        from_slot: Option<u64>,
+        #[arg(long, default_value_t = u64::MAX)]
+        to_slot: u64,
+    },
+    /// Stream account changes and transactions as they land.
+    Follow {
+        #[arg(long, default_value = "ws://127.0.0.1:8900")]
+        ws_url: String,
+    },
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let store = Store::open(&args.database)?;
+    let resume_from = store.last_slot()?.unwrap_or(0);
+    let mut indexer = Indexer::new(args.rpc_url, store);
+
+    match args.command {
+        Command::Backfill { from_slot, to_slot } => {
+            let from_slot = from_slot.unwrap_or(resume_from);
+            let count = indexer.backfill(from_slot, to_slot).await?;
+            println!("indexed {count} transactions from slots {from_slot}..={to_slot}");
+        }
+        Command::Follow { ws_url } => indexer.follow(&ws_url).await?,
+    }
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use anyhow::Context;
+use futures::StreamExt;
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_commitment_config::CommitmentConfig;
+use solana_pubkey::Pubkey;
+use solana_pubsub_client::nonblocking::pubsub_client::PubsubClient;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_rpc_client_api::config::{
+    RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionConfig,
+    RpcTransactionLogsConfig, RpcTransactionLogsFilter,
+};
+use solana_signature::Signature;
+use solana_transaction_status_client_types::option_serializer::OptionSerializer;
+use solana_transaction_status_client_types::UiTransactionEncoding;
+
+use crate::decode::{decode_account, decode_instruction, DecodedAccount};
+use crate::store::{InstructionRecord, Store};
+
+/// Largest page `getSignaturesForAddress` and `getMultipleAccounts` serve.
+const SIGNATURE_PAGE: usize = 1_000;
+const ACCOUNT_PAGE: usize = 100;
+
+/// Feeds a [`Store`] from an RPC node.
+pub struct Indexer {
+    rpc: RpcClient,
+    store: Store,
+    commitment: CommitmentConfig,
+}
+
+impl Indexer {
+    pub fn new(rpc_url: String, store: Store) -> Self {
+        let commitment = CommitmentConfig::confirmed();
+        Self {
+            rpc: RpcClient::new_with_commitment(rpc_url, commitment),
+            store,
+            commitment,
+        }
+    }
+
+    /// Index every successful program transaction landed in
+    /// `from_slot..=to_slot`, oldest first, then refresh the accounts they
+    /// touched. Returns how many transactions were indexed.
+    pub async fn backfill(&mut self, from_slot: u64, to_slot: u64) -> anyhow::Result<usize> {
+        // Signatures come newest first; page back until we pass `from_slot`.
+        let mut signatures = Vec::new();
+        let mut before = None;
+        loop {
+            let page = self
+                .rpc
+                .get_signatures_for_address_with_config(
+                    &morra_program::ID,
+                    GetConfirmedSignaturesForAddress2Config {
+                        before,
+                        until: None,
+                        limit: Some(SIGNATURE_PAGE),
+                        commitment: Some(self.commitment),
+                    },
+                )
+                .await?;
+            let Some(last) = page.last() else {
+                break;
+            };
+            before = Some(Signature::from_str(&last.signature)?);
+            let done = page.len() < SIGNATURE_PAGE || last.slot < from_slot;
+            signatures.extend(
+                page.into_iter()
+                    .filter(|status| (from_slot..=to_slot).contains(&status.slot))
+                    .filter(|status| status.err.is_none()),
+            );
+            if done {
+                break;
+            }
+        }
+
+        let mut touched = BTreeSet::new();
+        for status in signatures.iter().rev() {
+            let signature = Signature::from_str(&status.signature)?;
+            touched.extend(self.index_transaction(&signature).await?);
+        }
+        self.refresh(&touched.into_iter().collect::<Vec<_>>()).await?;
+        Ok(signatures.len())
+    }
+
+    /// Log the program instructions in one transaction and return every
+    /// account they reference.
+    pub async fn index_transaction(&mut self, signature: &Signature) -> anyhow::Result<Vec<Pubkey>> {
+        let confirmed = self
+            .rpc
+            .get_transaction_with_config(
+                signature,
+                RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    commitment: Some(self.commitment),
+                    max_supported_transaction_version: Some(0),
+                },
+            )
+            .await?;
+        let meta = confirmed.transaction.meta.context("transaction has no status meta")?;
+        if meta.err.is_some() {
+            return Ok(Vec::new());
+        }
+        let transaction = confirmed
+            .transaction
+            .transaction
+            .decode()
+            .context("transaction did not decode")?;
+
+        // Lookup-table accounts follow the static keys, writable first.
+        let mut keys = transaction.message.static_account_keys().to_vec();
+        if let OptionSerializer::Some(loaded) = &meta.loaded_addresses {
+            for key in loaded.writable.iter().chain(&loaded.readonly) {
+                keys.push(Pubkey::from_str(key)?);
+            }
+        }
+
+        let mut touched = Vec::new();
+        for (index, instruction) in transaction.message.instructions().iter().enumerate() {
+            if keys.get(usize::from(instruction.program_id_index)) != Some(&morra_program::ID) {
+                continue;
+            }
+            let accounts: Vec<Pubkey> = instruction
+                .accounts
+                .iter()
+                .filter_map(|&account| keys.get(usize::from(account)).copied())
+                .collect();
+            let Some(decoded) = decode_instruction(&instruction.data, &accounts) else {
+                continue;
+            };
+            self.store.record_instruction(&InstructionRecord {
+                signature: signature.to_string(),
+                index,
+                slot: confirmed.slot,
+                block_time: confirmed.block_time,
+                instruction: decoded,
+            })?;
+            touched.extend(accounts);
+        }
+        Ok(touched)
+    }
+
+    /// Fetch `accounts` and upsert the games and profiles among them.
+    pub async fn refresh(&mut self, accounts: &[Pubkey]) -> anyhow::Result<()> {
+        for page in accounts.chunks(ACCOUNT_PAGE) {
+            let response = self
+                .rpc
+                .get_multiple_accounts_with_commitment(page, self.commitment)
+                .await?;
+            for (address, account) in page.iter().zip(response.value) {
+                let Some(account) = account.filter(|account| account.owner == morra_program::ID)
+                else {
+                    continue;
+                };
+                self.apply(address, &account.data, response.context.slot)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, address: &Pubkey, data: &[u8], slot: u64) -> anyhow::Result<()> {
+        match decode_account(data) {
+            Some(DecodedAccount::Game(game)) => self.store.upsert_game(address, &game, slot)?,
+            Some(DecodedAccount::Profile(profile)) => self.store.upsert_profile(&profile, slot)?,
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Follow the chain live: upsert program accounts as they change and
+    /// log the instructions of every transaction that mentions the program.
+    pub async fn follow(&mut self, ws_url: &str) -> anyhow::Result<()> {
+        let pubsub = PubsubClient::new(ws_url).await?;
+        let (mut accounts, _unsubscribe_accounts) = pubsub
+            .program_subscribe(
+                &morra_program::ID,
+                Some(RpcProgramAccountsConfig {
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        commitment: Some(self.commitment),
+                        ..RpcAccountInfoConfig::default()
+                    },
+                    ..RpcProgramAccountsConfig::default()
+                }),
+            )
+            .await?;
+        let (mut logs, _unsubscribe_logs) = pubsub
+            .logs_subscribe(
+                RpcTransactionLogsFilter::Mentions(vec![morra_program::ID.to_string()]),
+                RpcTransactionLogsConfig {
+                    commitment: Some(self.commitment),
+                },
+            )
+            .await?;
+
+        loop {
+            tokio::select! {
+                Some(update) = accounts.next() => {
+                    let address = Pubkey::from_str(&update.value.pubkey)?;
+                    if let Some(data) = update.value.account.data.decode() {
+                        self.apply(&address, &data, update.context.slot)?;
+                    }
+                }
+                Some(update) = logs.next() => {
+                    if update.value.err.is_none() {
+                        let signature = Signature::from_str(&update.value.signature)?;
+                        self.index_transaction(&signature).await?;
+                    }
+                }
+                else => return Ok(()),
+            }
+        }
+    }
+}
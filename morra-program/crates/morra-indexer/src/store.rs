@@ -0,0 +1,237 @@
+use std::path::Path;
+
+use morra_program::{Game, PlayerMove, PlayerProfile};
+use rusqlite::{params, Connection, OptionalExtension};
+use solana_pubkey::Pubkey;
+
+use crate::decode::{payouts, status_name, DecodedInstruction};
+
+/// Tables the indexer keeps. Account-backed rows (`games`, `moves`,
+/// `payouts`, `players`) hold the latest state seen and the slot it was
+/// seen at; `instructions` is the append-only history.
+pub const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS games (
+    address TEXT PRIMARY KEY,
+    creator TEXT NOT NULL,
+    game_id INTEGER NOT NULL,
+    opponent TEXT,
+    bet_amount INTEGER NOT NULL,
+    ranked INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    winner TEXT,
+    created_at INTEGER NOT NULL,
+    last_action_at INTEGER NOT NULL,
+    slot INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS games_by_creator ON games (creator);
+CREATE INDEX IF NOT EXISTS games_by_status ON games (status);
+
+CREATE TABLE IF NOT EXISTS moves (
+    game TEXT NOT NULL,
+    seat TEXT NOT NULL,
+    player TEXT NOT NULL,
+    commitment TEXT,
+    card INTEGER,
+    prediction INTEGER,
+    PRIMARY KEY (game, seat)
+);
+
+CREATE TABLE IF NOT EXISTS payouts (
+    game TEXT NOT NULL,
+    recipient TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    PRIMARY KEY (game, recipient, kind)
+);
+
+CREATE TABLE IF NOT EXISTS players (
+    player TEXT PRIMARY KEY,
+    games_played INTEGER NOT NULL,
+    wins INTEGER NOT NULL,
+    losses INTEGER NOT NULL,
+    draws INTEGER NOT NULL,
+    forfeits INTEGER NOT NULL,
+    total_wagered INTEGER NOT NULL,
+    net_winnings INTEGER NOT NULL,
+    current_streak INTEGER NOT NULL,
+    rating INTEGER NOT NULL,
+    slot INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS instructions (
+    signature TEXT NOT NULL,
+    idx INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    block_time INTEGER,
+    name TEXT NOT NULL,
+    game TEXT,
+    PRIMARY KEY (signature, idx)
+);
+CREATE INDEX IF NOT EXISTS instructions_by_game ON instructions (game);
+";
+
+/// One program instruction from a confirmed transaction.
+#[derive(Debug, Clone)]
+pub struct InstructionRecord {
+    pub signature: String,
+    pub index: usize,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub instruction: DecodedInstruction,
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+fn optional_key(key: Pubkey) -> Option<String> {
+    (key != Pubkey::default()).then(|| key.to_string())
+}
+
+impl Store {
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    pub fn into_connection(self) -> Connection {
+        self.conn
+    }
+
+    /// Record `game` as seen at `slot`, with its moves and payouts. Older
+    /// sightings than the stored one are ignored.
+    pub fn upsert_game(&mut self, address: &Pubkey, game: &Game, slot: u64) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        let address = address.to_string();
+        let stored: Option<u64> = tx
+            .query_row("SELECT slot FROM games WHERE address = ?1", [&address], |row| row.get(0))
+            .optional()?;
+        if stored.is_some_and(|stored| stored > slot) {
+            return Ok(());
+        }
+
+        tx.execute(
+            "INSERT OR REPLACE INTO games (address, creator, game_id, opponent, bet_amount,
+                ranked, status, winner, created_at, last_action_at, slot)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                address,
+                game.creator.to_string(),
+                game.game_id,
+                optional_key(game.opponent),
+                game.bet_amount,
+                game.ranked,
+                status_name(game.status),
+                game.winner.map(|winner| winner.to_string()),
+                game.created_at,
+                game.last_action_at,
+                slot,
+            ],
+        )?;
+
+        let seats: [(&str, Pubkey, &PlayerMove); 2] = [
+            ("creator", game.creator, &game.creator_move),
+            ("opponent", game.opponent, &game.opponent_move),
+        ];
+        for (seat, player, player_move) in seats {
+            let Some(player) = optional_key(player) else {
+                continue;
+            };
+            tx.execute(
+                "INSERT OR REPLACE INTO moves (game, seat, player, commitment, card, prediction)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    address,
+                    seat,
+                    player,
+                    player_move.committed_hash.map(hex),
+                    player_move.card,
+                    player_move.prediction,
+                ],
+            )?;
+        }
+
+        for (recipient, amount, kind) in payouts(game) {
+            tx.execute(
+                "INSERT OR REPLACE INTO payouts (game, recipient, kind, amount)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![address, recipient.to_string(), kind.as_str(), amount],
+            )?;
+        }
+        tx.commit()
+    }
+
+    /// Record a profile's lifetime stats as seen at `slot`.
+    pub fn upsert_profile(&mut self, profile: &PlayerProfile, slot: u64) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT INTO players (player, games_played, wins, losses, draws, forfeits,
+                total_wagered, net_winnings, current_streak, rating, slot)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+             ON CONFLICT (player) DO UPDATE SET
+                games_played = excluded.games_played,
+                wins = excluded.wins,
+                losses = excluded.losses,
+                draws = excluded.draws,
+                forfeits = excluded.forfeits,
+                total_wagered = excluded.total_wagered,
+                net_winnings = excluded.net_winnings,
+                current_streak = excluded.current_streak,
+                rating = excluded.rating,
+                slot = excluded.slot
+             WHERE excluded.slot >= players.slot",
+            params![
+                profile.player.to_string(),
+                profile.games_played,
+                profile.wins,
+                profile.losses,
+                profile.draws,
+                profile.forfeits,
+                profile.total_wagered,
+                profile.net_winnings,
+                profile.current_streak,
+                profile.rating,
+                slot,
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn record_instruction(&mut self, record: &InstructionRecord) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT OR IGNORE INTO instructions (signature, idx, slot, block_time, name, game)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                record.signature,
+                record.index,
+                record.slot,
+                record.block_time,
+                record.instruction.name,
+                record.instruction.game.map(|game| game.to_string()),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The newest slot any logged instruction landed in, where a restarted
+    /// indexer can resume a backfill.
+    pub fn last_slot(&self) -> rusqlite::Result<Option<u64>> {
+        self.conn
+            .query_row("SELECT MAX(slot) FROM instructions", [], |row| row.get(0))
+    }
+}
+
+fn hex(bytes: [u8; 32]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
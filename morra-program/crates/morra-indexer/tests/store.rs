@@ -0,0 +1,237 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+use morra_indexer::{
+    decode_account, decode_instruction, payouts, DecodedAccount, InstructionRecord, PayoutKind,
+    Store,
+};
+use morra_program::{Game, GameStatus, PlayerMove, PlayerProfile, Sponsor};
+use rusqlite::Connection;
+
+const BET: u64 = 1_000_000_000;
+
+fn game(status: GameStatus) -> Game {
+    Game {
+        creator: Pubkey::new_unique(),
+        game_id: 0,
+        bump: 0,
+        opponent: Pubkey::new_unique(),
+        bet_amount: BET,
+        ranked: false,
+        lobby_page: 0,
+        status,
+        creator_move: PlayerMove {
+            committed_hash: Some([0xab; 32]),
+            card: Some(3),
+            prediction: Some(5),
+        },
+        opponent_move: PlayerMove::committed([0xcd; 32]),
+        winner: None,
+        created_at: 10,
+        last_action_at: 20,
+        creator_sponsor: None,
+        opponent_sponsor: None,
+        queue: None,
+    }
+}
+
+fn count(conn: &Connection, table: &str) -> i64 {
+    conn.query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| row.get(0))
+        .unwrap()
+}
+
+#[test]
+fn instructions_decode_with_their_game() {
+    let game = Pubkey::new_unique();
+    let accounts = morra_program::accounts::RevealMove {
+        game,
+        player: Pubkey::new_unique(),
+        creator_profile: Pubkey::new_unique(),
+        opponent_profile: Pubkey::new_unique(),
+        session: None,
+        session_scope: None,
+    }
+    .to_account_metas(None);
+    let keys: Vec<Pubkey> = accounts.iter().map(|meta| meta.pubkey).collect();
+    let data = morra_program::instruction::RevealMove {
+        card: 3,
+        prediction: 5,
+        salt: [1; 32],
+    }
+    .data();
+
+    let decoded = decode_instruction(&data, &keys).unwrap();
+    assert_eq!(decoded.name, "reveal_move");
+    assert_eq!(decoded.game, Some(game));
+
+    let accounts = morra_program::accounts::CreateGame {
+        creator: Pubkey::new_unique(),
+        payer: Pubkey::new_unique(),
+        creator_profile: Pubkey::new_unique(),
+        game,
+        lobby: Pubkey::new_unique(),
+        game_account: Pubkey::new_unique(),
+        system_program: Pubkey::default(),
+        rent: Pubkey::new_unique(),
+    }
+    .to_account_metas(None);
+    let keys: Vec<Pubkey> = accounts.iter().map(|meta| meta.pubkey).collect();
+    let data = morra_program::instruction::CreateGame {
+        bet_amount: BET,
+        commitment: [0; 32],
+        ranked: false,
+        reimbursement: 0,
+    }
+    .data();
+    assert_eq!(decode_instruction(&data, &keys).unwrap().game, Some(game));
+
+    assert_eq!(decode_instruction(&[0; 8], &keys), None);
+}
+
+#[test]
+fn accounts_decode_by_discriminator() {
+    let mut data = Vec::new();
+    game(GameStatus::WaitingForReveal)
+        .try_serialize(&mut data)
+        .unwrap();
+    assert!(matches!(decode_account(&data), Some(DecodedAccount::Game(_))));
+
+    let mut data = Vec::new();
+    PlayerProfile::new(Pubkey::new_unique(), 0)
+        .try_serialize(&mut data)
+        .unwrap();
+    assert!(matches!(decode_account(&data), Some(DecodedAccount::Profile(_))));
+
+    assert!(decode_account(&data[..8]).is_none());
+}
+
+#[test]
+fn settled_sponsored_win_pays_winner_and_relayer() {
+    let mut settled = game(GameStatus::Settled);
+    settled.winner = Some(settled.opponent);
+    let relayer = Pubkey::new_unique();
+    settled.opponent_sponsor = Some(Sponsor {
+        relayer,
+        reimbursement: 5_000,
+    });
+
+    assert_eq!(
+        payouts(&settled),
+        [
+            (settled.opponent, 2 * BET - 5_000, PayoutKind::Winnings),
+            (relayer, 5_000, PayoutKind::Reimbursement),
+        ]
+    );
+    assert!(payouts(&game(GameStatus::Completed)).is_empty());
+    let draw = game(GameStatus::Settled);
+    assert_eq!(
+        payouts(&draw),
+        [
+            (draw.creator, BET, PayoutKind::Refund),
+            (draw.opponent, BET, PayoutKind::Refund),
+        ]
+    );
+}
+
+#[test]
+fn cancelled_game_refunds_whoever_staked() {
+    let mut unjoined = game(GameStatus::Cancelled);
+    unjoined.opponent = Pubkey::default();
+    assert_eq!(
+        payouts(&unjoined),
+        [(unjoined.creator, BET, PayoutKind::Refund)]
+    );
+
+    // Both players timing out cancels a joined game too.
+    let abandoned = game(GameStatus::Cancelled);
+    assert_eq!(
+        payouts(&abandoned),
+        [
+            (abandoned.creator, BET, PayoutKind::Refund),
+            (abandoned.opponent, BET, PayoutKind::Refund),
+        ]
+    );
+}
+
+#[test]
+fn games_keep_their_newest_state() {
+    let mut store = Store::open_in_memory().unwrap();
+    let address = Pubkey::new_unique();
+    let mut waiting = game(GameStatus::WaitingForOpponent);
+    waiting.opponent = Pubkey::default();
+
+    store.upsert_game(&address, &waiting, 5).unwrap();
+    let conn = store.connection();
+    let (status, opponent): (String, Option<String>) = conn
+        .query_row("SELECT status, opponent FROM games", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(status, "waiting_for_opponent");
+    assert_eq!(opponent, None);
+    assert_eq!(count(conn, "moves"), 1);
+
+    let mut settled = game(GameStatus::Settled);
+    settled.creator = waiting.creator;
+    settled.winner = Some(settled.creator);
+    store.upsert_game(&address, &settled, 9).unwrap();
+    // A late, older sighting doesn't roll the game back.
+    store.upsert_game(&address, &waiting, 7).unwrap();
+
+    let conn = store.connection();
+    let status: String = conn
+        .query_row("SELECT status FROM games", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(status, "settled");
+    let (card, prediction, commitment): (Option<u8>, Option<u8>, String) = conn
+        .query_row(
+            "SELECT card, prediction, commitment FROM moves WHERE seat = 'creator'",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!((card, prediction), (Some(3), Some(5)));
+    assert_eq!(commitment, "ab".repeat(32));
+    let hidden: Option<u8> = conn
+        .query_row("SELECT card FROM moves WHERE seat = 'opponent'", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(hidden, None);
+    let paid: u64 = conn
+        .query_row("SELECT amount FROM payouts WHERE kind = 'winnings'", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(paid, 2 * BET);
+}
+
+#[test]
+fn profiles_and_instructions_are_recorded() {
+    let mut store = Store::open_in_memory().unwrap();
+    let mut profile = PlayerProfile::new(Pubkey::new_unique(), 0);
+    profile.record_win(BET);
+    store.upsert_profile(&profile, 4).unwrap();
+    profile.record_loss(BET);
+    store.upsert_profile(&profile, 3).unwrap();
+
+    let (games, net): (u64, i64) = store
+        .connection()
+        .query_row("SELECT games_played, net_winnings FROM players", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!((games, net), (1, BET as i64));
+
+    let record = InstructionRecord {
+        signature: "sig".into(),
+        index: 0,
+        slot: 42,
+        block_time: None,
+        instruction: decode_instruction(
+            &morra_program::instruction::ClaimWinnings {}.data(),
+            &[Pubkey::new_unique()],
+        )
+        .unwrap(),
+    };
+    assert_eq!(store.last_slot().unwrap(), None);
+    store.record_instruction(&record).unwrap();
+    store.record_instruction(&record).unwrap();
+    assert_eq!(count(store.connection(), "instructions"), 1);
+    assert_eq!(store.last_slot().unwrap(), Some(42));
+}
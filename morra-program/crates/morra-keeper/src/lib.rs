@@ -0,0 +1,10 @@
+//! Keeper that plays out a player's Morra games for them: it holds their
+//! unrevealed moves in a local [`Vault`] and, game by game, takes the
+//! [`next_action`] as soon as it becomes available, so a forgotten reveal
+//! doesn't turn into a forfeit.
+
+mod plan;
+mod vault;
+
+pub use plan::*;
+pub use vault::*;
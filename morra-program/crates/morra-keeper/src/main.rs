@@ -0,0 +1,218 @@
+//! Keeper CLI: store a move's secret in the vault, then run alongside the
+//! player to reveal, refund and claim their games as they come due, and to
+//! claim a forfeit when their opponent stalls.
+//!
+//! The keeper signs with `--keypair`. That can be the player's own key, or
+//! a session key they authorized (pass `--player` and, for tournament or
+//! league games, `--session-scope`). A session key can commit and reveal
+//! but not claim winnings, which are left for the player.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use clap::{Parser, Subcommand};
+use morra_client::{
+    chain_time, claim_winnings, commit_move, forfeit_game, player_games, refund_draw, reveal_move,
+    session_address, Instruction, Secret, SessionAccounts,
+};
+use morra_core::{is_valid_card, is_valid_prediction};
+use morra_keeper::{next_action, Action, Vault};
+use morra_program::Game;
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_pubkey::Pubkey;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+#[derive(Parser)]
+#[command(about = "Reveal and settle Morra games on a player's behalf")]
+struct Args {
+    #[arg(long, default_value = "morra-vault.json")]
+    vault: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Store the move committed to a game.
+    Add {
+        #[arg(long)]
+        game: Pubkey,
+        #[arg(long)]
+        card: u8,
+        #[arg(long)]
+        prediction: u8,
+        /// The 32-byte salt, hex-encoded.
+        #[arg(long, value_parser = parse_salt)]
+        salt: [u8; 32],
+    },
+    /// Watch the player's games and act on them until interrupted.
+    Run {
+        #[arg(long)]
+        keypair: PathBuf,
+        /// The player, when `--keypair` is one of their session keys.
+        #[arg(long)]
+        player: Option<Pubkey>,
+        /// Tournament or league the session key is scoped to.
+        #[arg(long, requires = "player")]
+        session_scope: Option<Pubkey>,
+        #[arg(long, default_value = "http://127.0.0.1:8899")]
+        rpc_url: String,
+        /// Seconds between polls.
+        #[arg(long, default_value_t = 5)]
+        interval: u64,
+    },
+}
+
+fn parse_salt(hex: &str) -> Result<[u8; 32], String> {
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err("expected 64 hex digits".into());
+    }
+    let mut salt = [0; 32];
+    for (byte, pair) in salt.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        let pair = std::str::from_utf8(pair).map_err(|err| err.to_string())?;
+        *byte = u8::from_str_radix(pair, 16).map_err(|err| err.to_string())?;
+    }
+    Ok(salt)
+}
+
+struct Keeper {
+    rpc: RpcClient,
+    signer: Keypair,
+    player: Pubkey,
+    session: Option<SessionAccounts>,
+    vault: Vault,
+}
+
+impl Keeper {
+    async fn send(&self, instruction: Instruction) -> anyhow::Result<()> {
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.signer.pubkey()),
+            &[&self.signer],
+            blockhash,
+        );
+        let signature = self.rpc.send_and_confirm_transaction(&transaction).await?;
+        println!("  {signature}");
+        Ok(())
+    }
+
+    async fn act(&mut self, address: &Pubkey, game: &Game, action: Action) -> anyhow::Result<()> {
+        println!("{address}: {action:?}");
+        let secret = self.vault.get(address).copied();
+        match action {
+            Action::Commit => {
+                let secret = secret.context("no stored move")?;
+                self.send(commit_move(
+                    address,
+                    &self.player,
+                    &self.signer.pubkey(),
+                    self.session,
+                    &secret,
+                ))
+                .await?;
+            }
+            Action::Reveal => {
+                let secret = secret.context("no stored move")?;
+                let signer = self.signer.pubkey();
+                self.send(reveal_move(address, game, &signer, self.session, &secret))
+                    .await?;
+            }
+            Action::Claim if self.session.is_some() => {
+                println!("  won; claim it with the player's own key");
+            }
+            Action::Claim => {
+                self.send(claim_winnings(address, game, &self.player))
+                    .await?
+            }
+            Action::RefundDraw => self.send(refund_draw(address, game)).await?,
+            Action::ClaimTimeout => {
+                let signer = self.signer.pubkey();
+                self.send(forfeit_game(address, game, &signer, false))
+                    .await?;
+            }
+            Action::Forget => {
+                self.vault.forget(address);
+                self.vault.save()?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn poll(&mut self) -> anyhow::Result<()> {
+        let now = chain_time(&self.rpc).await?;
+        for (address, game) in player_games(&self.rpc, &self.player).await? {
+            let secret = self.vault.get(&address);
+            let Some(action) = next_action(&address, &game, &self.player, secret, now) else {
+                continue;
+            };
+            // One stuck game shouldn't hold up the rest.
+            if let Err(err) = self.act(&address, &game, action).await {
+                eprintln!("{address}: {action:?} failed: {err:#}");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let mut vault =
+        Vault::open(&args.vault).with_context(|| format!("opening {}", args.vault.display()))?;
+    match args.command {
+        Command::Add {
+            game,
+            card,
+            prediction,
+            salt,
+        } => {
+            if !is_valid_card(card) || !is_valid_prediction(prediction) {
+                bail!("{card} and {prediction} aren't a valid move");
+            }
+            vault.insert(
+                &game,
+                Secret {
+                    card,
+                    prediction,
+                    salt,
+                },
+            );
+            vault.save()?;
+        }
+        Command::Run {
+            keypair,
+            player,
+            session_scope,
+            rpc_url,
+            interval,
+        } => {
+            let signer = read_keypair_file(&keypair)
+                .map_err(|err| anyhow::anyhow!("reading {}: {err}", keypair.display()))?;
+            let player = player.unwrap_or_else(|| signer.pubkey());
+            let session = (player != signer.pubkey()).then(|| SessionAccounts {
+                session: session_address(&player, &signer.pubkey()),
+                scope: session_scope,
+            });
+            println!("keeping {player}'s games, {} moves stored", vault.len());
+            let mut keeper = Keeper {
+                rpc: RpcClient::new(rpc_url),
+                signer,
+                player,
+                session,
+                vault,
+            };
+            let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+            loop {
+                ticker.tick().await;
+                if let Err(err) = keeper.poll().await {
+                    eprintln!("poll failed: {err:#}");
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use morra_client::Secret;
+use morra_program::{Game, GameStatus, PlayerMove};
+use solana_pubkey::Pubkey;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Commit the stored move to a game spawned with both seats filled.
+    Commit,
+    Reveal,
+    Claim,
+    RefundDraw,
+    /// The other player stalled past the move timeout; win by forfeit.
+    ClaimTimeout,
+    /// Nothing is left to do; drop the stored move.
+    Forget,
+}
+
+fn seat<'a>(game: &'a Game, player: &Pubkey) -> Option<&'a PlayerMove> {
+    if *player == game.creator {
+        Some(&game.creator_move)
+    } else if *player == game.opponent {
+        Some(&game.opponent_move)
+    } else {
+        None
+    }
+}
+
+/// What the keeper should do next in the game at `address` for `player` at
+/// `now`, holding `secret` if the vault has their move. A secret that doesn't
+/// open the player's commitment is never revealed.
+pub fn next_action(
+    address: &Pubkey,
+    game: &Game,
+    player: &Pubkey,
+    secret: Option<&Secret>,
+    now: i64,
+) -> Option<Action> {
+    let seat = seat(game, player)?;
+    let moved = match game.status {
+        GameStatus::WaitingForCommits => seat.committed_hash.is_some(),
+        GameStatus::WaitingForReveal => seat.revealed().is_some(),
+        _ => false,
+    };
+    if moved && game.timed_out(now) {
+        return Some(Action::ClaimTimeout);
+    }
+    match game.status {
+        GameStatus::WaitingForCommits if seat.committed_hash.is_none() => {
+            secret.map(|_| Action::Commit)
+        }
+        GameStatus::WaitingForReveal if seat.revealed().is_none() => {
+            let committed = seat.committed_hash?;
+            let scope = game.commitment_scope(address);
+            secret
+                .filter(|secret| secret.opens(&committed, player, &scope))
+                .map(|_| Action::Reveal)
+        }
+        GameStatus::Completed if game.winner == Some(*player) => Some(Action::Claim),
+        GameStatus::Draw => Some(Action::RefundDraw),
+        GameStatus::Completed | GameStatus::Settled | GameStatus::Cancelled => {
+            secret.map(|_| Action::Forget)
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use morra_client::Secret;
+use solana_pubkey::Pubkey;
+
+/// Moves waiting to be revealed, by game address, kept in a JSON file.
+///
+/// Anyone who can read the file can see the player's hidden moves, so it's
+/// written owner-only.
+#[derive(Debug)]
+pub struct Vault {
+    path: PathBuf,
+    secrets: BTreeMap<String, Secret>,
+}
+
+impl Vault {
+    /// Load the vault at `path`, or start an empty one if there's no file.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let secrets = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+        Ok(Self { path, secrets })
+    }
+
+    pub fn get(&self, game: &Pubkey) -> Option<&Secret> {
+        self.secrets.get(&game.to_string())
+    }
+
+    pub fn insert(&mut self, game: &Pubkey, secret: Secret) {
+        self.secrets.insert(game.to_string(), secret);
+    }
+
+    pub fn forget(&mut self, game: &Pubkey) -> Option<Secret> {
+        self.secrets.remove(&game.to_string())
+    }
+
+    pub fn len(&self) -> usize {
+        self.secrets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.secrets.is_empty()
+    }
+
+    /// Write the vault back, replacing the file in one step so a crash
+    /// can't leave it half written.
+    pub fn save(&self) -> io::Result<()> {
+        let staging = self.path.with_extension("tmp");
+        fs::write(&staging, serde_json::to_vec_pretty(&self.secrets)?)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&staging, fs::Permissions::from_mode(0o600))?;
+        }
+        fs::rename(staging, &self.path)
+    }
+}
@@ -0,0 +1,158 @@
+use morra_client::Secret;
+use morra_keeper::{next_action, Action, Vault};
+use morra_program::MOVE_TIMEOUT;
+use morra_program::{Game, GameStatus, PlayerMove};
+use solana_pubkey::Pubkey;
+
+const BET: u64 = 1_000_000_000;
+const GAME: Pubkey = Pubkey::new_from_array([7; 32]);
+
+/// A joined game where the creator committed `secret`.
+fn joined(secret: &Secret) -> Game {
+    let creator = Pubkey::new_unique();
+    Game {
+        creator,
+        game_id: 0,
+        bump: 0,
+        opponent: Pubkey::new_unique(),
+        bet_amount: BET,
+        ranked: false,
+        lobby_page: 0,
+        status: GameStatus::WaitingForReveal,
+        creator_move: PlayerMove::committed(secret.commitment(&creator, &GAME)),
+        opponent_move: PlayerMove::committed([2; 32]),
+        winner: None,
+        created_at: 0,
+        last_action_at: 0,
+        creator_sponsor: None,
+        opponent_sponsor: None,
+        queue: None,
+    }
+}
+
+#[test]
+fn reveals_only_a_matching_unrevealed_move() {
+    let secret = Secret::new(3, 5);
+    let mut game = joined(&secret);
+    let creator = game.creator;
+
+    assert_eq!(
+        next_action(&GAME, &game, &creator, Some(&secret), 0),
+        Some(Action::Reveal)
+    );
+    assert_eq!(next_action(&GAME, &game, &creator, None, 0), None);
+    let wrong = Secret::new(3, 5);
+    assert_eq!(next_action(&GAME, &game, &creator, Some(&wrong), 0), None);
+    assert_eq!(
+        next_action(&GAME, &game, &Pubkey::new_unique(), Some(&secret), 0),
+        None
+    );
+
+    // The commitment is bound to this game, not any other.
+    assert_eq!(
+        next_action(&Pubkey::new_unique(), &game, &creator, Some(&secret), 0),
+        None
+    );
+
+    game.creator_move.card = Some(3);
+    game.creator_move.prediction = Some(5);
+    assert_eq!(next_action(&GAME, &game, &creator, Some(&secret), 0), None);
+}
+
+#[test]
+fn reveals_queued_moves_against_the_queue() {
+    let secret = Secret::new(3, 5);
+    let mut game = joined(&secret);
+    let (creator, queue) = (game.creator, Pubkey::new_unique());
+    game.queue = Some(queue);
+    game.creator_move = PlayerMove::committed(secret.commitment(&creator, &queue));
+
+    assert_eq!(
+        next_action(&GAME, &game, &creator, Some(&secret), 0),
+        Some(Action::Reveal)
+    );
+}
+
+#[test]
+fn commits_to_spawned_games() {
+    let secret = Secret::new(1, 6);
+    let mut game = joined(&secret);
+    game.status = GameStatus::WaitingForCommits;
+    game.creator_move = PlayerMove::default();
+
+    assert_eq!(
+        next_action(&GAME, &game, &game.creator, Some(&secret), 0),
+        Some(Action::Commit)
+    );
+    assert_eq!(next_action(&GAME, &game, &game.creator, None, 0), None);
+    assert_eq!(next_action(&GAME, &game, &game.opponent, Some(&secret), 0), None);
+}
+
+#[test]
+fn settles_finished_games() {
+    let secret = Secret::new(3, 5);
+    let mut game = joined(&secret);
+    let (creator, opponent) = (game.creator, game.opponent);
+
+    game.status = GameStatus::Completed;
+    game.winner = Some(creator);
+    assert_eq!(next_action(&GAME, &game, &creator, None, 0), Some(Action::Claim));
+    assert_eq!(
+        next_action(&GAME, &game, &opponent, Some(&secret), 0),
+        Some(Action::Forget)
+    );
+    assert_eq!(next_action(&GAME, &game, &opponent, None, 0), None);
+
+    game.status = GameStatus::Draw;
+    game.winner = None;
+    assert_eq!(
+        next_action(&GAME, &game, &opponent, None, 0),
+        Some(Action::RefundDraw)
+    );
+
+    for status in [GameStatus::Settled, GameStatus::Cancelled] {
+        game.status = status;
+        assert_eq!(
+            next_action(&GAME, &game, &creator, Some(&secret), 0),
+            Some(Action::Forget)
+        );
+    }
+}
+
+#[test]
+fn claims_a_forfeit_once_the_opponent_stalls() {
+    let secret = Secret::new(3, 5);
+    let mut game = joined(&secret);
+    let (creator, opponent) = (game.creator, game.opponent);
+    game.creator_move.card = Some(3);
+    game.creator_move.prediction = Some(5);
+    let deadline = game.last_action_at + MOVE_TIMEOUT;
+
+    assert_eq!(next_action(&GAME, &game, &creator, None, deadline - 1), None);
+    assert_eq!(
+        next_action(&GAME, &game, &creator, None, deadline),
+        Some(Action::ClaimTimeout)
+    );
+    // The staller still reveals if they can, rather than forfeit.
+    assert_eq!(next_action(&GAME, &game, &opponent, None, deadline), None);
+}
+
+#[test]
+fn vault_round_trips_through_its_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("vault.json");
+    let [kept, dropped] = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let secret = Secret::new(2, 7);
+
+    let mut vault = Vault::open(&path).unwrap();
+    assert!(vault.is_empty());
+    vault.insert(&kept, secret);
+    vault.insert(&dropped, Secret::new(4, 4));
+    assert!(vault.forget(&dropped).is_some());
+    vault.save().unwrap();
+
+    let vault = Vault::open(&path).unwrap();
+    assert_eq!(vault.len(), 1);
+    assert_eq!(vault.get(&kept), Some(&secret));
+    assert_eq!(vault.get(&dropped), None);
+}
@@ -0,0 +1,11 @@
+//! Policy for a relayer that pays transaction fees and rent on behalf of
+//! Morra players.
+//!
+//! The relayer co-signs as fee payer, so anything it forwards can spend its
+//! lamports. [`Policy::check`] only lets through transactions made entirely
+//! of known `morra_program` instructions, where the relayer's key shows up
+//! nowhere but the slots built for a sponsor.
+
+mod policy;
+
+pub use policy::*;
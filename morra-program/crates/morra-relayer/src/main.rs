@@ -0,0 +1,129 @@
+//! Reference relayer: accepts partially signed Morra transactions over HTTP,
+//! checks them against its [`Policy`], co-signs as fee payer and submits
+//! them.
+//!
+//! Clients fetch `GET /policy` to learn the relayer's key and terms, build
+//! a transaction with that key as fee payer (and as `payer` on
+//! `create_game`/`join_game`), sign it with the player's key and post it
+//! base64-encoded to `POST /relay`.
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use clap::Parser;
+use morra_relayer::Policy;
+use serde::{Deserialize, Serialize};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+#[derive(Parser)]
+#[command(about = "Pay fees and rent for Morra players")]
+struct Args {
+    /// Keypair that pays fees and rent.
+    #[arg(long)]
+    keypair: PathBuf,
+    #[arg(long, default_value = "http://127.0.0.1:8899")]
+    rpc_url: String,
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    listen: SocketAddr,
+    /// Lamports a player must agree to repay from winnings per game.
+    #[arg(long, default_value_t = 0)]
+    min_reimbursement: u64,
+    #[arg(long, default_value_t = 4)]
+    max_instructions: usize,
+    /// Also pay rent for new players' profiles.
+    #[arg(long)]
+    sponsor_profiles: bool,
+}
+
+struct Relayer {
+    keypair: Keypair,
+    policy: Policy,
+    rpc: RpcClient,
+}
+
+#[derive(Deserialize)]
+struct RelayRequest {
+    /// Bincode-serialized, base64-encoded transaction, signed by every
+    /// signer except the relayer.
+    transaction: String,
+}
+
+#[derive(Serialize)]
+struct RelayResponse {
+    signature: String,
+}
+
+type Rejected = (StatusCode, String);
+
+fn bad_request(err: impl ToString) -> Rejected {
+    (StatusCode::BAD_REQUEST, err.to_string())
+}
+
+async fn show_policy(State(relayer): State<Arc<Relayer>>) -> Json<Policy> {
+    Json(relayer.policy.clone())
+}
+
+async fn relay(
+    State(relayer): State<Arc<Relayer>>,
+    Json(request): Json<RelayRequest>,
+) -> Result<Json<RelayResponse>, Rejected> {
+    let bytes = BASE64_STANDARD
+        .decode(request.transaction)
+        .map_err(bad_request)?;
+    let mut transaction: Transaction = bincode::deserialize(&bytes).map_err(bad_request)?;
+    relayer.policy.check(&transaction).map_err(bad_request)?;
+
+    let blockhash = transaction.message.recent_blockhash;
+    transaction
+        .try_partial_sign(&[&relayer.keypair], blockhash)
+        .map_err(bad_request)?;
+    // Don't pay to learn that a player's signature is missing or wrong.
+    transaction.verify().map_err(bad_request)?;
+
+    let signature = relayer
+        .rpc
+        .send_transaction(&transaction)
+        .await
+        .map_err(|err| (StatusCode::BAD_GATEWAY, err.to_string()))?;
+    Ok(Json(RelayResponse {
+        signature: signature.to_string(),
+    }))
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let keypair = read_keypair_file(&args.keypair)
+        .map_err(|err| anyhow::anyhow!("{err}"))
+        .with_context(|| format!("reading {}", args.keypair.display()))?;
+    let policy = Policy {
+        min_reimbursement: args.min_reimbursement,
+        max_instructions: args.max_instructions,
+        sponsor_profiles: args.sponsor_profiles,
+        ..Policy::new(keypair.pubkey())
+    };
+    let relayer = Arc::new(Relayer {
+        keypair,
+        policy,
+        rpc: RpcClient::new(args.rpc_url),
+    });
+
+    let app = Router::new()
+        .route("/policy", get(show_policy))
+        .route("/relay", post(relay))
+        .with_state(relayer);
+    let listener = tokio::net::TcpListener::bind(args.listen).await?;
+    println!("relaying on {}", args.listen);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use morra_program::instruction as ix;
+use serde::Serialize;
+use solana_pubkey::Pubkey;
+use solana_transaction::Transaction;
+use thiserror::Error;
+
+/// Why the relayer refused to pay for a transaction.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum Rejection {
+    #[error("the relayer must be the fee payer")]
+    NotFeePayer,
+    #[error("transactions carry between 1 and {max} instructions")]
+    InstructionCount { max: usize },
+    #[error("instruction {index} calls a program other than morra_program")]
+    ForeignProgram { index: usize },
+    #[error("instruction {index} is not one the relayer sponsors")]
+    NotRelayable { index: usize },
+    #[error("instruction {index} has malformed data or accounts")]
+    Malformed { index: usize },
+    #[error("instruction {index} uses the relayer outside its sponsor slot")]
+    RelayerMisused { index: usize },
+    #[error("instruction {index} must name the relayer as payer and reimburse {min} lamports")]
+    Unreimbursed { index: usize, min: u64 },
+}
+
+/// A `morra_program` instruction the relayer is willing to pay for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relayable {
+    CreateProfile,
+    CreateGame { reimbursement: u64 },
+    JoinGame { reimbursement: u64 },
+    CommitMove,
+    RevealMove,
+    CancelGame,
+    ClaimWinnings,
+    RefundDraw,
+}
+
+/// Why instruction data didn't decode to a [`Relayable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Not an instruction the relayer forwards.
+    Unknown,
+    /// A relayable discriminator with arguments that don't parse exactly.
+    Malformed,
+}
+
+fn parse<T: AnchorDeserialize>(args: &[u8]) -> Result<T, DecodeError> {
+    T::try_from_slice(args).map_err(|_| DecodeError::Malformed)
+}
+
+impl Relayable {
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        if data.len() < 8 {
+            return Err(DecodeError::Malformed);
+        }
+        let (discriminator, args) = data.split_at(8);
+        Ok(match discriminator {
+            d if d == ix::CreateProfile::DISCRIMINATOR => {
+                parse::<ix::CreateProfile>(args)?;
+                Self::CreateProfile
+            }
+            d if d == ix::CreateGame::DISCRIMINATOR => Self::CreateGame {
+                reimbursement: parse::<ix::CreateGame>(args)?.reimbursement,
+            },
+            d if d == ix::JoinGame::DISCRIMINATOR => Self::JoinGame {
+                reimbursement: parse::<ix::JoinGame>(args)?.reimbursement,
+            },
+            d if d == ix::CommitMove::DISCRIMINATOR => {
+                parse::<ix::CommitMove>(args)?;
+                Self::CommitMove
+            }
+            d if d == ix::RevealMove::DISCRIMINATOR => {
+                parse::<ix::RevealMove>(args)?;
+                Self::RevealMove
+            }
+            d if d == ix::CancelGame::DISCRIMINATOR => {
+                parse::<ix::CancelGame>(args)?;
+                Self::CancelGame
+            }
+            d if d == ix::ClaimWinnings::DISCRIMINATOR => {
+                parse::<ix::ClaimWinnings>(args)?;
+                Self::ClaimWinnings
+            }
+            d if d == ix::RefundDraw::DISCRIMINATOR => {
+                parse::<ix::RefundDraw>(args)?;
+                Self::RefundDraw
+            }
+            _ => return Err(DecodeError::Unknown),
+        })
+    }
+
+    /// How many accounts the instruction takes, counting the program-id
+    /// placeholders Anchor passes for absent optional accounts.
+    pub fn account_count(self) -> usize {
+        match self {
+            Self::CreateProfile | Self::CommitMove => 4,
+            Self::CreateGame { .. } => 8,
+            Self::JoinGame { .. } => 9,
+            Self::RevealMove => 6,
+            Self::CancelGame | Self::ClaimWinnings | Self::RefundDraw => 5,
+        }
+    }
+
+    /// The account slot built for a sponsor: the rent payer, or the
+    /// account a winner's reimbursement is paid to.
+    pub fn sponsor_slot(self) -> Option<usize> {
+        match self {
+            Self::CreateGame { .. } => Some(1),
+            Self::CreateProfile | Self::JoinGame { .. } => Some(2),
+            Self::ClaimWinnings => Some(3),
+            _ => None,
+        }
+    }
+
+    /// What a player opening or joining a game promises to repay on a win.
+    pub fn reimbursement(self) -> Option<u64> {
+        match self {
+            Self::CreateGame { reimbursement } | Self::JoinGame { reimbursement } => {
+                Some(reimbursement)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// What a relayer will pay for.
+#[derive(Debug, Clone, Serialize)]
+pub struct Policy {
+    #[serde(with = "display")]
+    pub relayer: Pubkey,
+    /// Lamports a player must promise back, out of winnings, for each game
+    /// they create or join through the relayer.
+    pub min_reimbursement: u64,
+    pub max_instructions: usize,
+    /// Whether the relayer pays rent for new players' profiles, which it
+    /// never gets back.
+    pub sponsor_profiles: bool,
+}
+
+mod display {
+    use serde::Serializer;
+    use solana_pubkey::Pubkey;
+
+    pub fn serialize<S: Serializer>(key: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(key)
+    }
+}
+
+impl Policy {
+    pub fn new(relayer: Pubkey) -> Self {
+        Self {
+            relayer,
+            min_reimbursement: 0,
+            max_instructions: 4,
+            sponsor_profiles: false,
+        }
+    }
+
+    /// Decode every instruction in `transaction`, refusing it unless the
+    /// relayer pays the fee, every instruction is relayable and well formed,
+    /// and the relayer's key appears only in sponsor slots.
+    pub fn check(&self, transaction: &Transaction) -> Result<Vec<Relayable>, Rejection> {
+        let message = &transaction.message;
+        if message.account_keys.first() != Some(&self.relayer) {
+            return Err(Rejection::NotFeePayer);
+        }
+        let count = message.instructions.len();
+        if count == 0 || count > self.max_instructions {
+            return Err(Rejection::InstructionCount {
+                max: self.max_instructions,
+            });
+        }
+
+        let key = |index: u8| message.account_keys.get(usize::from(index));
+        let mut relayable = Vec::with_capacity(count);
+        for (index, instruction) in message.instructions.iter().enumerate() {
+            if key(instruction.program_id_index) != Some(&morra_program::ID) {
+                return Err(Rejection::ForeignProgram { index });
+            }
+            let decoded = Relayable::decode(&instruction.data).map_err(|err| match err {
+                DecodeError::Unknown => Rejection::NotRelayable { index },
+                DecodeError::Malformed => Rejection::Malformed { index },
+            })?;
+            if instruction.accounts.len() != decoded.account_count() {
+                return Err(Rejection::Malformed { index });
+            }
+            let accounts = instruction
+                .accounts
+                .iter()
+                .map(|&account| key(account).ok_or(Rejection::Malformed { index }))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let sponsor_slot = decoded
+                .sponsor_slot()
+                .filter(|_| decoded != Relayable::CreateProfile || self.sponsor_profiles);
+            for (slot, account) in accounts.iter().enumerate() {
+                if **account == self.relayer && Some(slot) != sponsor_slot {
+                    return Err(Rejection::RelayerMisused { index });
+                }
+            }
+            if let (Some(reimbursement), Some(slot)) = (decoded.reimbursement(), sponsor_slot) {
+                if *accounts[slot] != self.relayer || reimbursement < self.min_reimbursement {
+                    return Err(Rejection::Unreimbursed {
+                        index,
+                        min: self.min_reimbursement,
+                    });
+                }
+            }
+            relayable.push(decoded);
+        }
+        Ok(relayable)
+    }
+}
@@ -0,0 +1,195 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use morra_relayer::{Policy, Rejection, Relayable};
+use solana_transaction::Transaction;
+
+const REIMBURSEMENT: u64 = 5_000;
+
+fn create_game(creator: Pubkey, payer: Pubkey, reimbursement: u64) -> Instruction {
+    Instruction {
+        program_id: morra_program::ID,
+        accounts: morra_program::accounts::CreateGame {
+            creator,
+            payer,
+            creator_profile: Pubkey::new_unique(),
+            game: Pubkey::new_unique(),
+            lobby: Pubkey::new_unique(),
+            game_account: Pubkey::new_unique(),
+            system_program: Pubkey::default(),
+            rent: Pubkey::new_unique(),
+        }
+        .to_account_metas(None),
+        data: morra_program::instruction::CreateGame {
+            bet_amount: 1_000_000_000,
+            commitment: [7; 32],
+            ranked: false,
+            reimbursement,
+        }
+        .data(),
+    }
+}
+
+fn claim_winnings(winner: Pubkey, sponsor: Option<Pubkey>) -> Instruction {
+    Instruction {
+        program_id: morra_program::ID,
+        accounts: morra_program::accounts::ClaimWinnings {
+            game: Pubkey::new_unique(),
+            winner,
+            game_account: Pubkey::new_unique(),
+            sponsor,
+            system_program: Pubkey::default(),
+        }
+        .to_account_metas(None),
+        data: morra_program::instruction::ClaimWinnings {}.data(),
+    }
+}
+
+fn create_profile(player: Pubkey, payer: Pubkey) -> Instruction {
+    Instruction {
+        program_id: morra_program::ID,
+        accounts: morra_program::accounts::CreateProfile {
+            profile: Pubkey::new_unique(),
+            player,
+            payer,
+            system_program: Pubkey::default(),
+        }
+        .to_account_metas(None),
+        data: morra_program::instruction::CreateProfile {}.data(),
+    }
+}
+
+fn paid_by(payer: Pubkey, instructions: &[Instruction]) -> Transaction {
+    Transaction::new_with_payer(instructions, Some(&payer))
+}
+
+fn policy() -> Policy {
+    Policy {
+        min_reimbursement: REIMBURSEMENT,
+        ..Policy::new(Pubkey::new_unique())
+    }
+}
+
+#[test]
+fn sponsored_game_flow_is_relayed() {
+    let policy = policy();
+    let (relayer, player) = (policy.relayer, Pubkey::new_unique());
+    let transaction = paid_by(
+        relayer,
+        &[
+            create_game(player, relayer, REIMBURSEMENT),
+            claim_winnings(player, Some(relayer)),
+            claim_winnings(player, None),
+        ],
+    );
+
+    assert_eq!(
+        policy.check(&transaction),
+        Ok(vec![
+            Relayable::CreateGame {
+                reimbursement: REIMBURSEMENT
+            },
+            Relayable::ClaimWinnings,
+            Relayable::ClaimWinnings,
+        ])
+    );
+}
+
+#[test]
+fn relayer_must_pay_the_fee() {
+    let policy = policy();
+    let player = Pubkey::new_unique();
+    let transaction = paid_by(player, &[create_game(player, policy.relayer, REIMBURSEMENT)]);
+
+    assert_eq!(policy.check(&transaction), Err(Rejection::NotFeePayer));
+    assert_eq!(
+        policy.check(&paid_by(policy.relayer, &[])),
+        Err(Rejection::InstructionCount { max: 4 })
+    );
+}
+
+#[test]
+fn only_morra_instructions_are_relayed() {
+    let policy = policy();
+    let relayer = policy.relayer;
+    let foreign = Instruction {
+        program_id: Pubkey::new_unique(),
+        accounts: vec![AccountMeta::new(relayer, true)],
+        data: vec![],
+    };
+    let unknown = Instruction {
+        program_id: morra_program::ID,
+        accounts: vec![],
+        data: morra_program::instruction::CreateLobbyPage {
+            ranked: false,
+            index: 0,
+        }
+        .data(),
+    };
+    let mut truncated = claim_winnings(Pubkey::new_unique(), None);
+    truncated.data.truncate(4);
+    let mut extra_account = claim_winnings(Pubkey::new_unique(), None);
+    extra_account
+        .accounts
+        .push(AccountMeta::new(Pubkey::new_unique(), false));
+
+    for (instruction, rejection) in [
+        (foreign, Rejection::ForeignProgram { index: 1 }),
+        (unknown, Rejection::NotRelayable { index: 1 }),
+        (truncated, Rejection::Malformed { index: 1 }),
+        (extra_account, Rejection::Malformed { index: 1 }),
+    ] {
+        let transaction = paid_by(
+            relayer,
+            &[claim_winnings(Pubkey::new_unique(), None), instruction],
+        );
+        assert_eq!(policy.check(&transaction), Err(rejection));
+    }
+}
+
+#[test]
+fn relayer_key_stays_in_sponsor_slots() {
+    let policy = policy();
+    let relayer = policy.relayer;
+
+    // Naming the relayer as creator would stake its lamports.
+    let staked = paid_by(relayer, &[create_game(relayer, relayer, REIMBURSEMENT)]);
+    assert_eq!(
+        policy.check(&staked),
+        Err(Rejection::RelayerMisused { index: 0 })
+    );
+    let claimed = paid_by(relayer, &[claim_winnings(relayer, None)]);
+    assert_eq!(
+        policy.check(&claimed),
+        Err(Rejection::RelayerMisused { index: 0 })
+    );
+}
+
+#[test]
+fn games_must_reimburse_the_relayer() {
+    let policy = policy();
+    let (relayer, player) = (policy.relayer, Pubkey::new_unique());
+    let unreimbursed = Rejection::Unreimbursed {
+        index: 0,
+        min: REIMBURSEMENT,
+    };
+
+    let self_paid = paid_by(relayer, &[create_game(player, player, REIMBURSEMENT)]);
+    assert_eq!(policy.check(&self_paid), Err(unreimbursed.clone()));
+    let underpaid = paid_by(relayer, &[create_game(player, relayer, REIMBURSEMENT - 1)]);
+    assert_eq!(policy.check(&underpaid), Err(unreimbursed));
+}
+
+#[test]
+fn profile_rent_is_opt_in() {
+    let mut policy = policy();
+    let (relayer, player) = (policy.relayer, Pubkey::new_unique());
+    let transaction = paid_by(relayer, &[create_profile(player, relayer)]);
+
+    assert_eq!(
+        policy.check(&transaction),
+        Err(Rejection::RelayerMisused { index: 0 })
+    );
+    policy.sponsor_profiles = true;
+    assert_eq!(policy.check(&transaction), Ok(vec![Relayable::CreateProfile]));
+}
@@ -0,0 +1,151 @@
+use std::io::{self, Write};
+
+use microlp::{ComparisonOp, OptimizationDirection, Problem};
+use morra_core::{Move, Outcome, RuleSet};
+
+/// Mixes below this probability are solver noise, not part of the strategy.
+const NEGLIGIBLE: f64 = 1e-9;
+
+/// Every legal move under a rule set and what each scores against each
+/// other: +1 for a win, -1 for a loss, 0 for a draw, from the row's seat.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayoffMatrix {
+    pub moves: Vec<Move>,
+    pub payoffs: Vec<Vec<f64>>,
+}
+
+impl PayoffMatrix {
+    /// Score every pair of moves with [`RuleSet::resolve`], which for
+    /// [`RuleSet::STANDARD`] is the program's own resolution.
+    pub fn new(rules: &RuleSet) -> Self {
+        let moves: Vec<Move> = rules
+            .cards()
+            .flat_map(|card| {
+                rules
+                    .predictions()
+                    .map(move |prediction| Move::new(card, prediction))
+            })
+            .collect();
+        let payoffs = moves
+            .iter()
+            .map(|&row| {
+                moves
+                    .iter()
+                    .map(|&column| match rules.resolve(row, column) {
+                        Outcome::CreatorWins => 1.0,
+                        Outcome::OpponentWins => -1.0,
+                        Outcome::Draw => 0.0,
+                    })
+                    .collect()
+            })
+            .collect();
+        Self { moves, payoffs }
+    }
+
+    /// The least `mix` (weights over [`PayoffMatrix::moves`]) scores per
+    /// game against any reply: its value against a best response.
+    pub fn guarantee(&self, mix: &[f64]) -> f64 {
+        (0..self.moves.len())
+            .map(|column| {
+                mix.iter()
+                    .zip(&self.payoffs)
+                    .map(|(weight, row)| weight * row[column])
+                    .sum::<f64>()
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// An optimal mixed strategy for one rule set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equilibrium {
+    pub rules: RuleSet,
+    /// What the strategy wins per game against a best response, in bets.
+    /// Zero for a fair table.
+    pub value: f64,
+    /// The moves played and their probabilities, most likely first.
+    pub mix: Vec<(Move, f64)>,
+}
+
+impl Equilibrium {
+    /// How often each legal card is shown.
+    pub fn cards(&self) -> Vec<(u8, f64)> {
+        self.rules
+            .cards()
+            .map(|card| {
+                let share = self
+                    .mix
+                    .iter()
+                    .filter(|(chosen, _)| chosen.card == card)
+                    .fold(0.0, |share, (_, weight)| share + weight);
+                (card, share)
+            })
+            .collect()
+    }
+}
+
+/// Solve for a Nash equilibrium of the game under `rules` by linear
+/// programming: maximise `v` over mixes `x` with `x·A[_, j] >= v` for every
+/// reply `j`. Equilibria aren't unique in general; this is the one the
+/// solver lands on.
+pub fn solve(rules: &RuleSet) -> Result<Equilibrium, microlp::Error> {
+    let matrix = PayoffMatrix::new(rules);
+    let mut problem = Problem::new(OptimizationDirection::Maximize);
+    let weights: Vec<_> = matrix
+        .moves
+        .iter()
+        .map(|_| problem.add_var(0.0, (0.0, 1.0)))
+        .collect();
+    let value = problem.add_var(1.0, (-1.0, 1.0));
+    for column in 0..matrix.moves.len() {
+        let mut expr: Vec<_> = weights
+            .iter()
+            .zip(&matrix.payoffs)
+            .map(|(&weight, row)| (weight, row[column]))
+            .collect();
+        expr.push((value, -1.0));
+        problem.add_constraint(expr, ComparisonOp::Ge, 0.0);
+    }
+    let total: Vec<_> = weights.iter().map(|&weight| (weight, 1.0)).collect();
+    problem.add_constraint(total, ComparisonOp::Eq, 1.0);
+
+    let solution = problem.solve()?;
+    let mut mix: Vec<(Move, f64)> = matrix
+        .moves
+        .iter()
+        .zip(&weights)
+        .map(|(&chosen, &weight)| (chosen, solution[weight]))
+        .filter(|&(_, weight)| weight > NEGLIGIBLE)
+        .collect();
+    mix.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let value = solution[value];
+    Ok(Equilibrium {
+        rules: *rules,
+        value: if value.abs() < NEGLIGIBLE { 0.0 } else { value },
+        mix,
+    })
+}
+
+/// Write each equilibrium's mix as CSV, one row per move played.
+pub fn write_mix_csv(out: &mut impl Write, equilibria: &[Equilibrium]) -> io::Result<()> {
+    writeln!(
+        out,
+        "zero_allowed,both_called,neither_called,value,card,prediction,probability"
+    )?;
+    for equilibrium in equilibria {
+        let rules = &equilibrium.rules;
+        for (chosen, weight) in &equilibrium.mix {
+            writeln!(
+                out,
+                "{},{},{},{},{},{},{weight}",
+                rules.zero_allowed,
+                rules.both_called,
+                rules.neither_called,
+                equilibrium.value,
+                chosen.card,
+                chosen.prediction,
+            )?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,13 @@
+//! Offline simulator: plays [`Strategy`](morra_bot::Strategy)s against each
+//! other with the same resolution rules the program uses, or a variant of
+//! them, and summarises the results with confidence intervals. It also
+//! [`solve`]s each rule set for its equilibrium mix, the yardstick for
+//! whether a table is fair and how far a bot is from unexploitable.
+
+mod equilibrium;
+mod stats;
+mod tournament;
+
+pub use equilibrium::*;
+pub use stats::*;
+pub use tournament::*;
@@ -0,0 +1,173 @@
+//! Simulator CLI: round-robin the baseline strategies, mirror matches
+//! included, or solve for the equilibrium mix, under the program's rules or
+//! every variant, and print or export the results.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use morra_bot::Baseline;
+use morra_core::RuleSet;
+use morra_sim::{play_match, solve, write_csv, write_mix_csv, Matchup};
+
+#[derive(Parser)]
+#[command(about = "Pit Morra strategies against each other offline")]
+struct Args {
+    /// Cover every rule variant, not just the program's rules.
+    #[arg(long, global = true)]
+    all_rules: bool,
+    /// Also write the results to this CSV file.
+    #[arg(long, global = true)]
+    csv: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Play every pairing of the strategies.
+    Tournament {
+        /// Games per pairing and rule set.
+        #[arg(long, default_value_t = 1_000_000)]
+        rounds: u64,
+        /// Comma-separated strategies: uniform, frequency, markov.
+        #[arg(long, value_delimiter = ',', default_values_t = Baseline::ALL)]
+        strategies: Vec<Baseline>,
+        /// Seed for every strategy's randomness; runs with the same seed
+        /// match.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Solve each rule set for an equilibrium mix and the table's value.
+    Solve,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let rule_sets: Vec<RuleSet> = if args.all_rules {
+        RuleSet::variants().collect()
+    } else {
+        vec![RuleSet::STANDARD]
+    };
+    match args.command {
+        Command::Tournament {
+            rounds,
+            strategies,
+            seed,
+        } => tournament(&rule_sets, &strategies, rounds, seed, args.csv),
+        Command::Solve => equilibria(&rule_sets, args.csv),
+    }
+}
+
+fn equilibria(rule_sets: &[RuleSet], csv: Option<PathBuf>) -> anyhow::Result<()> {
+    let mut equilibria = Vec::new();
+    for rules in rule_sets {
+        let equilibrium = solve(rules).with_context(|| format!("solving {rules}"))?;
+        println!("{rules}");
+        println!("  value {:+.6}", equilibrium.value);
+        let cards: Vec<String> = equilibrium
+            .cards()
+            .iter()
+            .map(|(card, share)| format!("{card}: {share:.4}"))
+            .collect();
+        println!("  cards  {}", cards.join("  "));
+        for (chosen, weight) in &equilibrium.mix {
+            println!(
+                "  show {} call {:>2}  {weight:.4}",
+                chosen.card, chosen.prediction
+            );
+        }
+        equilibria.push(equilibrium);
+    }
+    if let Some(path) = csv {
+        let file = File::create(&path).with_context(|| format!("creating {}", path.display()))?;
+        write_mix_csv(&mut BufWriter::new(file), &equilibria)?;
+    }
+    Ok(())
+}
+
+fn tournament(
+    rule_sets: &[RuleSet],
+    strategies: &[Baseline],
+    rounds: u64,
+    seed: u64,
+    csv: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let mut jobs = Vec::new();
+    for rules in rule_sets {
+        for (index, first) in strategies.iter().enumerate() {
+            for second in &strategies[index..] {
+                jobs.push((*rules, *first, *second));
+            }
+        }
+    }
+
+    // Pairings are independent, so spread them over the available cores.
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; jobs.len()]);
+    let workers = thread::available_parallelism().map_or(1, usize::from);
+    thread::scope(|scope| {
+        for _ in 0..workers.min(jobs.len()) {
+            scope.spawn(|| loop {
+                let job = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&(rules, first, second)) = jobs.get(job) else {
+                    break;
+                };
+                let seed = seed.wrapping_add(2 * job as u64);
+                let tally = play_match(
+                    &rules,
+                    first.build(rules, Some(seed)).as_mut(),
+                    second.build(rules, Some(seed.wrapping_add(1))).as_mut(),
+                    rounds,
+                );
+                results.lock().unwrap()[job] = Some(Matchup {
+                    rules,
+                    first: first.to_string(),
+                    second: second.to_string(),
+                    tally,
+                });
+            });
+        }
+    });
+    let matchups: Vec<Matchup> = results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|matchup| matchup.expect("every job runs"))
+        .collect();
+
+    let mut rules = None;
+    for matchup in &matchups {
+        if rules != Some(matchup.rules) {
+            rules = Some(matchup.rules);
+            println!("{}", matchup.rules);
+        }
+        let (win, draw, ev) = (
+            matchup.tally.win_rate(),
+            matchup.tally.draw_rate(),
+            matchup.tally.ev(),
+        );
+        println!(
+            "  {:>9} vs {:<9}  win {:.4} ±{:.4}  draw {:.4} ±{:.4}  EV {:+.4} ±{:.4}",
+            matchup.first,
+            matchup.second,
+            win.mean,
+            win.half_width,
+            draw.mean,
+            draw.half_width,
+            ev.mean,
+            ev.half_width,
+        );
+    }
+
+    if let Some(path) = csv {
+        let file = File::create(&path).with_context(|| format!("creating {}", path.display()))?;
+        write_csv(&mut BufWriter::new(file), &matchups)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use morra_core::Outcome;
+
+/// Standard normal quantile for a two-sided 95% interval.
+const Z_95: f64 = 1.96;
+
+/// A sample mean and the half-width of its 95% confidence interval.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate {
+    pub mean: f64,
+    pub half_width: f64,
+}
+
+impl Estimate {
+    pub fn low(&self) -> f64 {
+        self.mean - self.half_width
+    }
+
+    pub fn high(&self) -> f64 {
+        self.mean + self.half_width
+    }
+}
+
+/// Results from the first player's seat.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub wins: u64,
+    pub losses: u64,
+    pub draws: u64,
+}
+
+impl Tally {
+    /// Count `outcome`, with the first player in the creator's seat.
+    pub fn record(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::CreatorWins => self.wins += 1,
+            Outcome::OpponentWins => self.losses += 1,
+            Outcome::Draw => self.draws += 1,
+        }
+    }
+
+    pub fn rounds(&self) -> u64 {
+        self.wins + self.losses + self.draws
+    }
+
+    pub fn win_rate(&self) -> Estimate {
+        self.proportion(self.wins)
+    }
+
+    pub fn loss_rate(&self) -> Estimate {
+        self.proportion(self.losses)
+    }
+
+    pub fn draw_rate(&self) -> Estimate {
+        self.proportion(self.draws)
+    }
+
+    /// Expected winnings per round in bets: a win takes the opponent's bet,
+    /// a loss gives up one's own, a draw refunds both.
+    pub fn ev(&self) -> Estimate {
+        let n = self.rounds() as f64;
+        if n == 0.0 {
+            return Estimate {
+                mean: 0.0,
+                half_width: f64::INFINITY,
+            };
+        }
+        let mean = (self.wins as f64 - self.losses as f64) / n;
+        // Each round scores +1, -1 or 0, so the second moment is the
+        // decisive share.
+        let second_moment = (self.wins + self.losses) as f64 / n;
+        let variance = second_moment - mean * mean;
+        Estimate {
+            mean,
+            half_width: Z_95 * (variance / n).sqrt(),
+        }
+    }
+
+    /// Normal-approximation interval for a share of the rounds.
+    fn proportion(&self, count: u64) -> Estimate {
+        let n = self.rounds() as f64;
+        if n == 0.0 {
+            return Estimate {
+                mean: 0.0,
+                half_width: f64::INFINITY,
+            };
+        }
+        let p = count as f64 / n;
+        Estimate {
+            mean: p,
+            half_width: Z_95 * (p * (1.0 - p) / n).sqrt(),
+        }
+    }
+}
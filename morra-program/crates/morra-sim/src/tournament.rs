@@ -0,0 +1,92 @@
+use std::io::{self, Write};
+
+use morra_bot::{Round, Strategy};
+use morra_core::RuleSet;
+use solana_pubkey::Pubkey;
+
+use crate::stats::Tally;
+
+/// Play `rounds` games of `first` against `second` under `rules`, each
+/// seeing only its own side of the history. `first` takes the creator's
+/// seat, which the rules treat the same as the opponent's.
+pub fn play_match(
+    rules: &RuleSet,
+    first: &mut dyn Strategy,
+    second: &mut dyn Strategy,
+    rounds: u64,
+) -> Tally {
+    // Stand-in seats so history-filtering strategies see a consistent
+    // opponent.
+    let (first_seat, second_seat) = (
+        Pubkey::new_from_array([1; 32]),
+        Pubkey::new_from_array([2; 32]),
+    );
+    let mut first_history = Vec::new();
+    let mut second_history = Vec::new();
+    let mut tally = Tally::default();
+    for _ in 0..rounds {
+        let own = first.choose(&first_history);
+        let theirs = second.choose(&second_history);
+        tally.record(rules.resolve(own, theirs));
+        first_history.push(Round {
+            opponent: second_seat,
+            own,
+            theirs,
+        });
+        second_history.push(Round {
+            opponent: first_seat,
+            own: theirs,
+            theirs: own,
+        });
+    }
+    tally
+}
+
+/// One pairing's results under one rule set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matchup {
+    pub rules: RuleSet,
+    pub first: String,
+    pub second: String,
+    pub tally: Tally,
+}
+
+/// Write `matchups` as CSV, one row each, with rates and EV as the first
+/// player's mean and 95% interval bounds.
+pub fn write_csv(out: &mut impl Write, matchups: &[Matchup]) -> io::Result<()> {
+    writeln!(
+        out,
+        "zero_allowed,both_called,neither_called,first,second,rounds,wins,losses,draws,\
+         win_rate,win_low,win_high,draw_rate,draw_low,draw_high,ev,ev_low,ev_high"
+    )?;
+    for matchup in matchups {
+        let Matchup {
+            rules,
+            first,
+            second,
+            tally,
+        } = matchup;
+        let (win, draw, ev) = (tally.win_rate(), tally.draw_rate(), tally.ev());
+        writeln!(
+            out,
+            "{},{},{},{first},{second},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            rules.zero_allowed,
+            rules.both_called,
+            rules.neither_called,
+            tally.rounds(),
+            tally.wins,
+            tally.losses,
+            tally.draws,
+            win.mean,
+            win.low(),
+            win.high(),
+            draw.mean,
+            draw.low(),
+            draw.high(),
+            ev.mean,
+            ev.low(),
+            ev.high(),
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use morra_core::{resolve, Outcome, RuleSet, Tiebreak};
+use morra_sim::{solve, write_mix_csv, PayoffMatrix};
+
+const TOLERANCE: f64 = 1e-6;
+
+#[test]
+fn standard_payoffs_come_from_the_program_rules() {
+    let matrix = PayoffMatrix::new(&RuleSet::STANDARD);
+    assert_eq!(matrix.moves.len(), 5 * 9);
+    for (row, &mine) in matrix.moves.iter().enumerate() {
+        for (column, &theirs) in matrix.moves.iter().enumerate() {
+            let expected = match resolve(mine, theirs) {
+                Outcome::CreatorWins => 1.0,
+                Outcome::OpponentWins => -1.0,
+                Outcome::Draw => 0.0,
+            };
+            assert_eq!(matrix.payoffs[row][column], expected);
+            // Seats are interchangeable, so the game is symmetric.
+            assert_eq!(matrix.payoffs[column][row], -expected);
+        }
+    }
+
+    let zero = RuleSet {
+        zero_allowed: true,
+        ..RuleSet::STANDARD
+    };
+    assert_eq!(PayoffMatrix::new(&zero).moves.len(), 6 * 11);
+}
+
+#[test]
+fn every_variant_is_a_fair_table() {
+    for rules in RuleSet::variants() {
+        let equilibrium = solve(&rules).unwrap();
+        assert!(
+            equilibrium.value.abs() < TOLERANCE,
+            "{rules}: {equilibrium:?}"
+        );
+
+        let total: f64 = equilibrium.mix.iter().map(|(_, weight)| weight).sum();
+        assert!((total - 1.0).abs() < TOLERANCE);
+        assert!(equilibrium
+            .mix
+            .iter()
+            .all(|(chosen, _)| rules.is_valid(*chosen)));
+
+        // No reply does better against the mix than the table's value.
+        let matrix = PayoffMatrix::new(&rules);
+        let weights: Vec<f64> = matrix
+            .moves
+            .iter()
+            .map(|chosen| {
+                equilibrium
+                    .mix
+                    .iter()
+                    .find(|(played, _)| played == chosen)
+                    .map_or(0.0, |(_, weight)| *weight)
+            })
+            .collect();
+        assert!(matrix.guarantee(&weights) > equilibrium.value - TOLERANCE);
+    }
+}
+
+#[test]
+fn tiebreaks_make_uniform_play_exploitable() {
+    let closest = RuleSet {
+        neither_called: Tiebreak::Closest,
+        ..RuleSet::STANDARD
+    };
+    for (rules, exploitable) in [(RuleSet::STANDARD, false), (closest, true)] {
+        let matrix = PayoffMatrix::new(&rules);
+        // Every card equally, calling it plus a uniformly random card.
+        let uniform: Vec<f64> = matrix
+            .moves
+            .iter()
+            .map(|chosen| {
+                let theirs = chosen.prediction.checked_sub(chosen.card);
+                if theirs.is_some_and(|theirs| rules.cards().contains(&theirs)) {
+                    1.0 / 25.0
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        assert_eq!(
+            matrix.guarantee(&uniform) < -TOLERANCE,
+            exploitable,
+            "{rules}"
+        );
+    }
+}
+
+#[test]
+fn mix_csv_has_a_row_per_move() {
+    let equilibria = [solve(&RuleSet::STANDARD).unwrap()];
+    let mut out = Vec::new();
+    write_mix_csv(&mut out, &equilibria).unwrap();
+    let csv = String::from_utf8(out).unwrap();
+    assert_eq!(csv.lines().count(), 1 + equilibria[0].mix.len());
+    assert!(csv
+        .lines()
+        .nth(1)
+        .unwrap()
+        .starts_with("false,draw,draw,0,"));
+}
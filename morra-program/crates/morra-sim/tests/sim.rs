@@ -0,0 +1,91 @@
+use morra_bot::{Frequency, Markov, Round, Strategy, Uniform};
+use morra_core::{Move, Outcome, RuleSet};
+use morra_sim::{play_match, write_csv, Matchup, Tally};
+
+/// Always shows the same card and calls a total that never comes up.
+struct Stubborn(u8);
+
+impl Strategy for Stubborn {
+    fn choose(&mut self, _history: &[Round]) -> Move {
+        Move::new(self.0, 2)
+    }
+}
+
+#[test]
+fn intervals_narrow_with_more_rounds() {
+    let mut small = Tally::default();
+    for outcome in [
+        Outcome::CreatorWins,
+        Outcome::OpponentWins,
+        Outcome::Draw,
+        Outcome::CreatorWins,
+    ] {
+        small.record(outcome);
+    }
+    assert_eq!(small.rounds(), 4);
+    assert_eq!(small.win_rate().mean, 0.5);
+    assert_eq!(small.ev().mean, 0.25);
+
+    let large = Tally {
+        wins: small.wins * 100,
+        losses: small.losses * 100,
+        draws: small.draws * 100,
+    };
+    assert_eq!(large.ev().mean, small.ev().mean);
+    assert!(large.ev().half_width < small.ev().half_width / 9.0);
+    assert!(large.win_rate().low() > 0.4 && large.win_rate().high() < 0.6);
+    assert!(Tally::default().win_rate().half_width.is_infinite());
+}
+
+#[test]
+fn modelling_strategies_beat_a_predictable_opponent() {
+    let rules = RuleSet::STANDARD;
+    let frequency = play_match(
+        &rules,
+        &mut Frequency::new(Some(1)),
+        &mut Stubborn(4),
+        2_000,
+    );
+    assert!(frequency.ev().low() > 0.9, "{frequency:?}");
+    let markov = play_match(&rules, &mut Markov::new(Some(2)), &mut Stubborn(4), 2_000);
+    assert!(markov.ev().low() > 0.9, "{markov:?}");
+
+    // Uniform guessing calls a fixed card a fifth of the time.
+    let uniform = play_match(&rules, &mut Uniform::new(Some(3)), &mut Stubborn(4), 20_000);
+    let win = uniform.win_rate();
+    assert!(win.low() < 0.2 && 0.2 < win.high(), "{win:?}");
+}
+
+#[test]
+fn mirror_matches_are_even() {
+    let tally = play_match(
+        &RuleSet::STANDARD,
+        &mut Uniform::new(Some(4)),
+        &mut Uniform::new(Some(5)),
+        50_000,
+    );
+    let ev = tally.ev();
+    assert!(ev.low() < 0.0 && 0.0 < ev.high(), "{ev:?}");
+}
+
+#[test]
+fn csv_has_a_row_per_matchup() {
+    let matchup = Matchup {
+        rules: RuleSet::STANDARD,
+        first: "uniform".into(),
+        second: "markov".into(),
+        tally: Tally {
+            wins: 3,
+            losses: 1,
+            draws: 0,
+        },
+    };
+    let mut out = Vec::new();
+    write_csv(&mut out, &[matchup.clone(), matchup]).unwrap();
+    let csv = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3);
+    let columns = lines[0].split(',').count();
+    assert!(lines.iter().all(|line| line.split(',').count() == columns));
+    assert!(lines[1].starts_with("false,draw,draw,uniform,markov,4,3,1,0,0.75,"));
+}
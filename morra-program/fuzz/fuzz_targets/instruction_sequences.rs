@@ -0,0 +1,645 @@
+//! Random instruction sequences from random signers against a fresh bank.
+//!
+//! Run with:
+//!
+//!     ASAN_OPTIONS=detect_leaks=0 cargo +nightly fuzz run instruction_sequences
+//!
+//! Leak checking is off because the processor shim below leaks each
+//! instruction's account list on purpose.
+#![no_main]
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use anchor_lang::prelude::AccountInfo;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::{system_program, AccountDeserialize, InstructionData, ToAccountMetas};
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use morra_core::Move;
+use morra_program::{Game, GameStatus, LobbyPage, PlayerProfile};
+use solana_keypair::Keypair;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+// Anyone can create or join, so each of these ends up a participant or a
+// bystander depending on the sequence.
+const PLAYERS: usize = 4;
+const STARTING_BALANCE: u64 = 100_000_000_000;
+// Scales the fuzzed u32 so bets land below, inside and above the program's
+// MIN_BET..=MAX_BET window.
+const BET_SCALE: u64 = 4;
+const MAX_ACTIONS: usize = 16;
+
+#[derive(Arbitrary, Clone, Copy, Debug)]
+struct Secret {
+    card: u8,
+    prediction: u8,
+    salt: u8,
+}
+
+impl Secret {
+    fn salt(&self) -> [u8; 32] {
+        [self.salt; 32]
+    }
+
+    fn commitment(&self, player: &Pubkey, game: &Pubkey) -> [u8; 32] {
+        morra_core::commitment(
+            &player.to_bytes(),
+            &game.to_bytes(),
+            Move::new(self.card, self.prediction),
+            &self.salt(),
+        )
+    }
+}
+
+/// Games are seeded by their creator, so `game` indexes the same player
+/// table as the signers. The timeout cranks first move the clock on by
+/// `minutes`, so both the join and the move timeouts can be reached.
+#[derive(Arbitrary, Debug)]
+enum Action {
+    Create { creator: u8, bet: u32, ranked: bool, secret: Secret },
+    Join { game: u8, opponent: u8, secret: Secret },
+    Reveal { game: u8, player: u8, honest: bool, secret: Secret },
+    Claim { game: u8, winner: u8 },
+    Refund { game: u8 },
+    Cancel { game: u8, creator: u8 },
+    Expire { game: u8, cranker: u8, minutes: u16 },
+    Forfeit { game: u8, cranker: u8, minutes: u16 },
+}
+
+impl Action {
+    /// The game whose escrow this action is allowed to pay out of.
+    fn payout_game(&self) -> Option<usize> {
+        match *self {
+            Action::Claim { game, .. }
+            | Action::Refund { game }
+            | Action::Cancel { game, .. }
+            | Action::Expire { game, .. }
+            | Action::Forfeit { game, .. } => Some(index(game)),
+            _ => None,
+        }
+    }
+}
+
+fn profile_address(player: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"profile", player.as_ref()], &morra_program::ID).0
+}
+
+fn lobby_address(ranked: bool) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"lobby", &[u8::from(ranked)], &0u32.to_le_bytes()],
+        &morra_program::ID,
+    )
+    .0
+}
+
+fn side_bet_pool_address(game: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"side_bet", game.as_ref()], &morra_program::ID).0
+}
+
+fn side_bet_address(pool: &Pubkey, bettor: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"side_bet", pool.as_ref(), bettor.as_ref()], &morra_program::ID)
+        .0
+}
+
+fn index(raw: u8) -> usize {
+    usize::from(raw) % PLAYERS
+}
+
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    morra_program::entry(program_id, accounts, data)
+}
+
+fn runtime() -> &'static tokio::runtime::Runtime {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    })
+}
+
+fn rank(status: GameStatus) -> u8 {
+    match status {
+        GameStatus::WaitingForOpponent | GameStatus::WaitingForCommits => 0,
+        GameStatus::WaitingForReveal => 1,
+        GameStatus::Draw | GameStatus::Completed => 2,
+        GameStatus::Settled | GameStatus::Cancelled => 3,
+    }
+}
+
+fn is_resolved(status: GameStatus) -> bool {
+    rank(status) >= 2
+}
+
+struct Snapshot {
+    players: Vec<u64>,
+    games: Vec<Option<Game>>,
+    game_lamports: Vec<u64>,
+    escrows: Vec<u64>,
+    profiles: Vec<PlayerProfile>,
+    lobbies: Vec<LobbyPage>,
+}
+
+impl Snapshot {
+    fn total(&self) -> u64 {
+        self.players.iter().sum::<u64>()
+            + self.game_lamports.iter().sum::<u64>()
+            + self.escrows.iter().sum::<u64>()
+    }
+}
+
+struct Bank {
+    context: ProgramTestContext,
+    players: Vec<Keypair>,
+    games: Vec<Pubkey>,
+    escrows: Vec<Pubkey>,
+    profiles: Vec<Pubkey>,
+    secrets: HashMap<(usize, Pubkey), Secret>,
+}
+
+impl Bank {
+    async fn new() -> Self {
+        let players: Vec<Keypair> = (0..PLAYERS)
+            .map(|i| Keypair::new_from_array([i as u8 + 1; 32]))
+            .collect();
+        let mut program_test = ProgramTest::new(
+            "morra_program",
+            morra_program::ID,
+            processor!(process_instruction),
+        );
+        for player in &players {
+            program_test.add_account(
+                player.pubkey(),
+                solana_account::Account::new(STARTING_BALANCE, 0, &system_program::ID),
+            );
+        }
+        let games: Vec<Pubkey> = players
+            .iter()
+            .map(|player| {
+                // Only each player's first game is tracked; later creates
+                // by the same player miss this address and are rejected.
+                Pubkey::find_program_address(
+                    &[b"game", player.pubkey().as_ref(), &0u64.to_le_bytes()],
+                    &morra_program::ID,
+                )
+                .0
+            })
+            .collect();
+        let escrows = games
+            .iter()
+            .map(|game| Pubkey::find_program_address(&[b"game", game.as_ref()], &morra_program::ID).0)
+            .collect();
+
+        let profiles = players
+            .iter()
+            .map(|player| profile_address(&player.pubkey()))
+            .collect();
+
+        let mut bank = Self {
+            context: program_test.start_with_context().await,
+            players,
+            games,
+            escrows,
+            profiles,
+            secrets: HashMap::new(),
+        };
+        // Every player starts with a profile, funded by the bank's payer so
+        // player balances stay at STARTING_BALANCE.
+        for player in 0..PLAYERS {
+            let signer = bank.players[player].insecure_clone();
+            let instruction = Instruction {
+                program_id: morra_program::ID,
+                accounts: morra_program::accounts::CreateProfile {
+                    profile: bank.profiles[player],
+                    player: signer.pubkey(),
+                    payer: bank.context.payer.pubkey(),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: morra_program::instruction::CreateProfile {}.data(),
+            };
+            bank.send(instruction, Some(signer)).await.unwrap();
+        }
+        // One page per mode holds every game the players can open.
+        for ranked in [false, true] {
+            let instruction = Instruction {
+                program_id: morra_program::ID,
+                accounts: morra_program::accounts::CreateLobbyPage {
+                    lobby: lobby_address(ranked),
+                    payer: bank.context.payer.pubkey(),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: morra_program::instruction::CreateLobbyPage { ranked, index: 0 }.data(),
+            };
+            bank.send(instruction, None).await.unwrap();
+        }
+        bank
+    }
+
+    /// The lobby page `game` is listed on, or the casual page if it doesn't
+    /// exist yet.
+    async fn lobby_of(&mut self, game: usize) -> Pubkey {
+        let ranked = self.snapshot().await.games[game]
+            .as_ref()
+            .is_some_and(|game| game.ranked);
+        lobby_address(ranked)
+    }
+
+    /// The profile the program expects for whoever sits in `game`'s
+    /// opponent seat, or a placeholder before anyone has joined.
+    async fn opponent_of(&mut self, game: usize) -> Pubkey {
+        self.snapshot().await.games[game]
+            .as_ref()
+            .map_or(Pubkey::default(), |game| game.opponent)
+    }
+
+    async fn snapshot(&mut self) -> Snapshot {
+        let client = &mut self.context.banks_client;
+        let mut snapshot = Snapshot {
+            players: Vec::with_capacity(PLAYERS),
+            games: Vec::with_capacity(PLAYERS),
+            game_lamports: Vec::with_capacity(PLAYERS),
+            escrows: Vec::with_capacity(PLAYERS),
+            profiles: Vec::with_capacity(PLAYERS),
+            lobbies: Vec::with_capacity(2),
+        };
+        for player in &self.players {
+            snapshot.players.push(client.get_balance(player.pubkey()).await.unwrap());
+        }
+        for (game, escrow) in self.games.iter().zip(&self.escrows) {
+            let account = client.get_account(*game).await.unwrap();
+            snapshot.game_lamports.push(account.as_ref().map_or(0, |account| account.lamports));
+            snapshot.games.push(
+                account.map(|account| Game::try_deserialize(&mut account.data.as_slice()).unwrap()),
+            );
+            snapshot.escrows.push(client.get_balance(*escrow).await.unwrap());
+        }
+        for profile in &self.profiles {
+            let account = client.get_account(*profile).await.unwrap().unwrap();
+            snapshot
+                .profiles
+                .push(PlayerProfile::try_deserialize(&mut account.data.as_slice()).unwrap());
+        }
+        for ranked in [false, true] {
+            let account = client.get_account(lobby_address(ranked)).await.unwrap().unwrap();
+            snapshot
+                .lobbies
+                .push(LobbyPage::try_deserialize(&mut account.data.as_slice()).unwrap());
+        }
+        snapshot
+    }
+
+    async fn execute(&mut self, action: &Action) {
+        // Secrets are only remembered once their commitment lands on chain.
+        let mut committed = None;
+        let (instruction, signer): (Instruction, Option<Keypair>) = match *action {
+            Action::Create { creator, bet, ranked, secret } => {
+                let creator = index(creator);
+                let signer = self.players[creator].insecure_clone();
+                committed = Some(((creator, signer.pubkey()), secret));
+                let instruction = Instruction {
+                    program_id: morra_program::ID,
+                    accounts: morra_program::accounts::CreateGame {
+                        game: self.games[creator],
+                        creator: signer.pubkey(),
+                        payer: signer.pubkey(),
+                        creator_profile: self.profiles[creator],
+                        lobby: lobby_address(ranked),
+                        game_account: self.escrows[creator],
+                        system_program: system_program::ID,
+                        rent: sysvar::rent::ID,
+                    }
+                    .to_account_metas(None),
+                    data: morra_program::instruction::CreateGame {
+                        bet_amount: u64::from(bet) * BET_SCALE,
+                        commitment: secret.commitment(&signer.pubkey(), &self.games[creator]),
+                        ranked,
+                        reimbursement: 0,
+                    }
+                    .data(),
+                };
+                (instruction, Some(signer))
+            }
+            Action::Join { game, opponent, secret } => {
+                let game = index(game);
+                let signer = self.players[index(opponent)].insecure_clone();
+                committed = Some(((game, signer.pubkey()), secret));
+                let lobby = self.lobby_of(game).await;
+                let instruction = Instruction {
+                    program_id: morra_program::ID,
+                    accounts: morra_program::accounts::JoinGame {
+                        game: self.games[game],
+                        opponent: signer.pubkey(),
+                        payer: signer.pubkey(),
+                        opponent_profile: profile_address(&signer.pubkey()),
+                        lobby,
+                        game_account: self.escrows[game],
+                        system_program: system_program::ID,
+                        side_bet_pool: side_bet_pool_address(&self.games[game]),
+                        opponent_side_bet: side_bet_address(
+                            &side_bet_pool_address(&self.games[game]),
+                            &signer.pubkey(),
+                        ),
+                    }
+                    .to_account_metas(None),
+                    data: morra_program::instruction::JoinGame {
+                        commitment: secret.commitment(&signer.pubkey(), &self.games[game]),
+                        reimbursement: 0,
+                    }
+                    .data(),
+                };
+                (instruction, Some(signer))
+            }
+            Action::Reveal { game, player, honest, secret } => {
+                let game = index(game);
+                let signer = self.players[index(player)].insecure_clone();
+                let secret = match self.secrets.get(&(game, signer.pubkey())) {
+                    Some(committed) if honest => *committed,
+                    _ => secret,
+                };
+                let opponent = self.opponent_of(game).await;
+                let instruction = Instruction {
+                    program_id: morra_program::ID,
+                    accounts: morra_program::accounts::RevealMove {
+                        game: self.games[game],
+                        player: signer.pubkey(),
+                        creator_profile: self.profiles[game],
+                        opponent_profile: profile_address(&opponent),
+                        session: None,
+                        session_scope: None,
+                    }
+                    .to_account_metas(None),
+                    data: morra_program::instruction::RevealMove {
+                        card: secret.card,
+                        prediction: secret.prediction,
+                        salt: secret.salt(),
+                    }
+                    .data(),
+                };
+                (instruction, Some(signer))
+            }
+            Action::Claim { game, winner } => {
+                let game = index(game);
+                let signer = self.players[index(winner)].insecure_clone();
+                let instruction = Instruction {
+                    program_id: morra_program::ID,
+                    accounts: morra_program::accounts::ClaimWinnings {
+                        game: self.games[game],
+                        winner: signer.pubkey(),
+                        game_account: self.escrows[game],
+                        sponsor: None,
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: morra_program::instruction::ClaimWinnings {}.data(),
+                };
+                (instruction, Some(signer))
+            }
+            Action::Refund { game } => {
+                let game = index(game);
+                // Refunds go to whoever the game recorded, so pass the
+                // creator and the game's current opponent field as-is.
+                let opponent = self.opponent_of(game).await;
+                let instruction = Instruction {
+                    program_id: morra_program::ID,
+                    accounts: morra_program::accounts::RefundDraw {
+                        game: self.games[game],
+                        creator: self.players[game].pubkey(),
+                        opponent,
+                        game_account: self.escrows[game],
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: morra_program::instruction::RefundDraw {}.data(),
+                };
+                (instruction, None)
+            }
+            Action::Cancel { game, creator } => {
+                let game = index(game);
+                let signer = self.players[index(creator)].insecure_clone();
+                let lobby = self.lobby_of(game).await;
+                let instruction = Instruction {
+                    program_id: morra_program::ID,
+                    accounts: morra_program::accounts::CancelGame {
+                        game: self.games[game],
+                        creator: signer.pubkey(),
+                        lobby,
+                        game_account: self.escrows[game],
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: morra_program::instruction::CancelGame {}.data(),
+                };
+                (instruction, Some(signer))
+            }
+            Action::Expire { game, cranker, minutes } => {
+                self.warp(minutes).await;
+                let game = index(game);
+                let signer = self.players[index(cranker)].insecure_clone();
+                let lobby = self.lobby_of(game).await;
+                // No config exists, so the crank goes unrewarded.
+                let instruction = Instruction {
+                    program_id: morra_program::ID,
+                    accounts: morra_program::accounts::ExpireGame {
+                        game: self.games[game],
+                        creator: self.players[game].pubkey(),
+                        lobby,
+                        game_account: self.escrows[game],
+                        config: None,
+                        cranker: signer.pubkey(),
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: morra_program::instruction::ExpireGame {}.data(),
+                };
+                (instruction, Some(signer))
+            }
+            Action::Forfeit { game, cranker, minutes } => {
+                self.warp(minutes).await;
+                let game = index(game);
+                let signer = self.players[index(cranker)].insecure_clone();
+                let opponent = self.opponent_of(game).await;
+                let instruction = Instruction {
+                    program_id: morra_program::ID,
+                    accounts: morra_program::accounts::ForfeitGame {
+                        game: self.games[game],
+                        creator: self.players[game].pubkey(),
+                        opponent,
+                        creator_profile: self.profiles[game],
+                        opponent_profile: profile_address(&opponent),
+                        game_account: self.escrows[game],
+                        config: None,
+                        cranker: signer.pubkey(),
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: morra_program::instruction::ForfeitGame {}.data(),
+                };
+                (instruction, Some(signer))
+            }
+        };
+
+        // Rejected instructions are expected; only the resulting state matters.
+        let result = self.send(instruction, signer).await;
+        if let (Ok(()), Some((key, secret))) = (result, committed) {
+            self.secrets.insert(key, secret);
+        }
+    }
+
+    async fn warp(&mut self, minutes: u16) {
+        let mut clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp += i64::from(minutes) * 60;
+        self.context.set_sysvar(&clock);
+    }
+
+    async fn send(
+        &mut self,
+        instruction: Instruction,
+        signer: Option<Keypair>,
+    ) -> Result<(), BanksClientError> {
+        let mut signers = vec![&self.context.payer];
+        signers.extend(signer.as_ref());
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.context.payer.pubkey()),
+            &signers,
+            self.context.last_blockhash,
+        );
+        self.context.banks_client.process_transaction(transaction).await
+    }
+
+    fn check(&self, action: &Action, before: &Snapshot, after: &Snapshot) {
+        assert_eq!(
+            before.total(),
+            after.total(),
+            "lamports left the escrow system after {action:?}"
+        );
+
+        // Every resolved game credits one side and debits the other.
+        let wins: u64 = after.profiles.iter().map(|profile| profile.wins).sum();
+        let losses: u64 = after.profiles.iter().map(|profile| profile.losses).sum();
+        let net: i64 = after.profiles.iter().map(|profile| profile.net_winnings).sum();
+        assert_eq!(wins, losses, "profile wins and losses diverged after {action:?}");
+        assert_eq!(net, 0, "profile net winnings don't cancel out after {action:?}");
+        // Ratings can't hit the zero floor within MAX_ACTIONS games, so
+        // every ranked result is exactly zero-sum.
+        let ratings: u64 = after.profiles.iter().map(|profile| u64::from(profile.rating)).sum();
+        assert_eq!(
+            ratings,
+            PLAYERS as u64 * u64::from(morra_core::INITIAL_RATING),
+            "rating points were created or destroyed after {action:?}"
+        );
+        for (player, (old, new)) in before.profiles.iter().zip(&after.profiles).enumerate() {
+            assert!(
+                new.games_played >= old.games_played
+                    && new.games_played == new.wins + new.losses + new.draws,
+                "player {player}'s profile tally is inconsistent after {action:?}"
+            );
+        }
+
+        // The lobbies list exactly the games still waiting for an opponent.
+        for (ranked, lobby) in [false, true].into_iter().zip(&after.lobbies) {
+            let mut listed: Vec<Pubkey> = lobby.games.iter().map(|entry| entry.game).collect();
+            let mut waiting: Vec<Pubkey> = after
+                .games
+                .iter()
+                .zip(&self.games)
+                .filter_map(|(game, key)| {
+                    game.as_ref()
+                        .filter(|game| {
+                            game.ranked == ranked && game.status == GameStatus::WaitingForOpponent
+                        })
+                        .map(|_| *key)
+                })
+                .collect();
+            listed.sort();
+            waiting.sort();
+            assert_eq!(listed, waiting, "lobby out of sync after {action:?}");
+        }
+
+        for (i, (old, new)) in before.games.iter().zip(&after.games).enumerate() {
+            let Some(old) = old else { continue };
+            let new = new
+                .as_ref()
+                .unwrap_or_else(|| panic!("game {i} disappeared after {action:?}"));
+            assert!(
+                rank(new.status) >= rank(old.status),
+                "game {i} status moved backwards after {action:?}"
+            );
+            if is_resolved(old.status) {
+                assert!(
+                    new.winner == old.winner
+                        && (new.status == old.status || new.status == GameStatus::Settled),
+                    "resolved game {i} changed outcome after {action:?}"
+                );
+            }
+            if !is_resolved(new.status) {
+                assert!(
+                    after.escrows[i] >= before.escrows[i],
+                    "unsettled game {i} lost escrow after {action:?}"
+                );
+            }
+            assert!(
+                after.escrows[i] <= 2 * new.bet_amount,
+                "game {i} escrow exceeds its pot after {action:?}"
+            );
+        }
+
+        for (player, (old, new)) in before.players.iter().zip(&after.players).enumerate() {
+            if new <= old {
+                continue;
+            }
+            let key = self.players[player].pubkey();
+            let game = action
+                .payout_game()
+                .and_then(|game| before.games[game].as_ref())
+                .unwrap_or_else(|| panic!("player {player} was paid by {action:?}"));
+            let entitled = match game.status {
+                // Only the creator can withdraw from an unjoined game.
+                GameStatus::WaitingForOpponent => {
+                    matches!(action, Action::Cancel { .. } | Action::Expire { .. })
+                        && key == game.creator
+                }
+                // A game neither player moved in refunds both once forfeited.
+                GameStatus::WaitingForCommits | GameStatus::WaitingForReveal => {
+                    matches!(action, Action::Forfeit { .. })
+                        && (key == game.creator || key == game.opponent)
+                }
+                GameStatus::Draw | GameStatus::Completed => match game.winner {
+                    Some(winner) => winner == key,
+                    None => key == game.creator || key == game.opponent,
+                },
+                _ => panic!("player {player} was paid from a {:?} game by {action:?}", game.status),
+            };
+            assert!(entitled, "non-participant {player} was paid by {action:?}");
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct Sequence {
+    actions: Vec<Action>,
+}
+
+fuzz_target!(|sequence: Sequence| {
+    runtime().block_on(async {
+        let mut bank = Bank::new().await;
+        let mut before = bank.snapshot().await;
+        for action in sequence.actions.iter().take(MAX_ACTIONS) {
+            bank.execute(action).await;
+            let after = bank.snapshot().await;
+            bank.check(action, &before, &after);
+            before = after;
+        }
+    });
+});
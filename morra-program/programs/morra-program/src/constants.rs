@@ -0,0 +1,46 @@
+pub const GAME_SEED: &[u8] = b"game";
+pub const PROFILE_SEED: &[u8] = b"profile";
+pub const LOBBY_SEED: &[u8] = b"lobby";
+pub const QUEUE_SEED: &[u8] = b"queue";
+pub const TOURNAMENT_SEED: &[u8] = b"tournament";
+pub const LEAGUE_SEED: &[u8] = b"league";
+pub const SIDE_BET_SEED: &[u8] = b"side_bet";
+pub const SESSION_SEED: &[u8] = b"session";
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const HOUSE_SEED: &[u8] = b"house";
+pub const BANKROLL_SEED: &[u8] = b"bankroll";
+pub const LP_SEED: &[u8] = b"lp";
+
+pub const MIN_BET: u64 = 100_000_000; // 0.1 SOL
+pub const MAX_BET: u64 = 10_000_000_000; // 10 SOL
+
+pub const MIN_SIDE_BET: u64 = 10_000_000; // 0.01 SOL
+
+/// How long a game can wait for an opponent before anyone may cancel it.
+pub const JOIN_TIMEOUT: i64 = 24 * 60 * 60;
+/// How long a joined game can wait on a move before anyone may forfeit
+/// the player holding it up.
+pub const MOVE_TIMEOUT: i64 = 60 * 60;
+
+/// How many times a bracket match is replayed after neither player moves
+/// in its game, before the higher seed advances on a walkover.
+pub const MAX_NO_SHOW_REMATCHES: u8 = 2;
+
+/// How long a liquidity provider waits between asking to withdraw from
+/// the bankroll and taking the lamports out.
+pub const WITHDRAWAL_COOLDOWN: i64 = 24 * 60 * 60;
+/// How long a cooled-down withdrawal request can be redeemed before it
+/// lapses and has to be filed again.
+pub const WITHDRAWAL_WINDOW: i64 = 24 * 60 * 60;
+
+/// Longest a session key can be authorized for.
+pub const MAX_SESSION_DURATION: i64 = 7 * 24 * 60 * 60;
+
+/// Stakes offered by the quick-play queues, indexed by tier.
+pub const BET_TIERS: [u64; 5] = [
+    100_000_000,    // 0.1 SOL
+    500_000_000,    // 0.5 SOL
+    1_000_000_000,  // 1 SOL
+    5_000_000_000,  // 5 SOL
+    10_000_000_000, // 10 SOL
+];
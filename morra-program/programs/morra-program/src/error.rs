@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum GameError {
+    #[msg("Invalid game state for this operation")]
+    InvalidGameState,
+    
+    #[msg("Invalid commitment provided")]
+    InvalidCommitment,
+    
+    #[msg("Only the winner can claim winnings")]
+    NotWinner,
+    
+    #[msg("Invalid bet amount")]
+    InvalidBetAmount,
+    
+    #[msg("Cannot join your own game")]
+    CannotJoinOwnGame,
+    
+    #[msg("Only the game's players can do this")]
+    InvalidPlayer,
+    
+    #[msg("Invalid card")]
+    InvalidCard,
+    
+    #[msg("Invalid prediction")]
+    InvalidPrediction,
+    
+    #[msg("Illegal game status transition")]
+    InvalidTransition,
+    
+    #[msg("Profile does not belong to this game's player")]
+    InvalidProfile,
+    
+    #[msg("Lobby page is full")]
+    LobbyFull,
+    
+    #[msg("Lobby page does not match this game")]
+    InvalidLobby,
+    
+    #[msg("Match queue is full")]
+    QueueFull,
+    
+    #[msg("Player is already in this queue")]
+    AlreadyQueued,
+    
+    #[msg("Player is not in this queue")]
+    NotQueued,
+    
+    #[msg("No compatible players to pair")]
+    NoMatch,
+    
+    #[msg("Player has already committed a move")]
+    AlreadyCommitted,
+    
+    #[msg("Invalid tournament configuration")]
+    InvalidTournamentConfig,
+    
+    #[msg("Invalid tournament state for this operation")]
+    InvalidTournamentState,
+    
+    #[msg("Tournament registration has closed")]
+    RegistrationClosed,
+    
+    #[msg("Tournament is full")]
+    TournamentFull,
+    
+    #[msg("Player is already registered")]
+    AlreadyRegistered,
+    
+    #[msg("Player is not registered")]
+    NotRegistered,
+    
+    #[msg("Not enough players to start")]
+    NotEnoughPlayers,
+    
+    #[msg("Bracket match is not ready for this operation")]
+    InvalidMatch,
+    
+    #[msg("No unclaimed prize for this player")]
+    NoPrize,
+    
+    #[msg("Invalid league configuration")]
+    InvalidLeagueConfig,
+    
+    #[msg("Invalid league state for this operation")]
+    InvalidLeagueState,
+    
+    #[msg("Player is not on the league roster")]
+    NotOnRoster,
+    
+    #[msg("The season has not ended")]
+    SeasonNotOver,
+    
+    #[msg("Side betting has closed for this game")]
+    BettingClosed,
+    
+    #[msg("Players cannot side-bet on their own game")]
+    PlayerCannotBet,
+    
+    #[msg("Session key is not valid for this game")]
+    InvalidSession,
+    
+    #[msg("Session key has expired")]
+    SessionExpired,
+    
+    #[msg("Reimbursement needs a separate payer and can't exceed the bet")]
+    InvalidReimbursement,
+    
+    #[msg("Sponsor account does not match the game")]
+    InvalidSponsor,
+    
+    #[msg("The game has not timed out")]
+    TimeoutNotReached,
+    
+    #[msg("Only the config authority can do this")]
+    Unauthorized,
+    
+    #[msg("Only the house operator can do this")]
+    NotHouseOperator,
+    
+    #[msg("Bet exceeds what the house bankroll can cover")]
+    ExposureLimit,
+    
+    #[msg("Rates are capped at 10,000 basis points")]
+    InvalidRate,
+    
+    #[msg("Share amount must be positive and within the position")]
+    InvalidShareAmount,
+    
+    #[msg("The withdrawal cooldown has not elapsed")]
+    CooldownNotElapsed,
+    
+    #[msg("The withdrawal request has lapsed; file a new one")]
+    WithdrawalExpired,
+    
+    #[msg("The house bond can't cover another open game")]
+    BondExhausted,
+    
+    #[msg("The game has timed out")]
+    GameTimedOut,
+    
+    #[msg("The house bond is backing open games")]
+    BondLocked,
+    
+    #[msg("Side bettors cannot join the game they bet on")]
+    BettorCannotJoin,
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use morra_core::Outcome;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: carry a resolved match game's result into the bracket.
+/// A win advances the winner and a draw frees the match for a rematch. A
+/// game cancelled because neither player moved before the timeout is
+/// replayed up to `MAX_NO_SHOW_REMATCHES` times, after which the higher
+/// seed advances so two no-shows can't hold up the bracket.
+#[derive(Accounts)]
+pub struct AdvanceTournament<'info> {
+    #[account(
+        mut,
+        seeds = [
+            TOURNAMENT_SEED,
+            tournament.organizer.as_ref(),
+            &tournament.id.to_le_bytes()
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+}
+
+impl<'info> AdvanceTournament<'info> {
+    pub fn advance_tournament(&mut self, match_index: u8) -> Result<()> {
+        let tournament = &mut self.tournament;
+        require!(
+            tournament.status == TournamentStatus::InProgress,
+            GameError::InvalidTournamentState
+        );
+        let index = usize::from(match_index);
+        let bracket_match = tournament.matches.get(index).ok_or(GameError::InvalidMatch)?;
+        require!(
+            bracket_match.game == Some(self.game.key()),
+            GameError::InvalidMatch
+        );
+
+        let Some(outcome) = self.game.outcome() else {
+            require!(
+                self.game.status == GameStatus::Cancelled,
+                GameError::InvalidGameState
+            );
+            let bracket_match = &mut tournament.matches[index];
+            if bracket_match.no_shows < MAX_NO_SHOW_REMATCHES {
+                bracket_match.no_shows += 1;
+                bracket_match.game = None;
+                return Ok(());
+            }
+            let seed = tournament.higher_seed(index).ok_or(GameError::InvalidMatch)?;
+            return tournament.advance(index, seed);
+        };
+        match outcome {
+            Outcome::CreatorWins => tournament.advance(index, self.game.creator),
+            Outcome::OpponentWins => tournament.advance(index, self.game.opponent),
+            Outcome::Draw => {
+                tournament.matches[index].game = None;
+                Ok(())
+            }
+        }
+    }
+}
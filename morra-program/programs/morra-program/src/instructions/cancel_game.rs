@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelGame<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump,
+        has_one = creator @ GameError::InvalidPlayer
+    )]
+    pub game: Account<'info, Game>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LOBBY_SEED, &[u8::from(game.ranked)], &game.lobby_page.to_le_bytes()],
+        bump = lobby.bump
+    )]
+    pub lobby: Account<'info, LobbyPage>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the game funds
+    pub game_account: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelGame<'info> {
+    pub fn cancel_game(&mut self, bumps: &CancelGameBumps) -> Result<()> {
+        let game = &mut self.game;
+        game.transition(GameStatus::WaitingForOpponent, GameStatus::Cancelled)?;
+        game.last_action_at = Clock::get()?.unix_timestamp;
+        self.lobby.remove(&game.key())?;
+
+        // Return the creator's bet from the game account
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.game_account.to_account_info(),
+                    to: self.creator.to_account_info(),
+                },
+                &[&[
+                    GAME_SEED,
+                    &game.key().to_bytes(),
+                    &[bumps.game_account],
+                ]],
+            ),
+            game.bet_amount,
+        )?;
+
+        Ok(())
+    }
+}
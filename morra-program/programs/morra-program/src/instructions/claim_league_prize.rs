@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ClaimLeaguePrize<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, league.organizer.as_ref(), &league.id.to_le_bytes()],
+        bump = league.bump
+    )]
+    pub league: Account<'info, League>,
+    
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, league.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the pot
+    pub league_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimLeaguePrize<'info> {
+    pub fn claim_league_prize(&mut self, bumps: &ClaimLeaguePrizeBumps) -> Result<()> {
+        let league = &mut self.league;
+        require!(
+            league.status == LeagueStatus::Completed,
+            GameError::InvalidLeagueState
+        );
+        let index = league.roster_index(&self.player.key())?;
+        let amount = league.prize(index).ok_or(GameError::NoPrize)?;
+        let claimed_bit = 1 << index;
+        require!(league.prizes_claimed & claimed_bit == 0, GameError::NoPrize);
+        league.prizes_claimed |= claimed_bit;
+
+        // Pay the player's share of the pot from the vault
+        let league_key = league.key();
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.league_vault.to_account_info(),
+                    to: self.player.to_account_info(),
+                },
+                &[&[LEAGUE_SEED, league_key.as_ref(), &[bumps.league_vault]]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::state::*;
+
+/// Collect a side bet's winnings or refund, closing the bet. The first
+/// claim settles the pool from the game.
+#[derive(Accounts)]
+pub struct ClaimSideBet<'info> {
+    #[account(mut)]
+    pub bettor: Signer<'info>,
+    
+    #[account(
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    #[account(
+        mut,
+        seeds = [SIDE_BET_SEED, game.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, SideBetPool>,
+    
+    #[account(
+        mut,
+        close = bettor,
+        seeds = [SIDE_BET_SEED, pool.key().as_ref(), bettor.key().as_ref()],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, SideBet>,
+    
+    #[account(
+        mut,
+        seeds = [SIDE_BET_SEED, pool.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds side-bet stakes
+    pub pool_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimSideBet<'info> {
+    pub fn claim_side_bet(&mut self, bumps: &ClaimSideBetBumps) -> Result<()> {
+        let pool = &mut self.pool;
+        pool.settle(&self.game, Clock::get()?.unix_timestamp)?;
+        let amount = pool.claim(&self.bet)?;
+        if amount == 0 {
+            return Ok(());
+        }
+
+        // Pay out of the pool vault
+        let pool_key = pool.key();
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.pool_vault.to_account_info(),
+                    to: self.bettor.to_account_info(),
+                },
+                &[&[SIDE_BET_SEED, pool_key.as_ref(), &[bumps.pool_vault]]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ClaimTournamentPrize<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            TOURNAMENT_SEED,
+            tournament.organizer.as_ref(),
+            &tournament.id.to_le_bytes()
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, tournament.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the prize pool
+    pub prize_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimTournamentPrize<'info> {
+    pub fn claim_tournament_prize(&mut self, bumps: &ClaimTournamentPrizeBumps) -> Result<()> {
+        let tournament = &mut self.tournament;
+        require!(
+            tournament.status == TournamentStatus::Completed,
+            GameError::InvalidTournamentState
+        );
+        let place = tournament
+            .placement(&self.player.key())
+            .ok_or(GameError::NoPrize)?;
+        let claimed_bit = 1 << place;
+        require!(tournament.prizes_claimed & claimed_bit == 0, GameError::NoPrize);
+        tournament.prizes_claimed |= claimed_bit;
+        let amount = tournament.prizes()[place];
+
+        // Pay the placement's prize from the vault
+        let tournament_key = tournament.key();
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.prize_vault.to_account_info(),
+                    to: self.player.to_account_info(),
+                },
+                &[&[TOURNAMENT_SEED, tournament_key.as_ref(), &[bumps.prize_vault]]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
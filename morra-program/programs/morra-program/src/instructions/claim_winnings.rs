@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ClaimWinnings<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    #[account(mut)]
+    pub winner: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the game funds
+    pub game_account: AccountInfo<'info>,
+    
+    /// CHECK: The winner's sponsor, checked against the game; required
+    /// only when the winner owes a reimbursement
+    #[account(mut)]
+    pub sponsor: Option<UncheckedAccount<'info>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimWinnings<'info> {
+    pub fn claim_winnings(&mut self, bumps: &ClaimWinningsBumps) -> Result<()> {
+        let game = &mut self.game;
+        let winner = &self.winner;
+
+        game.transition(GameStatus::Completed, GameStatus::Settled)?;
+        require!(
+            game.winner == Some(winner.key()),
+            GameError::NotWinner
+        );
+
+        let payout = game.payout()?;
+        let mut amount = if winner.key() == game.creator {
+            payout.creator
+        } else {
+            payout.opponent
+        };
+
+        let game_key = game.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            GAME_SEED,
+            game_key.as_ref(),
+            &[bumps.game_account],
+        ]];
+
+        // Repay the relayer that sponsored the winner, out of the winnings
+        if let Some(sponsor) = game.sponsor_of(&winner.key()) {
+            let relayer = self
+                .sponsor
+                .as_ref()
+                .filter(|account| account.key() == sponsor.relayer)
+                .ok_or(GameError::InvalidSponsor)?;
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: self.game_account.to_account_info(),
+                        to: relayer.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                sponsor.reimbursement,
+            )?;
+            amount -= sponsor.reimbursement;
+        }
+
+        // Transfer winnings to winner
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.game_account.to_account_info(),
+                    to: self.winner.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        Ok(())
+    }
+}
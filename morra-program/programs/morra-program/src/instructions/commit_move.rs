@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Commit to a move in a game whose seats were filled for the players, such
+/// as a tournament match.
+#[derive(Accounts)]
+pub struct CommitMove<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    
+    /// The player, or a session key they authorized.
+    pub player: Signer<'info>,
+    
+    #[account(
+        seeds = [SESSION_SEED, session.player.as_ref(), player.key().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    
+    /// CHECK: The tournament or league the session is scoped to, read by
+    /// `SessionKey::authorize`
+    pub session_scope: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> CommitMove<'info> {
+    pub fn commit_move(&mut self, commitment: [u8; 32]) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let player = match &self.session {
+            Some(session) => session.authorize(
+                &self.player.key(),
+                &self.game.key(),
+                self.session_scope.as_deref(),
+                now,
+            )?,
+            None => self.player.key(),
+        };
+
+        let game = &mut self.game;
+        require!(game.status == GameStatus::WaitingForCommits, GameError::InvalidGameState);
+        require!(!game.timed_out(now), GameError::GameTimedOut);
+
+        let player_move = game
+            .player_move_mut(&player)
+            .ok_or(GameError::InvalidPlayer)?;
+        require!(player_move.committed_hash.is_none(), GameError::AlreadyCommitted);
+        *player_move = PlayerMove::committed(commitment);
+        game.last_action_at = now;
+
+        let both_committed = game.creator_move.committed_hash.is_some()
+            && game.opponent_move.committed_hash.is_some();
+        if both_committed {
+            game.transition(GameStatus::WaitingForCommits, GameStatus::WaitingForReveal)?;
+        }
+        Ok(())
+    }
+}
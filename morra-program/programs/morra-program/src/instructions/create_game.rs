@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bet_amount: u64, commitment: [u8; 32], ranked: bool)]
+pub struct CreateGame<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Pays the game account's rent. Usually the creator, or a relayer
+    /// sponsoring them.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Players need a profile before they can play, so resolution can
+    /// always record the result. It also numbers the creator's games.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, creator.key().as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Game::LEN,
+        seeds = [
+            GAME_SEED,
+            creator.key().as_ref(),
+            &creator_profile.next_game_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    /// Any page of the game's mode with room; the game records which.
+    #[account(
+        mut,
+        constraint = lobby.ranked == ranked @ GameError::InvalidLobby
+    )]
+    pub lobby: Account<'info, LobbyPage>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the game funds
+    pub game_account: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> CreateGame<'info> {
+    pub fn create_game(
+        &mut self,
+        bet_amount: u64,
+        commitment: [u8; 32],
+        ranked: bool,
+        reimbursement: u64,
+        bumps: &CreateGameBumps,
+    ) -> Result<()> {
+        require!(
+            (MIN_BET..=MAX_BET).contains(&bet_amount),
+            GameError::InvalidBetAmount
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let game = &mut self.game;
+        game.creator = self.creator.key();
+        game.game_id = self.creator_profile.take_game_id();
+        game.bump = bumps.game;
+        game.bet_amount = bet_amount;
+        game.ranked = ranked;
+        game.lobby_page = self.lobby.index;
+        game.creator_move = PlayerMove::committed(commitment);
+        // Initial status; every later change goes through `Game::transition`
+        game.status = GameStatus::WaitingForOpponent;
+        game.created_at = now;
+        game.last_action_at = now;
+        game.creator_sponsor = Sponsor::new(
+            &game.creator,
+            &self.payer.key(),
+            reimbursement,
+            bet_amount,
+        )?;
+
+        self.lobby.register(LobbyEntry {
+            game: game.key(),
+            creator: game.creator,
+            bet_amount,
+            created_at: now,
+        })?;
+
+        // Transfer bet amount from creator to game account
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.creator.to_account_info(),
+                    to: self.game_account.to_account_info(),
+                },
+            ),
+            bet_amount,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Stake a bet against the house, which must commit its move before the
+/// player picks theirs.
+#[derive(Accounts)]
+pub struct CreateHouseGame<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        seeds = [HOUSE_SEED],
+        bump = house.bump
+    )]
+    pub house: Account<'info, House>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + HouseGame::LEN,
+        seeds = [
+            HOUSE_SEED,
+            player.key().as_ref(),
+            &player_profile.next_game_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub house_game: Account<'info, HouseGame>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, house_game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the player's stake
+    pub game_account: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [BANKROLL_SEED],
+        bump = bankroll.bump
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateHouseGame<'info> {
+    pub fn create_house_game(
+        &mut self,
+        bet_amount: u64,
+        bumps: &CreateHouseGameBumps,
+    ) -> Result<()> {
+        require!(
+            (MIN_BET..=MAX_BET).contains(&bet_amount),
+            GameError::InvalidBetAmount
+        );
+        Bankroll::reserve(&mut self.bankroll, bet_amount)?;
+        // Every open game could end in a slash for its full bet
+        require!(
+            self.bankroll.open_exposure <= self.house.bond,
+            GameError::BondExhausted
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.house_game.set_inner(HouseGame {
+            player: self.player.key(),
+            game_id: self.player_profile.take_game_id(),
+            bump: bumps.house_game,
+            bet_amount,
+            status: HouseGameStatus::WaitingForHouse,
+            player_move: PlayerMove::default(),
+            house_move: PlayerMove::default(),
+            outcome: None,
+            created_at: now,
+            last_action_at: now,
+            house_edge_bps: self.bankroll.house_edge_bps,
+        });
+
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.player.to_account_info(),
+                    to: self.game_account.to_account_info(),
+                },
+            ),
+            bet_amount,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateLeague<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + League::LEN,
+        seeds = [LEAGUE_SEED, organizer.key().as_ref(), &id.to_le_bytes()],
+        bump
+    )]
+    pub league: Account<'info, League>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateLeague<'info> {
+    pub fn create_league(
+        &mut self,
+        id: u64,
+        entry_fee: u64,
+        roster: Vec<Pubkey>,
+        season_ends_at: i64,
+        bumps: &CreateLeagueBumps,
+    ) -> Result<()> {
+        require!(entry_fee >= MIN_BET, GameError::InvalidBetAmount);
+        require!(
+            (2..=League::MAX_PLAYERS).contains(&roster.len()),
+            GameError::InvalidLeagueConfig
+        );
+        for (index, player) in roster.iter().enumerate() {
+            require!(
+                !roster[..index].contains(player),
+                GameError::InvalidLeagueConfig
+            );
+        }
+        require!(
+            season_ends_at > Clock::get()?.unix_timestamp,
+            GameError::InvalidLeagueConfig
+        );
+
+        self.league.set_inner(League {
+            organizer: self.organizer.key(),
+            id,
+            bump: bumps.league,
+            entry_fee,
+            season_ends_at,
+            status: LeagueStatus::Registration,
+            standings: roster
+                .into_iter()
+                .map(|player| Standing {
+                    player,
+                    ..Standing::default()
+                })
+                .collect(),
+            fixtures: Vec::new(),
+            pot: 0,
+            prizes_claimed: 0,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(ranked: bool, index: u32)]
+pub struct CreateLobbyPage<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + LobbyPage::LEN,
+        seeds = [LOBBY_SEED, &[u8::from(ranked)], &index.to_le_bytes()],
+        bump
+    )]
+    pub lobby: Account<'info, LobbyPage>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateLobbyPage<'info> {
+    pub fn create_lobby_page(
+        &mut self,
+        ranked: bool,
+        index: u32,
+        bumps: &CreateLobbyPageBumps,
+    ) -> Result<()> {
+        self.lobby.set_inner(LobbyPage {
+            ranked,
+            index,
+            bump: bumps.lobby,
+            games: Vec::new(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CreateLpPosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + LpPosition::LEN,
+        seeds = [LP_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, LpPosition>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateLpPosition<'info> {
+    pub fn create_lp_position(&mut self, bumps: &CreateLpPositionBumps) -> Result<()> {
+        self.position.set_inner(LpPosition {
+            owner: self.owner.key(),
+            shares: 0,
+            pending_shares: 0,
+            requested_at: 0,
+            bump: bumps.position,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct CreateMatchQueue<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MatchQueue::LEN,
+        seeds = [QUEUE_SEED, &[tier]],
+        bump
+    )]
+    pub queue: Account<'info, MatchQueue>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateMatchQueue<'info> {
+    pub fn create_match_queue(&mut self, tier: u8, bumps: &CreateMatchQueueBumps) -> Result<()> {
+        let bet_amount = *BET_TIERS
+            .get(usize::from(tier))
+            .ok_or(GameError::InvalidBetAmount)?;
+
+        self.queue.set_inner(MatchQueue {
+            tier,
+            bet_amount,
+            bump: bumps.queue,
+            entries: Vec::new(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CreateProfile<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PlayerProfile::LEN,
+        seeds = [PROFILE_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, PlayerProfile>,
+    
+    pub player: Signer<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateProfile<'info> {
+    pub fn create_profile(&mut self, bumps: &CreateProfileBumps) -> Result<()> {
+        self.profile.set_inner(PlayerProfile::new(self.player.key(), bumps.profile));
+        Ok(())
+    }
+}
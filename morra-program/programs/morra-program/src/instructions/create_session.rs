@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct CreateSession<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + SessionKey::LEN,
+        seeds = [SESSION_SEED, player.key().as_ref(), session_key.as_ref()],
+        bump
+    )]
+    pub session: Account<'info, SessionKey>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSession<'info> {
+    pub fn create_session(
+        &mut self,
+        session_key: Pubkey,
+        scope: Pubkey,
+        expires_at: i64,
+        bumps: &CreateSessionBumps,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            expires_at > now && expires_at - now <= MAX_SESSION_DURATION,
+            GameError::InvalidSession
+        );
+        require_keys_neq!(session_key, self.player.key(), GameError::InvalidSession);
+
+        self.session.set_inner(SessionKey {
+            player: self.player.key(),
+            session_key,
+            scope,
+            expires_at,
+            bump: bumps.session,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: open side betting on a game that hasn't got under way.
+#[derive(Accounts)]
+pub struct CreateSideBetPool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SideBetPool::LEN,
+        seeds = [SIDE_BET_SEED, game.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, SideBetPool>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSideBetPool<'info> {
+    pub fn create_side_bet_pool(&mut self, bumps: &CreateSideBetPoolBumps) -> Result<()> {
+        require!(
+            SideBetPool::betting_open(&self.game),
+            GameError::BettingClosed
+        );
+        self.pool.set_inner(SideBetPool {
+            game: self.game.key(),
+            bump: bumps.pool,
+            totals: [0; 3],
+            status: SideBetPoolStatus::Open,
+            winning_side: None,
+            claimed_stake: 0,
+            paid_out: 0,
+        });
+        Ok(())
+    }
+}
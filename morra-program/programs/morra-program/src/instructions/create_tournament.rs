@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateTournament<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + Tournament::LEN,
+        seeds = [TOURNAMENT_SEED, organizer.key().as_ref(), &id.to_le_bytes()],
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateTournament<'info> {
+    pub fn create_tournament(
+        &mut self,
+        id: u64,
+        entry_fee: u64,
+        max_players: u8,
+        registration_ends_at: i64,
+        bumps: &CreateTournamentBumps,
+    ) -> Result<()> {
+        require!(entry_fee >= MIN_BET, GameError::InvalidBetAmount);
+        require!(
+            usize::from(max_players) <= Tournament::MAX_PLAYERS
+                && morra_core::is_valid_bracket_size(usize::from(max_players)),
+            GameError::InvalidTournamentConfig
+        );
+        require!(
+            registration_ends_at > Clock::get()?.unix_timestamp,
+            GameError::InvalidTournamentConfig
+        );
+
+        self.tournament.set_inner(Tournament {
+            organizer: self.organizer.key(),
+            id,
+            bump: bumps.tournament,
+            entry_fee,
+            max_players,
+            registration_ends_at,
+            status: TournamentStatus::Registration,
+            players: Vec::new(),
+            matches: Vec::new(),
+            prize_pool: 0,
+            prizes_claimed: 0,
+        });
+        Ok(())
+    }
+}
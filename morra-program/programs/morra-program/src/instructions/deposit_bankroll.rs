@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Add lamports to the bankroll for shares at the pool's current value.
+#[derive(Accounts)]
+pub struct DepositBankroll<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LP_SEED, owner.key().as_ref()],
+        bump = position.bump,
+        has_one = owner
+    )]
+    pub position: Account<'info, LpPosition>,
+    
+    #[account(
+        mut,
+        seeds = [BANKROLL_SEED],
+        bump = bankroll.bump
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositBankroll<'info> {
+    pub fn deposit_bankroll(&mut self, amount: u64) -> Result<()> {
+        let assets = Bankroll::assets(&self.bankroll)?;
+        let total_shares = self.bankroll.total_shares;
+        let shares = morra_core::shares_for_deposit(amount, total_shares, assets)
+            .filter(|&shares| shares > 0)
+            .ok_or(GameError::InvalidShareAmount)?;
+
+        self.bankroll.total_shares = total_shares
+            .checked_add(shares)
+            .ok_or(GameError::InvalidShareAmount)?;
+        self.position.shares += shares;
+
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.owner.to_account_info(),
+                    to: self.bankroll.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct Enqueue<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    /// Required so the paired game can record its result.
+    #[account(
+        seeds = [PROFILE_SEED, player.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [QUEUE_SEED, &[queue.tier]],
+        bump = queue.bump
+    )]
+    pub queue: Account<'info, MatchQueue>,
+    
+    #[account(
+        mut,
+        seeds = [QUEUE_SEED, queue.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds queued stakes
+    pub queue_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Enqueue<'info> {
+    pub fn enqueue(&mut self, commitment: [u8; 32], ranked: bool) -> Result<()> {
+        self.queue.enqueue(QueueEntry {
+            player: self.player.key(),
+            commitment,
+            ranked,
+            enqueued_at: Clock::get()?.unix_timestamp,
+        })?;
+
+        // Transfer the tier's stake from the player to the queue vault
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.player.to_account_info(),
+                    to: self.queue_vault.to_account_info(),
+                },
+            ),
+            self.queue.bet_amount,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: cancel a game nobody joined within `JOIN_TIMEOUT`,
+/// returning the creator's bet.
+#[derive(Accounts)]
+pub struct ExpireGame<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    #[account(mut, address = game.creator)]
+    /// CHECK: Receives the creator's bet back
+    pub creator: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LOBBY_SEED, &[u8::from(game.ranked)], &game.lobby_page.to_le_bytes()],
+        bump = lobby.bump
+    )]
+    pub lobby: Account<'info, LobbyPage>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the game funds
+    pub game_account: AccountInfo<'info>,
+    
+    /// Pays the crank reward, when passed.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Option<Account<'info, Config>>,
+    
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExpireGame<'info> {
+    pub fn expire_game(&mut self, bumps: &ExpireGameBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let game = &mut self.game;
+        let timed_out = game.timed_out(now);
+        game.transition(GameStatus::WaitingForOpponent, GameStatus::Cancelled)?;
+        require!(timed_out, GameError::TimeoutNotReached);
+        game.last_action_at = now;
+        self.lobby.remove(&game.key())?;
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.game_account.to_account_info(),
+                    to: self.creator.to_account_info(),
+                },
+                &[&[
+                    GAME_SEED,
+                    &game.key().to_bytes(),
+                    &[bumps.game_account],
+                ]],
+            ),
+            game.bet_amount,
+        )?;
+
+        if let Some(config) = &self.config {
+            Config::pay_crank_reward(config, &self.cranker.to_account_info())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: end a game stuck waiting on a move for `MOVE_TIMEOUT`.
+/// The player who moved wins by forfeit and claims as usual; if neither
+/// did, both bets are refunded.
+#[derive(Accounts)]
+pub struct ForfeitGame<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    #[account(mut, address = game.creator)]
+    /// CHECK: Receives the creator's bet back if neither player moved
+    pub creator: AccountInfo<'info>,
+    
+    #[account(mut, address = game.opponent)]
+    /// CHECK: Receives the opponent's bet back if neither player moved
+    pub opponent: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, game.creator.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, game.opponent.as_ref()],
+        bump = opponent_profile.bump
+    )]
+    pub opponent_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the game funds
+    pub game_account: AccountInfo<'info>,
+    
+    /// Pays the crank reward, when passed.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Option<Account<'info, Config>>,
+    
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ForfeitGame<'info> {
+    pub fn forfeit_game(&mut self, bumps: &ForfeitGameBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let game = &mut self.game;
+        let timed_out = game.timed_out(now);
+        game.forfeit()?;
+        require!(timed_out, GameError::TimeoutNotReached);
+        game.last_action_at = now;
+
+        if let Some(outcome) = game.outcome() {
+            // The winner claims the pot as usual; the staller takes a forfeit.
+            let (winner, staller) = if game.winner == Some(game.creator) {
+                (&mut self.creator_profile, &mut self.opponent_profile)
+            } else {
+                (&mut self.opponent_profile, &mut self.creator_profile)
+            };
+            winner.record_win(game.bet_amount);
+            staller.record_forfeit(game.bet_amount);
+            if game.ranked {
+                PlayerProfile::update_ratings(
+                    &mut self.creator_profile,
+                    &mut self.opponent_profile,
+                    outcome,
+                );
+            }
+        } else if game.bet_amount > 0 {
+            // Return each player's bet from the game account
+            let game_key = game.key();
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                GAME_SEED,
+                game_key.as_ref(),
+                &[bumps.game_account],
+            ]];
+            for player in [&self.creator, &self.opponent] {
+                system_program::transfer(
+                    CpiContext::new_with_signer(
+                        self.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: self.game_account.to_account_info(),
+                            to: player.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    game.bet_amount,
+                )?;
+            }
+        }
+
+        if let Some(config) = &self.config {
+            Config::pay_crank_reward(config, &self.cranker.to_account_info())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: end a house game stuck for `MOVE_TIMEOUT`. A house that
+/// never committed cancels the game and the player is refunded; a player
+/// who never moved loses their stake; a house that never revealed loses
+/// the game and the player's winnings are slashed from its bond, without
+/// the house edge, so the bankroll's providers don't pay for the
+/// operator's stall. A cancelled game isn't recorded on the player's
+/// profile; a player who never moved is charged a forfeit.
+#[derive(Accounts)]
+pub struct ForfeitHouseGame<'info> {
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, house_game.player.as_ref(), &house_game.game_id.to_le_bytes()],
+        bump = house_game.bump
+    )]
+    pub house_game: Account<'info, HouseGame>,
+    
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED],
+        bump = house.bump
+    )]
+    pub house: Account<'info, House>,
+    
+    #[account(mut, address = house_game.player)]
+    /// CHECK: Receives the player's refund or winnings
+    pub player: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, house_game.player.as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, house_game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the player's stake
+    pub game_account: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [BANKROLL_SEED],
+        bump = bankroll.bump
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+    
+    pub cranker: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ForfeitHouseGame<'info> {
+    pub fn forfeit_house_game(&mut self, bumps: &ForfeitHouseGameBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let game = &mut self.house_game;
+        require!(game.timed_out(now), GameError::TimeoutNotReached);
+
+        // A refund settles like a draw: the stake goes back to the player
+        let from = game.status;
+        let (status, outcome) = match from {
+            HouseGameStatus::WaitingForHouse => (HouseGameStatus::Cancelled, HouseOutcome::Draw),
+            HouseGameStatus::WaitingForPlayer => (HouseGameStatus::Settled, HouseOutcome::HouseWins),
+            _ => (HouseGameStatus::Settled, HouseOutcome::PlayerWins),
+        };
+        let withheld = from == HouseGameStatus::WaitingForReveal;
+        game.transition(from, status)?;
+        game.last_action_at = now;
+        if status == HouseGameStatus::Settled {
+            game.outcome = Some(outcome);
+        }
+        match from {
+            HouseGameStatus::WaitingForHouse => {}
+            HouseGameStatus::WaitingForPlayer => self.player_profile.record_forfeit(game.bet_amount),
+            _ => self.player_profile.record_house_result(outcome, game.bet_amount),
+        }
+        // A withheld reveal settles like a draw against the bankroll: the
+        // stake goes back and the bond pays the win in full
+        let settled_as = if withheld { HouseOutcome::Draw } else { outcome };
+
+        HouseSettlement::new(settled_as, game.bet_amount, game.house_edge_bps).pay(
+            &mut self.bankroll,
+            &self.house_game,
+            &self.game_account,
+            bumps.game_account,
+            &self.player,
+            &self.system_program.to_account_info(),
+        )?;
+        if withheld {
+            let bet_amount = self.house_game.bet_amount;
+            House::slash(&mut self.house, &self.player, bet_amount)?;
+        }
+        Ok(())
+    }
+}
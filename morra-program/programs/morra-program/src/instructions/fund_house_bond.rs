@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Top up the house bond from its authority.
+#[derive(Accounts)]
+pub struct FundHouseBond<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED],
+        bump = house.bump,
+        has_one = authority @ GameError::Unauthorized
+    )]
+    pub house: Account<'info, House>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundHouseBond<'info> {
+    pub fn fund_house_bond(&mut self, amount: u64) -> Result<()> {
+        self.house.bond += amount;
+
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.authority.to_account_info(),
+                    to: self.house.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
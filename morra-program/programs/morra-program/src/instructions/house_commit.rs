@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct HouseCommit<'info> {
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, house_game.player.as_ref(), &house_game.game_id.to_le_bytes()],
+        bump = house_game.bump
+    )]
+    pub house_game: Account<'info, HouseGame>,
+    
+    #[account(
+        seeds = [HOUSE_SEED],
+        bump = house.bump,
+        has_one = operator @ GameError::NotHouseOperator
+    )]
+    pub house: Account<'info, House>,
+    
+    pub operator: Signer<'info>,
+}
+
+impl<'info> HouseCommit<'info> {
+    pub fn house_commit(&mut self, commitment: [u8; 32]) -> Result<()> {
+        let game = &mut self.house_game;
+        game.transition(
+            HouseGameStatus::WaitingForHouse,
+            HouseGameStatus::WaitingForPlayer,
+        )?;
+        game.house_move = PlayerMove::committed(commitment);
+        game.last_action_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
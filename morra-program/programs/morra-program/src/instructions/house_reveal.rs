@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use morra_core::Move;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Open the house's commitment, resolve the game and settle it against
+/// the bankroll in one step. The result goes on the player's profile like
+/// any other game's.
+#[derive(Accounts)]
+pub struct HouseReveal<'info> {
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, house_game.player.as_ref(), &house_game.game_id.to_le_bytes()],
+        bump = house_game.bump
+    )]
+    pub house_game: Account<'info, HouseGame>,
+    
+    #[account(
+        seeds = [HOUSE_SEED],
+        bump = house.bump,
+        has_one = operator @ GameError::NotHouseOperator
+    )]
+    pub house: Account<'info, House>,
+    
+    pub operator: Signer<'info>,
+    
+    #[account(mut, address = house_game.player)]
+    /// CHECK: Receives the player's winnings or refund
+    pub player: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, house_game.player.as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, house_game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the player's stake
+    pub game_account: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [BANKROLL_SEED],
+        bump = bankroll.bump
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> HouseReveal<'info> {
+    pub fn house_reveal(
+        &mut self,
+        card: u8,
+        prediction: u8,
+        salt: [u8; 32],
+        bumps: &HouseRevealBumps,
+    ) -> Result<()> {
+        // An illegal committed move can't be revealed, so the house forfeits
+        require!(morra_core::is_valid_card(card), GameError::InvalidCard);
+        require!(
+            morra_core::is_valid_prediction(prediction),
+            GameError::InvalidPrediction
+        );
+
+        let game = &mut self.house_game;
+        game.transition(
+            HouseGameStatus::WaitingForReveal,
+            HouseGameStatus::Settled,
+        )?;
+        let house_move = Move::new(card, prediction);
+        // The house commits as the house account, not as its operator key
+        let preimage = morra_core::commitment_preimage(
+            &self.house.key().to_bytes(),
+            &game.key().to_bytes(),
+            house_move,
+            &salt,
+        );
+        require!(
+            game.house_move.committed_hash == Some(hash(&preimage).to_bytes()),
+            GameError::InvalidCommitment
+        );
+        let player_move = game
+            .player_move
+            .revealed()
+            .ok_or(GameError::InvalidGameState)?;
+
+        game.house_move.card = Some(card);
+        game.house_move.prediction = Some(prediction);
+        game.last_action_at = Clock::get()?.unix_timestamp;
+        let outcome = HouseOutcome::from(morra_core::resolve(player_move, house_move));
+        game.outcome = Some(outcome);
+        let house_edge_bps = game.house_edge_bps;
+        self.player_profile.record_house_result(outcome, game.bet_amount);
+
+        HouseSettlement::new(outcome, game.bet_amount, house_edge_bps).pay(
+            &mut self.bankroll,
+            &self.house_game,
+            &self.game_account,
+            bumps.game_account,
+            &self.player,
+            &self.system_program.to_account_info(),
+        )
+    }
+}
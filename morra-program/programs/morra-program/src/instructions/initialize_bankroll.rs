@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Create the empty bankroll under the config authority, with its per-game
+/// exposure limit and house edge.
+#[derive(Accounts)]
+pub struct InitializeBankroll<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority @ GameError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Bankroll::LEN,
+        seeds = [BANKROLL_SEED],
+        bump
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeBankroll<'info> {
+    pub fn initialize_bankroll(
+        &mut self,
+        max_exposure_bps: u16,
+        house_edge_bps: u16,
+        bumps: &InitializeBankrollBumps,
+    ) -> Result<()> {
+        self.bankroll.set_inner(Bankroll {
+            authority: self.authority.key(),
+            total_shares: 0,
+            open_exposure: 0,
+            max_exposure_bps: 0,
+            house_edge_bps: 0,
+            bump: bumps.bankroll,
+        });
+        self.bankroll.set_rates(max_exposure_bps, house_edge_bps)
+    }
+}
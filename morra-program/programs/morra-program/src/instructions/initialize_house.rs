@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Set up the house with its operator key, posting `bond` from the config
+/// authority.
+#[derive(Accounts)]
+pub struct InitializeHouse<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority @ GameError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + House::LEN,
+        seeds = [HOUSE_SEED],
+        bump
+    )]
+    pub house: Account<'info, House>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeHouse<'info> {
+    pub fn initialize_house(
+        &mut self,
+        operator: Pubkey,
+        bond: u64,
+        bumps: &InitializeHouseBumps,
+    ) -> Result<()> {
+        self.house.set_inner(House {
+            authority: self.authority.key(),
+            operator,
+            bond,
+            bump: bumps.house,
+        });
+
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.authority.to_account_info(),
+                    to: self.house.to_account_info(),
+                },
+            ),
+            bond,
+        )?;
+        Ok(())
+    }
+}
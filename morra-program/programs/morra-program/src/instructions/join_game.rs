@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct JoinGame<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    #[account(mut)]
+    pub opponent: Signer<'info>,
+    
+    /// Whoever covers the transaction; only recorded when the opponent
+    /// agrees to reimburse them.
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [PROFILE_SEED, opponent.key().as_ref()],
+        bump = opponent_profile.bump
+    )]
+    pub opponent_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [LOBBY_SEED, &[u8::from(game.ranked)], &game.lobby_page.to_le_bytes()],
+        bump = lobby.bump
+    )]
+    pub lobby: Account<'info, LobbyPage>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the game funds
+    pub game_account: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(
+        seeds = [SIDE_BET_SEED, game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: The game's side-bet pool, which may not exist yet
+    pub side_bet_pool: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [SIDE_BET_SEED, side_bet_pool.key().as_ref(), opponent.key().as_ref()],
+        bump
+    )]
+    /// CHECK: The opponent's side bet on the game, which must not exist
+    pub opponent_side_bet: AccountInfo<'info>,
+}
+
+impl<'info> JoinGame<'info> {
+    pub fn join_game(&mut self, commitment: [u8; 32], reimbursement: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let game = &mut self.game;
+        require!(!game.timed_out(now), GameError::GameTimedOut);
+        game.transition(GameStatus::WaitingForOpponent, GameStatus::WaitingForReveal)?;
+        require!(
+            game.creator != self.opponent.key(),
+            GameError::CannotJoinOwnGame
+        );
+        // Betting is open until the game starts, so a spectator could back
+        // a side and then take the other seat to throw it
+        require!(
+            self.opponent_side_bet.data_is_empty(),
+            GameError::BettorCannotJoin
+        );
+        self.lobby.remove(&game.key())?;
+
+        // Transfer bet amount from opponent to game account
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.opponent.to_account_info(),
+                    to: self.game_account.to_account_info(),
+                },
+            ),
+            game.bet_amount,
+        )?;
+
+        game.opponent = self.opponent.key();
+        game.opponent_move = PlayerMove::committed(commitment);
+        game.opponent_sponsor = Sponsor::new(
+            &game.opponent,
+            &self.payer.key(),
+            reimbursement,
+            game.bet_amount,
+        )?;
+        game.last_action_at = now;
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Pay a roster member's entry fee. The last member to join starts the
+/// season.
+#[derive(Accounts)]
+pub struct JoinLeague<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    /// Fixture games are opened from the players' profiles.
+    #[account(
+        seeds = [PROFILE_SEED, player.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, league.organizer.as_ref(), &league.id.to_le_bytes()],
+        bump = league.bump
+    )]
+    pub league: Account<'info, League>,
+    
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, league.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the pot
+    pub league_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> JoinLeague<'info> {
+    pub fn join_league(&mut self) -> Result<()> {
+        let league = &mut self.league;
+        require!(
+            league.status == LeagueStatus::Registration,
+            GameError::InvalidLeagueState
+        );
+        let index = league.roster_index(&self.player.key())?;
+        require!(!league.standings[index].joined, GameError::AlreadyRegistered);
+        league.standings[index].joined = true;
+        league.pot += league.entry_fee;
+        if league.all_joined() {
+            league.start();
+        }
+
+        // Transfer the entry fee into the league vault
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.player.to_account_info(),
+                    to: self.league_vault.to_account_info(),
+                },
+            ),
+            league.entry_fee,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Take back an entry fee while the rest of the roster has yet to join.
+#[derive(Accounts)]
+pub struct LeaveLeague<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, league.organizer.as_ref(), &league.id.to_le_bytes()],
+        bump = league.bump
+    )]
+    pub league: Account<'info, League>,
+    
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, league.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the pot
+    pub league_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LeaveLeague<'info> {
+    pub fn leave_league(&mut self, bumps: &LeaveLeagueBumps) -> Result<()> {
+        let league = &mut self.league;
+        require!(
+            league.status == LeagueStatus::Registration,
+            GameError::InvalidLeagueState
+        );
+        let index = league.roster_index(&self.player.key())?;
+        require!(league.standings[index].joined, GameError::NotRegistered);
+        league.standings[index].joined = false;
+        league.pot -= league.entry_fee;
+
+        // Refund the entry fee from the league vault
+        let league_key = league.key();
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.league_vault.to_account_info(),
+                    to: self.player.to_account_info(),
+                },
+                &[&[LEAGUE_SEED, league_key.as_ref(), &[bumps.league_vault]]],
+            ),
+            league.entry_fee,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct LeaveQueue<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [QUEUE_SEED, &[queue.tier]],
+        bump = queue.bump
+    )]
+    pub queue: Account<'info, MatchQueue>,
+    
+    #[account(
+        mut,
+        seeds = [QUEUE_SEED, queue.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds queued stakes
+    pub queue_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LeaveQueue<'info> {
+    pub fn leave_queue(&mut self, bumps: &LeaveQueueBumps) -> Result<()> {
+        self.queue.remove(&self.player.key())?;
+
+        // Return the player's stake from the queue vault
+        let queue_key = self.queue.key();
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.queue_vault.to_account_info(),
+                    to: self.player.to_account_info(),
+                },
+                &[&[QUEUE_SEED, queue_key.as_ref(), &[bumps.queue_vault]]],
+            ),
+            self.queue.bet_amount,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Withdraw before the bracket is drawn, for a refund of the entry fee. This
+/// stays open past the deadline so a tournament that never fills can be
+/// abandoned.
+#[derive(Accounts)]
+pub struct LeaveTournament<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            TOURNAMENT_SEED,
+            tournament.organizer.as_ref(),
+            &tournament.id.to_le_bytes()
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, tournament.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the prize pool
+    pub prize_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LeaveTournament<'info> {
+    pub fn leave_tournament(&mut self, bumps: &LeaveTournamentBumps) -> Result<()> {
+        let tournament = &mut self.tournament;
+        require!(
+            tournament.status == TournamentStatus::Registration,
+            GameError::InvalidTournamentState
+        );
+        tournament.unregister(&self.player.key())?;
+        tournament.prize_pool -= tournament.entry_fee;
+
+        // Refund the entry fee from the prize vault
+        let tournament_key = tournament.key();
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.prize_vault.to_account_info(),
+                    to: self.player.to_account_info(),
+                },
+                &[&[TOURNAMENT_SEED, tournament_key.as_ref(), &[bumps.prize_vault]]],
+            ),
+            tournament.entry_fee,
+        )?;
+        Ok(())
+    }
+}
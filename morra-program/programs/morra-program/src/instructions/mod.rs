@@ -0,0 +1,103 @@
+pub mod advance_tournament;
+pub mod cancel_game;
+pub mod claim_league_prize;
+pub mod claim_side_bet;
+pub mod claim_tournament_prize;
+pub mod claim_winnings;
+pub mod commit_move;
+pub mod create_game;
+pub mod create_house_game;
+pub mod create_league;
+pub mod create_lobby_page;
+pub mod create_lp_position;
+pub mod create_match_queue;
+pub mod create_profile;
+pub mod create_session;
+pub mod create_side_bet_pool;
+pub mod create_tournament;
+pub mod deposit_bankroll;
+pub mod enqueue;
+pub mod expire_game;
+pub mod forfeit_game;
+pub mod forfeit_house_game;
+pub mod fund_house_bond;
+pub mod house_commit;
+pub mod house_reveal;
+pub mod initialize_bankroll;
+pub mod initialize_config;
+pub mod initialize_house;
+pub mod join_game;
+pub mod join_league;
+pub mod leave_league;
+pub mod leave_queue;
+pub mod leave_tournament;
+pub mod pair_match;
+pub mod place_side_bet;
+pub mod play_house_game;
+pub mod record_fixture;
+pub mod refund_draw;
+pub mod register_tournament;
+pub mod request_withdrawal;
+pub mod reveal_move;
+pub mod revoke_session;
+pub mod set_house_operator;
+pub mod settle_league;
+pub mod spawn_fixture_game;
+pub mod spawn_match_game;
+pub mod start_tournament;
+pub mod update_bankroll;
+pub mod update_config;
+pub mod withdraw_bankroll;
+pub mod withdraw_house_bond;
+
+pub use advance_tournament::*;
+pub use cancel_game::*;
+pub use claim_league_prize::*;
+pub use claim_side_bet::*;
+pub use claim_tournament_prize::*;
+pub use claim_winnings::*;
+pub use commit_move::*;
+pub use create_game::*;
+pub use create_house_game::*;
+pub use create_league::*;
+pub use create_lobby_page::*;
+pub use create_lp_position::*;
+pub use create_match_queue::*;
+pub use create_profile::*;
+pub use create_session::*;
+pub use create_side_bet_pool::*;
+pub use create_tournament::*;
+pub use deposit_bankroll::*;
+pub use enqueue::*;
+pub use expire_game::*;
+pub use forfeit_game::*;
+pub use forfeit_house_game::*;
+pub use fund_house_bond::*;
+pub use house_commit::*;
+pub use house_reveal::*;
+pub use initialize_bankroll::*;
+pub use initialize_config::*;
+pub use initialize_house::*;
+pub use join_game::*;
+pub use join_league::*;
+pub use leave_league::*;
+pub use leave_queue::*;
+pub use leave_tournament::*;
+pub use pair_match::*;
+pub use place_side_bet::*;
+pub use play_house_game::*;
+pub use record_fixture::*;
+pub use refund_draw::*;
+pub use register_tournament::*;
+pub use request_withdrawal::*;
+pub use reveal_move::*;
+pub use revoke_session::*;
+pub use set_house_operator::*;
+pub use settle_league::*;
+pub use spawn_fixture_game::*;
+pub use spawn_match_game::*;
+pub use start_tournament::*;
+pub use update_bankroll::*;
+pub use update_config::*;
+pub use withdraw_bankroll::*;
+pub use withdraw_house_bond::*;
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: a crank, or the second enqueuer in the same transaction,
+/// pairs the two oldest compatible entries. The caller passes the profiles
+/// of the pair it expects, oldest entry first, and pays the game's rent.
+#[derive(Accounts)]
+pub struct PairMatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [QUEUE_SEED, &[queue.tier]],
+        bump = queue.bump
+    )]
+    pub queue: Account<'info, MatchQueue>,
+    
+    #[account(
+        mut,
+        seeds = [QUEUE_SEED, queue.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds queued stakes
+    pub queue_vault: AccountInfo<'info>,
+    
+    /// The older entry, who takes the creator seat.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, creator_profile.player.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        seeds = [PROFILE_SEED, opponent_profile.player.as_ref()],
+        bump = opponent_profile.bump
+    )]
+    pub opponent_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Game::LEN,
+        seeds = [
+            GAME_SEED,
+            creator_profile.player.as_ref(),
+            &creator_profile.next_game_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the game funds
+    pub game_account: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PairMatch<'info> {
+    pub fn pair_match(&mut self, bumps: &PairMatchBumps) -> Result<()> {
+        let (creator, opponent) = self.queue.next_pair().ok_or(GameError::NoMatch)?;
+        require_keys_eq!(
+            self.creator_profile.player,
+            creator.player,
+            GameError::InvalidProfile
+        );
+        require_keys_eq!(
+            self.opponent_profile.player,
+            opponent.player,
+            GameError::InvalidProfile
+        );
+        self.queue.remove(&creator.player)?;
+        self.queue.remove(&opponent.player)?;
+
+        // Both players have already committed, so the game skips straight to
+        // the reveal phase. This is its initial status, not a transition.
+        let now = Clock::get()?.unix_timestamp;
+        let bet_amount = self.queue.bet_amount;
+        let queue_key = self.queue.key();
+        self.game.set_inner(Game {
+            creator: creator.player,
+            game_id: self.creator_profile.take_game_id(),
+            bump: bumps.game,
+            opponent: opponent.player,
+            bet_amount,
+            ranked: creator.ranked,
+            lobby_page: 0,
+            status: GameStatus::WaitingForReveal,
+            creator_move: PlayerMove::committed(creator.commitment),
+            opponent_move: PlayerMove::committed(opponent.commitment),
+            winner: None,
+            created_at: now,
+            last_action_at: now,
+            creator_sponsor: None,
+            opponent_sponsor: None,
+            queue: Some(queue_key),
+        });
+
+        // Move both stakes from the queue vault into the game's escrow
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.queue_vault.to_account_info(),
+                    to: self.game_account.to_account_info(),
+                },
+                &[&[QUEUE_SEED, queue_key.as_ref(), &[bumps.queue_vault]]],
+            ),
+            bet_amount * 2,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct PlaceSideBet<'info> {
+    #[account(mut)]
+    pub bettor: Signer<'info>,
+    
+    #[account(
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    #[account(
+        mut,
+        seeds = [SIDE_BET_SEED, game.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, SideBetPool>,
+    
+    #[account(
+        init,
+        payer = bettor,
+        space = 8 + SideBet::LEN,
+        seeds = [SIDE_BET_SEED, pool.key().as_ref(), bettor.key().as_ref()],
+        bump
+    )]
+    pub bet: Account<'info, SideBet>,
+    
+    #[account(
+        mut,
+        seeds = [SIDE_BET_SEED, pool.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds side-bet stakes
+    pub pool_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PlaceSideBet<'info> {
+    pub fn place_side_bet(
+        &mut self,
+        side: BetSide,
+        amount: u64,
+        bumps: &PlaceSideBetBumps,
+    ) -> Result<()> {
+        require!(amount >= MIN_SIDE_BET, GameError::InvalidBetAmount);
+        let game = &self.game;
+        require!(SideBetPool::betting_open(game), GameError::BettingClosed);
+        let bettor = self.bettor.key();
+        require!(
+            bettor != game.creator && bettor != game.opponent,
+            GameError::PlayerCannotBet
+        );
+
+        self.pool.totals[side as usize] += amount;
+        self.bet.set_inner(SideBet {
+            pool: self.pool.key(),
+            bettor,
+            side,
+            amount,
+            bump: bumps.bet,
+        });
+
+        // Transfer the stake into the pool vault
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.bettor.to_account_info(),
+                    to: self.pool_vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
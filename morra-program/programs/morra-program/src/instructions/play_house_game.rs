@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// The player's move, made in the open: the house is already bound by its
+/// commitment.
+#[derive(Accounts)]
+pub struct PlayHouseGame<'info> {
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, player.key().as_ref(), &house_game.game_id.to_le_bytes()],
+        bump = house_game.bump
+    )]
+    pub house_game: Account<'info, HouseGame>,
+    
+    pub player: Signer<'info>,
+}
+
+impl<'info> PlayHouseGame<'info> {
+    pub fn play_house_game(&mut self, card: u8, prediction: u8) -> Result<()> {
+        require!(morra_core::is_valid_card(card), GameError::InvalidCard);
+        require!(
+            morra_core::is_valid_prediction(prediction),
+            GameError::InvalidPrediction
+        );
+
+        let game = &mut self.house_game;
+        game.transition(
+            HouseGameStatus::WaitingForPlayer,
+            HouseGameStatus::WaitingForReveal,
+        )?;
+        game.player_move.card = Some(card);
+        game.player_move.prediction = Some(prediction);
+        game.last_action_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
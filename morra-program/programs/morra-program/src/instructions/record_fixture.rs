@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: score a fixture from its resolved game. A game
+/// cancelled because neither player moved before the timeout scores
+/// nothing and frees the fixture to be played again.
+#[derive(Accounts)]
+pub struct RecordFixture<'info> {
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, league.organizer.as_ref(), &league.id.to_le_bytes()],
+        bump = league.bump
+    )]
+    pub league: Account<'info, League>,
+    
+    #[account(
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+}
+
+impl<'info> RecordFixture<'info> {
+    pub fn record_fixture(&mut self, fixture_index: u8) -> Result<()> {
+        let league = &mut self.league;
+        require!(
+            league.status == LeagueStatus::InProgress,
+            GameError::InvalidLeagueState
+        );
+        let index = usize::from(fixture_index);
+        let fixture = league.fixtures.get(index).ok_or(GameError::InvalidMatch)?;
+        require!(
+            fixture.game == Some(self.game.key()),
+            GameError::InvalidMatch
+        );
+        let Some(outcome) = self.game.outcome() else {
+            require!(
+                self.game.status == GameStatus::Cancelled,
+                GameError::InvalidGameState
+            );
+            league.fixtures[index].game = None;
+            return Ok(());
+        };
+        league.record(index, outcome)
+    }
+}
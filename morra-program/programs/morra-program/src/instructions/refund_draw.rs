@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RefundDraw<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.creator.as_ref(), &game.game_id.to_le_bytes()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    #[account(mut, address = game.creator)]
+    /// CHECK: Receives the creator's bet back
+    pub creator: AccountInfo<'info>,
+    
+    #[account(mut, address = game.opponent)]
+    /// CHECK: Receives the opponent's bet back
+    pub opponent: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the game funds
+    pub game_account: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RefundDraw<'info> {
+    pub fn refund_draw(&mut self, bumps: &RefundDrawBumps) -> Result<()> {
+        let game = &mut self.game;
+        game.transition(GameStatus::Draw, GameStatus::Settled)?;
+
+        let payout = game.payout()?;
+
+        // Return each player's bet from the game account
+        let game_key = game.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            GAME_SEED,
+            game_key.as_ref(),
+            &[bumps.game_account],
+        ]];
+        for (player, amount) in [
+            (&self.creator, payout.creator),
+            (&self.opponent, payout.opponent),
+        ] {
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: self.game_account.to_account_info(),
+                        to: player.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+
+        Ok(())
+    }
+}
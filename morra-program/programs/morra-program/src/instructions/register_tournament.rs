@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RegisterTournament<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    /// Seeding reads the player's rating, so registering requires a profile.
+    #[account(
+        seeds = [PROFILE_SEED, player.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [
+            TOURNAMENT_SEED,
+            tournament.organizer.as_ref(),
+            &tournament.id.to_le_bytes()
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, tournament.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is the PDA that holds the prize pool
+    pub prize_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterTournament<'info> {
+    pub fn register_tournament(&mut self) -> Result<()> {
+        let tournament = &mut self.tournament;
+        require!(
+            tournament.status == TournamentStatus::Registration,
+            GameError::InvalidTournamentState
+        );
+        require!(
+            Clock::get()?.unix_timestamp < tournament.registration_ends_at,
+            GameError::RegistrationClosed
+        );
+        tournament.register(self.player.key())?;
+        tournament.prize_pool += tournament.entry_fee;
+
+        // Transfer the entry fee into the prize vault
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.player.to_account_info(),
+                    to: self.prize_vault.to_account_info(),
+                },
+            ),
+            tournament.entry_fee,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Queue `shares` for withdrawal once `WITHDRAWAL_COOLDOWN` has passed,
+/// replacing any earlier request and restarting the clock. Zero shares
+/// cancels the request.
+#[derive(Accounts)]
+pub struct RequestWithdrawal<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LP_SEED, owner.key().as_ref()],
+        bump = position.bump,
+        has_one = owner
+    )]
+    pub position: Account<'info, LpPosition>,
+}
+
+impl<'info> RequestWithdrawal<'info> {
+    pub fn request_withdrawal(&mut self, shares: u64) -> Result<()> {
+        let position = &mut self.position;
+        require!(shares <= position.shares, GameError::InvalidShareAmount);
+        position.pending_shares = shares;
+        position.requested_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
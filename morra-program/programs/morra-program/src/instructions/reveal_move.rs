@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use morra_core::Move;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RevealMove<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    
+    /// The player, or a session key they authorized.
+    pub player: Signer<'info>,
+    
+    /// Checked against the game's players when this reveal resolves it.
+    #[account(mut)]
+    pub creator_profile: Account<'info, PlayerProfile>,
+    
+    #[account(mut)]
+    pub opponent_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        seeds = [SESSION_SEED, session.player.as_ref(), player.key().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    
+    /// CHECK: The tournament or league the session is scoped to, read by
+    /// `SessionKey::authorize`
+    pub session_scope: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> RevealMove<'info> {
+    pub fn reveal_move(&mut self, card: u8, prediction: u8, salt: [u8; 32]) -> Result<()> {
+        require!(morra_core::is_valid_card(card), GameError::InvalidCard);
+        require!(
+            morra_core::is_valid_prediction(prediction),
+            GameError::InvalidPrediction
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let player = match &self.session {
+            Some(session) => session.authorize(
+                &self.player.key(),
+                &self.game.key(),
+                self.session_scope.as_deref(),
+                now,
+            )?,
+            None => self.player.key(),
+        };
+
+        let game = &mut self.game;
+        require!(game.status == GameStatus::WaitingForReveal, GameError::InvalidGameState);
+        require!(!game.timed_out(now), GameError::GameTimedOut);
+
+        let scope = game.commitment_scope(&game.key());
+        let player_move = game
+            .player_move_mut(&player)
+            .ok_or(GameError::InvalidPlayer)?;
+
+        // Verify commitment using the shared encoding and the sha256 syscall
+        let preimage = morra_core::commitment_preimage(
+            &player.to_bytes(),
+            &scope.to_bytes(),
+            Move::new(card, prediction),
+            &salt,
+        );
+        let commitment = hash(&preimage).to_bytes();
+        require!(
+            player_move.committed_hash == Some(commitment),
+            GameError::InvalidCommitment
+        );
+
+        // Store move
+        player_move.card = Some(card);
+        player_move.prediction = Some(prediction);
+        game.last_action_at = now;
+
+        // Check if both players have revealed
+        if game.creator_move.revealed().is_some() && game.opponent_move.revealed().is_some() {
+            game.resolve_game()?;
+
+            require_keys_eq!(
+                self.creator_profile.player,
+                game.creator,
+                GameError::InvalidProfile
+            );
+            require_keys_eq!(
+                self.opponent_profile.player,
+                game.opponent,
+                GameError::InvalidProfile
+            );
+            let outcome = game.outcome().ok_or(GameError::InvalidGameState)?;
+            PlayerProfile::record_result(
+                &mut self.creator_profile,
+                &mut self.opponent_profile,
+                outcome,
+                game.bet_amount,
+            );
+            if game.ranked {
+                PlayerProfile::update_ratings(
+                    &mut self.creator_profile,
+                    &mut self.opponent_profile,
+                    outcome,
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+/// Close a session early, returning its rent to the player.
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = player,
+        seeds = [SESSION_SEED, player.key().as_ref(), session.session_key.as_ref()],
+        bump = session.bump
+    )]
+    pub session: Account<'info, SessionKey>,
+}
+
+impl<'info> RevokeSession<'info> {
+    pub fn revoke_session(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Rotate the key that signs the house's commitments and reveals. Games
+/// the old operator committed to are revealed by the new one, since the
+/// commitment is bound to the house account rather than the key.
+#[derive(Accounts)]
+pub struct SetHouseOperator<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED],
+        bump = house.bump,
+        has_one = authority @ GameError::Unauthorized
+    )]
+    pub house: Account<'info, House>,
+}
+
+impl<'info> SetHouseOperator<'info> {
+    pub fn set_house_operator(&mut self, operator: Pubkey) -> Result<()> {
+        self.house.operator = operator;
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: close the season once every fixture is recorded, or at
+/// the season deadline with whatever has been played, fixing the table the
+/// pot is split by.
+#[derive(Accounts)]
+pub struct SettleLeague<'info> {
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, league.organizer.as_ref(), &league.id.to_le_bytes()],
+        bump = league.bump
+    )]
+    pub league: Account<'info, League>,
+}
+
+impl<'info> SettleLeague<'info> {
+    pub fn settle_league(&mut self) -> Result<()> {
+        let league = &mut self.league;
+        require!(
+            league.status == LeagueStatus::InProgress,
+            GameError::InvalidLeagueState
+        );
+        require!(
+            league.season_complete()
+                || Clock::get()?.unix_timestamp >= league.season_ends_at,
+            GameError::SeasonNotOver
+        );
+        league.status = LeagueStatus::Completed;
+        Ok(())
+    }
+}
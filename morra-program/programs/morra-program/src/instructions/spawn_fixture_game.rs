@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: open the game for an unplayed fixture, in the home
+/// player's next game slot. Like a tournament match, the game carries no
+/// stake and starts waiting for both players to commit.
+#[derive(Accounts)]
+pub struct SpawnFixtureGame<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LEAGUE_SEED, league.organizer.as_ref(), &league.id.to_le_bytes()],
+        bump = league.bump
+    )]
+    pub league: Account<'info, League>,
+    
+    /// The fixture's home player, who takes the creator seat.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, creator_profile.player.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Game::LEN,
+        seeds = [
+            GAME_SEED,
+            creator_profile.player.as_ref(),
+            &creator_profile.next_game_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SpawnFixtureGame<'info> {
+    pub fn spawn_fixture_game(
+        &mut self,
+        fixture_index: u8,
+        bumps: &SpawnFixtureGameBumps,
+    ) -> Result<()> {
+        let league = &mut self.league;
+        require!(
+            league.status == LeagueStatus::InProgress,
+            GameError::InvalidLeagueState
+        );
+        let fixture = league
+            .fixtures
+            .get_mut(usize::from(fixture_index))
+            .ok_or(GameError::InvalidMatch)?;
+        require!(
+            !fixture.played && fixture.game.is_none(),
+            GameError::InvalidMatch
+        );
+        fixture.game = Some(self.game.key());
+        let [creator, opponent] =
+            fixture.players.map(|index| league.standings[usize::from(index)].player);
+        require_keys_eq!(self.creator_profile.player, creator, GameError::InvalidProfile);
+
+        // The players are seated already, so this is the game's initial
+        // status, not a transition.
+        let now = Clock::get()?.unix_timestamp;
+        self.game.set_inner(Game {
+            creator,
+            game_id: self.creator_profile.take_game_id(),
+            bump: bumps.game,
+            opponent,
+            bet_amount: 0,
+            ranked: false,
+            lobby_page: 0,
+            status: GameStatus::WaitingForCommits,
+            creator_move: PlayerMove::default(),
+            opponent_move: PlayerMove::default(),
+            winner: None,
+            created_at: now,
+            last_action_at: now,
+            creator_sponsor: None,
+            opponent_sponsor: None,
+            queue: None,
+        });
+        Ok(())
+    }
+}
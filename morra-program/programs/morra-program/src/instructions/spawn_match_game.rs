@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: open the game for a bracket match whose two players are
+/// known, in the first seat's next game slot. The game carries no stake and
+/// starts waiting for both players to commit.
+#[derive(Accounts)]
+pub struct SpawnMatchGame<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            TOURNAMENT_SEED,
+            tournament.organizer.as_ref(),
+            &tournament.id.to_le_bytes()
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    /// The match's first seat, who takes the creator seat.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, creator_profile.player.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Game::LEN,
+        seeds = [
+            GAME_SEED,
+            creator_profile.player.as_ref(),
+            &creator_profile.next_game_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub game: Account<'info, Game>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SpawnMatchGame<'info> {
+    pub fn spawn_match_game(
+        &mut self,
+        match_index: u8,
+        bumps: &SpawnMatchGameBumps,
+    ) -> Result<()> {
+        require!(
+            self.tournament.status == TournamentStatus::InProgress,
+            GameError::InvalidTournamentState
+        );
+        let bracket_match = self
+            .tournament
+            .matches
+            .get_mut(usize::from(match_index))
+            .ok_or(GameError::InvalidMatch)?;
+        let [Some(creator), Some(opponent)] = bracket_match.players else {
+            return err!(GameError::InvalidMatch);
+        };
+        require!(
+            bracket_match.winner.is_none() && bracket_match.game.is_none(),
+            GameError::InvalidMatch
+        );
+        require_keys_eq!(self.creator_profile.player, creator, GameError::InvalidProfile);
+        bracket_match.game = Some(self.game.key());
+
+        // The players are seated already, so this is the game's initial
+        // status, not a transition.
+        let now = Clock::get()?.unix_timestamp;
+        self.game.set_inner(Game {
+            creator,
+            game_id: self.creator_profile.take_game_id(),
+            bump: bumps.game,
+            opponent,
+            bet_amount: 0,
+            ranked: false,
+            lobby_page: 0,
+            status: GameStatus::WaitingForCommits,
+            creator_move: PlayerMove::default(),
+            opponent_move: PlayerMove::default(),
+            winner: None,
+            created_at: now,
+            last_action_at: now,
+            creator_sponsor: None,
+            opponent_sponsor: None,
+            queue: None,
+        });
+        Ok(())
+    }
+}
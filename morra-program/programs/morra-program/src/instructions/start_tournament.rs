@@ -0,0 +1,56 @@
+use std::cmp::Reverse;
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Permissionless: once registration closes, or the bracket fills, anyone
+/// can draw the bracket. The registered players' profiles are passed as
+/// remaining accounts in registration order; players are seeded by rating,
+/// earlier registration breaking ties.
+#[derive(Accounts)]
+pub struct StartTournament<'info> {
+    #[account(
+        mut,
+        seeds = [
+            TOURNAMENT_SEED,
+            tournament.organizer.as_ref(),
+            &tournament.id.to_le_bytes()
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+}
+
+impl<'info> StartTournament<'info> {
+    pub fn start_tournament(&mut self, profiles: &'info [AccountInfo<'info>]) -> Result<()> {
+        let tournament = &mut self.tournament;
+        require!(
+            tournament.status == TournamentStatus::Registration,
+            GameError::InvalidTournamentState
+        );
+        let full = tournament.players.len() == usize::from(tournament.max_players);
+        require!(
+            full || Clock::get()?.unix_timestamp >= tournament.registration_ends_at,
+            GameError::InvalidTournamentState
+        );
+        require!(
+            profiles.len() == tournament.players.len(),
+            GameError::InvalidProfile
+        );
+
+        let mut ratings = Vec::with_capacity(profiles.len());
+        for (player, info) in tournament.players.iter().zip(profiles) {
+            let profile = Account::<PlayerProfile>::try_from(info)?;
+            require_keys_eq!(profile.player, *player, GameError::InvalidProfile);
+            ratings.push(profile.rating);
+        }
+
+        let mut order: Vec<usize> = (0..ratings.len()).collect();
+        order.sort_by_key(|&index| (Reverse(ratings[index]), index));
+        let seeded: Vec<Pubkey> = order.iter().map(|&index| tournament.players[index]).collect();
+        tournament.start(&seeded)
+    }
+}
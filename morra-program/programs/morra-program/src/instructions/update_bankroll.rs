@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Change the bankroll's rates. Games already open keep the edge they were
+/// created under.
+#[derive(Accounts)]
+pub struct UpdateBankroll<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [BANKROLL_SEED],
+        bump = bankroll.bump,
+        has_one = authority @ GameError::Unauthorized
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+}
+
+impl<'info> UpdateBankroll<'info> {
+    pub fn update_bankroll(&mut self, max_exposure_bps: u16, house_edge_bps: u16) -> Result<()> {
+        self.bankroll.set_rates(max_exposure_bps, house_edge_bps)
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Redeem a cooled-down withdrawal request at its share of the pool's
+/// assets, open exposure included. A request worth more than the
+/// unreserved assets waits for open games to settle.
+#[derive(Accounts)]
+pub struct WithdrawBankroll<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LP_SEED, owner.key().as_ref()],
+        bump = position.bump,
+        has_one = owner
+    )]
+    pub position: Account<'info, LpPosition>,
+    
+    #[account(
+        mut,
+        seeds = [BANKROLL_SEED],
+        bump = bankroll.bump
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+}
+
+impl<'info> WithdrawBankroll<'info> {
+    pub fn withdraw_bankroll(&mut self) -> Result<()> {
+        let shares = self.position.pending_shares;
+        require!(shares > 0, GameError::InvalidShareAmount);
+        let now = Clock::get()?.unix_timestamp;
+        self.position.check_redeemable(now)?;
+
+        let total_shares = self.bankroll.total_shares;
+        let assets = Bankroll::assets(&self.bankroll)?;
+        let free_assets = Bankroll::free_assets(&self.bankroll)?;
+        let amount = morra_core::redemption_value(shares, total_shares, assets);
+        require!(amount <= free_assets, GameError::ExposureLimit);
+
+        self.position.shares -= shares;
+        self.position.pending_shares = 0;
+        self.bankroll.total_shares -= shares;
+        Bankroll::pay(&self.bankroll, &self.owner.to_account_info(), amount)
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// Return bond to the house authority. Only the bond above the bankroll's
+/// open exposure can leave, so every open game stays covered by a slash.
+#[derive(Accounts)]
+pub struct WithdrawHouseBond<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED],
+        bump = house.bump,
+        has_one = authority @ GameError::Unauthorized
+    )]
+    pub house: Account<'info, House>,
+    
+    #[account(
+        seeds = [BANKROLL_SEED],
+        bump = bankroll.bump
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+}
+
+impl<'info> WithdrawHouseBond<'info> {
+    pub fn withdraw_house_bond(&mut self, amount: u64) -> Result<()> {
+        let unlocked = self.house.bond.saturating_sub(self.bankroll.open_exposure);
+        require!(amount <= unlocked, GameError::BondLocked);
+
+        self.house.bond -= amount;
+        **self.house.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **self.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+        Ok(())
+    }
+}
@@ -1,5 +1,14 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::system_program;
+
+pub mod constants;
+pub mod error;
+pub mod instructions;
+pub mod state;
+
+pub use constants::*;
+pub use error::*;
+pub use instructions::*;
+pub use state::*;
 
 declare_id!("FYG1SQaM49FGPQEGco8s9x4i3MzquotS48XcNre1UMBf");
 
@@ -7,43 +16,77 @@ declare_id!("FYG1SQaM49FGPQEGco8s9x4i3MzquotS48XcNre1UMBf");
 pub mod morra_program {
     use super::*;
 
+    pub fn create_profile(ctx: Context<CreateProfile>) -> Result<()> {
+        ctx.accounts.create_profile(&ctx.bumps)
+    }
+
+    pub fn create_lobby_page(
+        ctx: Context<CreateLobbyPage>,
+        ranked: bool,
+        index: u32,
+    ) -> Result<()> {
+        ctx.accounts.create_lobby_page(ranked, index, &ctx.bumps)
+    }
+
     pub fn create_game(
         ctx: Context<CreateGame>,
         bet_amount: u64,
         commitment: [u8; 32],
+        ranked: bool,
+        reimbursement: u64,
     ) -> Result<()> {
-        let game = &mut ctx.accounts.game;
-        game.creator = ctx.accounts.creator.key();
-        game.bet_amount = bet_amount;
-        game.creator_commitment = commitment;
-        game.status = GameStatus::WaitingForOpponent;
-        game.created_at = Clock::get()?.unix_timestamp;
-        Ok(())
+        ctx.accounts
+            .create_game(bet_amount, commitment, ranked, reimbursement, &ctx.bumps)
     }
 
     pub fn join_game(
         ctx: Context<JoinGame>,
         commitment: [u8; 32],
+        reimbursement: u64,
+    ) -> Result<()> {
+        ctx.accounts.join_game(commitment, reimbursement)
+    }
+
+    pub fn cancel_game(ctx: Context<CancelGame>) -> Result<()> {
+        ctx.accounts.cancel_game(&ctx.bumps)
+    }
+
+    pub fn create_match_queue(ctx: Context<CreateMatchQueue>, tier: u8) -> Result<()> {
+        ctx.accounts.create_match_queue(tier, &ctx.bumps)
+    }
+
+    pub fn enqueue(
+        ctx: Context<Enqueue>,
+        commitment: [u8; 32],
+        ranked: bool,
+    ) -> Result<()> {
+        ctx.accounts.enqueue(commitment, ranked)
+    }
+
+    pub fn leave_queue(ctx: Context<LeaveQueue>) -> Result<()> {
+        ctx.accounts.leave_queue(&ctx.bumps)
+    }
+
+    pub fn pair_match(ctx: Context<PairMatch>) -> Result<()> {
+        ctx.accounts.pair_match(&ctx.bumps)
+    }
+
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_key: Pubkey,
+        scope: Pubkey,
+        expires_at: i64,
     ) -> Result<()> {
-        let game = &mut ctx.accounts.game;
-        require!(game.status == GameStatus::WaitingForOpponent, GameError::InvalidGameState);
-        
-        // Transfer bet amount from opponent to game account
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.opponent.to_account_info(),
-                    to: ctx.accounts.game_account.to_account_info(),
-                },
-            ),
-            game.bet_amount,
-        )?;
-
-        game.opponent = ctx.accounts.opponent.key();
-        game.opponent_commitment = commitment;
-        game.status = GameStatus::WaitingForReveal;
-        Ok(())
+        ctx.accounts
+            .create_session(session_key, scope, expires_at, &ctx.bumps)
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        ctx.accounts.revoke_session()
+    }
+
+    pub fn commit_move(ctx: Context<CommitMove>, commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.commit_move(commitment)
     }
 
     pub fn reveal_move(
@@ -52,209 +95,202 @@ pub mod morra_program {
         prediction: u8,
         salt: [u8; 32],
     ) -> Result<()> {
-        let game = &mut ctx.accounts.game;
-        let player = &ctx.accounts.player;
-        
-        // Verify commitment
-        let commitment = anchor_lang::solana_program::hash::hash(
-            format!("{}{}{}", card, prediction, salt).as_bytes(),
-        ).to_bytes();
-        
-        let is_creator = player.key() == game.creator;
-        let expected_commitment = if is_creator {
-            game.creator_commitment
-        } else {
-            game.opponent_commitment
-        };
-        
-        require!(commitment == expected_commitment, GameError::InvalidCommitment);
-        
-        // Store move
-        if is_creator {
-            game.creator_card = Some(card);
-            game.creator_prediction = Some(prediction);
-        } else {
-            game.opponent_card = Some(card);
-            game.opponent_prediction = Some(prediction);
-        }
-        
-        // Check if both players have revealed
-        if game.creator_card.is_some() && game.opponent_card.is_some() {
-            game.resolve_game()?;
-        }
-        
-        Ok(())
+        ctx.accounts.reveal_move(card, prediction, salt)
     }
 
     pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
-        let game = &ctx.accounts.game;
-        let winner = &ctx.accounts.winner;
-        
-        require!(game.status == GameStatus::Completed, GameError::InvalidGameState);
-        require!(
-            winner.key() == game.winner.unwrap(),
-            GameError::NotWinner
-        );
-        
-        // Transfer winnings to winner
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.game_account.to_account_info(),
-                    to: ctx.accounts.winner.to_account_info(),
-                },
-                &[&[
-                    b"game".as_ref(),
-                    &game.key().to_bytes(),
-                    &[*ctx.bumps.get("game_account").unwrap()],
-                ]],
-            ),
-            game.bet_amount * 2,
-        )?;
-        
-        Ok(())
+        ctx.accounts.claim_winnings(&ctx.bumps)
     }
-}
 
-#[derive(Accounts)]
-#[instruction(bet_amount: u64)]
-pub struct CreateGame<'info> {
-    #[account(
-        init,
-        payer = creator,
-        space = 8 + Game::LEN,
-        seeds = [b"game", creator.key().as_ref()],
-        bump
-    )]
-    pub game: Account<'info, Game>,
-    
-    #[account(mut)]
-    pub creator: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
+    pub fn refund_draw(ctx: Context<RefundDraw>) -> Result<()> {
+        ctx.accounts.refund_draw(&ctx.bumps)
+    }
 
-#[derive(Accounts)]
-pub struct JoinGame<'info> {
-    #[account(
-        mut,
-        seeds = [b"game", game.creator.as_ref()],
-        bump
-    )]
-    pub game: Account<'info, Game>,
-    
-    #[account(mut)]
-    pub opponent: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"game", game.creator.as_ref()],
-        bump,
-        constraint = game_account.key() == game.key()
-    )]
-    /// CHECK: This is the PDA that holds the game funds
-    pub game_account: AccountInfo<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        id: u64,
+        entry_fee: u64,
+        max_players: u8,
+        registration_ends_at: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_tournament(
+            id,
+            entry_fee,
+            max_players,
+            registration_ends_at,
+            &ctx.bumps,
+        )
+    }
 
-#[derive(Accounts)]
-pub struct RevealMove<'info> {
-    #[account(mut)]
-    pub game: Account<'info, Game>,
-    
-    pub player: Signer<'info>,
-}
+    pub fn register_tournament(ctx: Context<RegisterTournament>) -> Result<()> {
+        ctx.accounts.register_tournament()
+    }
 
-#[derive(Accounts)]
-pub struct ClaimWinnings<'info> {
-    #[account(
-        mut,
-        seeds = [b"game", game.creator.as_ref()],
-        bump
-    )]
-    pub game: Account<'info, Game>,
-    
-    #[account(mut)]
-    pub winner: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"game", game.creator.as_ref()],
-        bump,
-        constraint = game_account.key() == game.key()
-    )]
-    /// CHECK: This is the PDA that holds the game funds
-    pub game_account: AccountInfo<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    pub fn leave_tournament(ctx: Context<LeaveTournament>) -> Result<()> {
+        ctx.accounts.leave_tournament(&ctx.bumps)
+    }
 
-#[account]
-pub struct Game {
-    pub creator: Pubkey,
-    pub opponent: Pubkey,
-    pub bet_amount: u64,
-    pub creator_commitment: [u8; 32],
-    pub opponent_commitment: [u8; 32],
-    pub creator_card: Option<u8>,
-    pub opponent_card: Option<u8>,
-    pub creator_prediction: Option<u8>,
-    pub opponent_prediction: Option<u8>,
-    pub status: GameStatus,
-    pub winner: Option<Pubkey>,
-    pub created_at: i64,
-}
+    pub fn start_tournament<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StartTournament<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.start_tournament(ctx.remaining_accounts)
+    }
 
-impl Game {
-    pub const LEN: usize = 32 + 32 + 8 + 32 + 32 + 1 + 1 + 1 + 1 + 1 + 32 + 8;
-
-    pub fn resolve_game(&mut self) -> Result<()> {
-        let creator_card = self.creator_card.unwrap();
-        let opponent_card = self.opponent_card.unwrap();
-        let creator_prediction = self.creator_prediction.unwrap();
-        let opponent_prediction = self.opponent_prediction.unwrap();
-        
-        let total = creator_card + opponent_card;
-        let creator_wins = creator_prediction == total;
-        let opponent_wins = opponent_prediction == total;
-        
-        match (creator_wins, opponent_wins) {
-            (true, false) => {
-                self.winner = Some(self.creator);
-                self.status = GameStatus::Completed;
-            }
-            (false, true) => {
-                self.winner = Some(self.opponent);
-                self.status = GameStatus::Completed;
-            }
-            _ => {
-                self.status = GameStatus::Draw;
-            }
-        }
-        
-        Ok(())
+    pub fn spawn_match_game(ctx: Context<SpawnMatchGame>, match_index: u8) -> Result<()> {
+        ctx.accounts.spawn_match_game(match_index, &ctx.bumps)
     }
-}
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
-pub enum GameStatus {
-    WaitingForOpponent,
-    WaitingForReveal,
-    Draw,
-    Completed,
-}
+    pub fn advance_tournament(ctx: Context<AdvanceTournament>, match_index: u8) -> Result<()> {
+        ctx.accounts.advance_tournament(match_index)
+    }
+
+    pub fn claim_tournament_prize(ctx: Context<ClaimTournamentPrize>) -> Result<()> {
+        ctx.accounts.claim_tournament_prize(&ctx.bumps)
+    }
+
+    pub fn create_league(
+        ctx: Context<CreateLeague>,
+        id: u64,
+        entry_fee: u64,
+        roster: Vec<Pubkey>,
+        season_ends_at: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_league(id, entry_fee, roster, season_ends_at, &ctx.bumps)
+    }
+
+    pub fn join_league(ctx: Context<JoinLeague>) -> Result<()> {
+        ctx.accounts.join_league()
+    }
+
+    pub fn leave_league(ctx: Context<LeaveLeague>) -> Result<()> {
+        ctx.accounts.leave_league(&ctx.bumps)
+    }
+
+    pub fn spawn_fixture_game(ctx: Context<SpawnFixtureGame>, fixture_index: u8) -> Result<()> {
+        ctx.accounts.spawn_fixture_game(fixture_index, &ctx.bumps)
+    }
+
+    pub fn record_fixture(ctx: Context<RecordFixture>, fixture_index: u8) -> Result<()> {
+        ctx.accounts.record_fixture(fixture_index)
+    }
 
-#[error_code]
-pub enum GameError {
-    #[msg("Invalid game state for this operation")]
-    InvalidGameState,
-    
-    #[msg("Invalid commitment provided")]
-    InvalidCommitment,
-    
-    #[msg("Only the winner can claim winnings")]
-    NotWinner,
+    pub fn settle_league(ctx: Context<SettleLeague>) -> Result<()> {
+        ctx.accounts.settle_league()
+    }
+
+    pub fn claim_league_prize(ctx: Context<ClaimLeaguePrize>) -> Result<()> {
+        ctx.accounts.claim_league_prize(&ctx.bumps)
+    }
+
+    pub fn create_side_bet_pool(ctx: Context<CreateSideBetPool>) -> Result<()> {
+        ctx.accounts.create_side_bet_pool(&ctx.bumps)
+    }
+
+    pub fn place_side_bet(
+        ctx: Context<PlaceSideBet>,
+        side: BetSide,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.place_side_bet(side, amount, &ctx.bumps)
+    }
+
+    pub fn claim_side_bet(ctx: Context<ClaimSideBet>) -> Result<()> {
+        ctx.accounts.claim_side_bet(&ctx.bumps)
+    }
+
+    pub fn initialize_config(ctx: Context<InitializeConfig>, crank_reward: u64) -> Result<()> {
+        ctx.accounts.initialize_config(crank_reward, &ctx.bumps)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, crank_reward: u64) -> Result<()> {
+        ctx.accounts.update_config(crank_reward)
+    }
+
+    pub fn expire_game(ctx: Context<ExpireGame>) -> Result<()> {
+        ctx.accounts.expire_game(&ctx.bumps)
+    }
+
+    pub fn forfeit_game(ctx: Context<ForfeitGame>) -> Result<()> {
+        ctx.accounts.forfeit_game(&ctx.bumps)
+    }
+
+    pub fn initialize_house(
+        ctx: Context<InitializeHouse>,
+        operator: Pubkey,
+        bond: u64,
+    ) -> Result<()> {
+        ctx.accounts.initialize_house(operator, bond, &ctx.bumps)
+    }
+
+    pub fn fund_house_bond(ctx: Context<FundHouseBond>, amount: u64) -> Result<()> {
+        ctx.accounts.fund_house_bond(amount)
+    }
+
+    pub fn withdraw_house_bond(ctx: Context<WithdrawHouseBond>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_house_bond(amount)
+    }
+
+    pub fn set_house_operator(ctx: Context<SetHouseOperator>, operator: Pubkey) -> Result<()> {
+        ctx.accounts.set_house_operator(operator)
+    }
+
+    pub fn create_house_game(ctx: Context<CreateHouseGame>, bet_amount: u64) -> Result<()> {
+        ctx.accounts.create_house_game(bet_amount, &ctx.bumps)
+    }
+
+    pub fn house_commit(ctx: Context<HouseCommit>, commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.house_commit(commitment)
+    }
+
+    pub fn play_house_game(ctx: Context<PlayHouseGame>, card: u8, prediction: u8) -> Result<()> {
+        ctx.accounts.play_house_game(card, prediction)
+    }
+
+    pub fn house_reveal(
+        ctx: Context<HouseReveal>,
+        card: u8,
+        prediction: u8,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .house_reveal(card, prediction, salt, &ctx.bumps)
+    }
+
+    pub fn forfeit_house_game(ctx: Context<ForfeitHouseGame>) -> Result<()> {
+        ctx.accounts.forfeit_house_game(&ctx.bumps)
+    }
+
+    pub fn initialize_bankroll(
+        ctx: Context<InitializeBankroll>,
+        max_exposure_bps: u16,
+        house_edge_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .initialize_bankroll(max_exposure_bps, house_edge_bps, &ctx.bumps)
+    }
+
+    pub fn update_bankroll(
+        ctx: Context<UpdateBankroll>,
+        max_exposure_bps: u16,
+        house_edge_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.update_bankroll(max_exposure_bps, house_edge_bps)
+    }
+
+    pub fn create_lp_position(ctx: Context<CreateLpPosition>) -> Result<()> {
+        ctx.accounts.create_lp_position(&ctx.bumps)
+    }
+
+    pub fn deposit_bankroll(ctx: Context<DepositBankroll>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit_bankroll(amount)
+    }
+
+    pub fn request_withdrawal(ctx: Context<RequestWithdrawal>, shares: u64) -> Result<()> {
+        ctx.accounts.request_withdrawal(shares)
+    }
+
+    pub fn withdraw_bankroll(ctx: Context<WithdrawBankroll>) -> Result<()> {
+        ctx.accounts.withdraw_bankroll()
+    }
 }
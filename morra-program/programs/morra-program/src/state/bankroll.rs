@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::GameError;
+
+/// The house bankroll: a pool of SOL owned by its liquidity providers in
+/// proportion to their shares. The pool's assets are the lamports this
+/// account holds above its rent-exempt minimum; house losses are paid from
+/// them, and the player stakes the house wins, along with the house edge
+/// it keeps from player wins, are added to them.
+///
+/// Every open house game reserves its bet as exposure, so settlement can
+/// always pay and providers can't withdraw lamports a game may need.
+///
+/// Seeded `[BANKROLL_SEED]`.
+#[account]
+pub struct Bankroll {
+    /// The config authority that set the bankroll up; it alone sets rates.
+    pub authority: Pubkey,
+    pub total_shares: u64,
+    /// Bets reserved by house games still in play.
+    pub open_exposure: u64,
+    /// Largest bet the house takes in a single game, as a fraction of the
+    /// pool's assets in basis points.
+    pub max_exposure_bps: u16,
+    /// Cut of a winning player's payout the pool keeps, in basis points.
+    pub house_edge_bps: u16,
+    pub bump: u8,
+}
+
+impl Bankroll {
+    pub const LEN: usize = 32 + // authority
+        8 + // total_shares
+        8 + // open_exposure
+        2 + // max_exposure_bps
+        2 + // house_edge_bps
+        1; // bump
+
+    pub fn set_rates(&mut self, max_exposure_bps: u16, house_edge_bps: u16) -> Result<()> {
+        require!(
+            max_exposure_bps <= morra_core::BASIS_POINTS
+                && house_edge_bps <= morra_core::BASIS_POINTS,
+            GameError::InvalidRate
+        );
+        self.max_exposure_bps = max_exposure_bps;
+        self.house_edge_bps = house_edge_bps;
+        Ok(())
+    }
+
+    /// Lamports the pool holds for its providers.
+    pub fn assets(bankroll: &Account<Bankroll>) -> Result<u64> {
+        let info = bankroll.to_account_info();
+        let reserve = Rent::get()?.minimum_balance(info.data_len());
+        Ok(info.lamports().saturating_sub(reserve))
+    }
+
+    /// Assets not already reserved for open games.
+    pub fn free_assets(bankroll: &Account<Bankroll>) -> Result<u64> {
+        Ok(Self::assets(bankroll)?.saturating_sub(bankroll.open_exposure))
+    }
+
+    /// Reserve a game's bet, if it's within the per-game fraction of the
+    /// pool and the free assets can cover it.
+    pub fn reserve(bankroll: &mut Account<Bankroll>, bet_amount: u64) -> Result<()> {
+        let limit = morra_core::bps_of(Self::assets(bankroll)?, bankroll.max_exposure_bps);
+        require!(
+            bet_amount <= limit && bet_amount <= Self::free_assets(bankroll)?,
+            GameError::ExposureLimit
+        );
+        bankroll.open_exposure += bet_amount;
+        Ok(())
+    }
+
+    /// Move `amount` of the pool's lamports to `to`.
+    pub fn pay<'info>(
+        bankroll: &Account<'info, Bankroll>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        **bankroll.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? += amount;
+        Ok(())
+    }
+}
+
+/// One provider's stake in the bankroll. Shares queued for withdrawal keep
+/// sharing the pool's gains and losses until they're redeemed, and are
+/// redeemed at their share of all the pool's assets once the payout fits
+/// in what open games leave unreserved. A request can only be redeemed
+/// for `WITHDRAWAL_WINDOW` after its cooldown, so one can't be filed early
+/// and held in reserve.
+///
+/// Shares are a balance on this account rather than an SPL token, so they
+/// can't be transferred or traded; a provider exits only by withdrawing.
+/// That keeps the cooldown from being dodged by selling queued shares, and
+/// keeps the program free of a token dependency.
+///
+/// Seeded `[LP_SEED, owner]`.
+#[account]
+pub struct LpPosition {
+    pub owner: Pubkey,
+    pub shares: u64,
+    /// Shares queued for withdrawal, out of `shares`.
+    pub pending_shares: u64,
+    pub requested_at: i64,
+    pub bump: u8,
+}
+
+impl LpPosition {
+    pub const LEN: usize = 32 + // owner
+        8 + // shares
+        8 + // pending_shares
+        8 + // requested_at
+        1; // bump
+
+    /// Check the queued request can be redeemed at `now`.
+    pub fn check_redeemable(&self, now: i64) -> Result<()> {
+        let opens_at = self.requested_at + WITHDRAWAL_COOLDOWN;
+        require!(now >= opens_at, GameError::CooldownNotElapsed);
+        require!(
+            now < opens_at + WITHDRAWAL_WINDOW,
+            GameError::WithdrawalExpired
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+/// Program-wide settings. Lamports it holds above its rent-exempt minimum
+/// are the crank reward pool, topped up by plain transfers to the account.
+///
+/// Seeded `[CONFIG_SEED]`.
+#[account]
+pub struct Config {
+    pub authority: Pubkey,
+    /// Lamports paid to whoever enforces a timeout, while the pool lasts.
+    pub crank_reward: u64,
+    pub bump: u8,
+}
+
+impl Config {
+    pub const LEN: usize = 32 + // authority
+        8 + // crank_reward
+        1; // bump
+
+    /// Pay the crank reward to `cranker` if the pool can cover it, returning
+    /// what was paid. An empty pool pays nothing rather than failing, so
+    /// timeouts are always enforceable.
+    pub fn pay_crank_reward<'info>(
+        config: &Account<'info, Config>,
+        cranker: &AccountInfo<'info>,
+    ) -> Result<u64> {
+        let pool = config.to_account_info();
+        let reserve = Rent::get()?.minimum_balance(pool.data_len());
+        let reward = config.crank_reward;
+        if reward == 0 || pool.lamports().saturating_sub(reserve) < reward {
+            return Ok(0);
+        }
+        **pool.try_borrow_mut_lamports()? -= reward;
+        **cranker.try_borrow_mut_lamports()? += reward;
+        Ok(reward)
+    }
+}
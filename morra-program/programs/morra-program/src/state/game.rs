@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+use morra_core::{Move, Outcome, Payout, Status};
+
+use crate::constants::{JOIN_TIMEOUT, MOVE_TIMEOUT};
+use crate::error::GameError;
+
+#[account]
+pub struct Game {
+    pub creator: Pubkey,
+    /// The creator's game counter when this game was opened; part of the
+    /// game's address.
+    pub game_id: u64,
+    pub bump: u8,
+    pub opponent: Pubkey,
+    pub bet_amount: u64,
+    /// Ranked games move both players' ratings when they resolve.
+    pub ranked: bool,
+    /// Index of the lobby page listing this game while it waits for an
+    /// opponent.
+    pub lobby_page: u32,
+    pub status: GameStatus,
+    pub creator_move: PlayerMove,
+    pub opponent_move: PlayerMove,
+    pub winner: Option<Pubkey>,
+    pub created_at: i64,
+    pub last_action_at: i64,
+    /// Relayers that sponsored each seat's transactions, repaid out of that
+    /// seat's winnings.
+    pub creator_sponsor: Option<Sponsor>,
+    pub opponent_sponsor: Option<Sponsor>,
+    /// The match queue the game was paired out of. Queued players commit
+    /// before the game exists, so their commitments are bound to the queue.
+    pub queue: Option<Pubkey>,
+}
+
+impl Game {
+    pub const LEN: usize = 32 + // creator
+        8 + // game_id
+        1 + // bump
+        32 + // opponent
+        8 + // bet_amount
+        1 + // ranked
+        4 + // lobby_page
+        1 + // status
+        PlayerMove::LEN * 2 + // moves
+        (1 + 32) + // winner
+        8 + // created_at
+        8 + // last_action_at
+        (1 + Sponsor::LEN) * 2 + // sponsors
+        (1 + 32); // queue
+
+    /// The address commitments in the game at `address` are bound to: the
+    /// game's own, or the queue it was paired out of.
+    pub fn commitment_scope(&self, address: &Pubkey) -> Pubkey {
+        self.queue.unwrap_or(*address)
+    }
+
+    /// The move slot belonging to `player`, if they are in this game.
+    pub fn player_move_mut(&mut self, player: &Pubkey) -> Option<&mut PlayerMove> {
+        if *player == self.creator {
+            Some(&mut self.creator_move)
+        } else if *player == self.opponent {
+            Some(&mut self.opponent_move)
+        } else {
+            None
+        }
+    }
+
+    /// The sponsor owed a cut of `player`'s winnings, if any.
+    pub fn sponsor_of(&self, player: &Pubkey) -> Option<Sponsor> {
+        if *player == self.creator {
+            self.creator_sponsor
+        } else if *player == self.opponent {
+            self.opponent_sponsor
+        } else {
+            None
+        }
+    }
+
+    /// Move from `from` to `to`, the only way status changes after creation.
+    ///
+    /// Fails with `InvalidGameState` if the game isn't in `from`, and with
+    /// `InvalidTransition` if the lifecycle table has no `from -> to` edge.
+    pub fn transition(&mut self, from: GameStatus, to: GameStatus) -> Result<()> {
+        require!(self.status == from, GameError::InvalidGameState);
+        require!(from.can_transition_to(to), GameError::InvalidTransition);
+        self.status = to;
+        Ok(())
+    }
+
+    /// When anyone may time the game out: `JOIN_TIMEOUT` after creation
+    /// while it waits for an opponent, `MOVE_TIMEOUT` after the last move
+    /// while it waits on a player. `None` once nobody is being waited on.
+    pub fn timeout_at(&self) -> Option<i64> {
+        match self.status {
+            GameStatus::WaitingForOpponent => Some(self.created_at.saturating_add(JOIN_TIMEOUT)),
+            GameStatus::WaitingForCommits | GameStatus::WaitingForReveal => {
+                Some(self.last_action_at.saturating_add(MOVE_TIMEOUT))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn timed_out(&self, now: i64) -> bool {
+        self.timeout_at().is_some_and(|timeout_at| now >= timeout_at)
+    }
+
+    /// End a game stalled waiting on a move. A player who made their move
+    /// in the current phase wins by forfeit; if neither did, the game is
+    /// cancelled.
+    pub fn forfeit(&mut self) -> Result<()> {
+        let phase = self.status;
+        let moved = |player_move: &PlayerMove| match phase {
+            GameStatus::WaitingForCommits => player_move.committed_hash.is_some(),
+            GameStatus::WaitingForReveal => player_move.revealed().is_some(),
+            _ => false,
+        };
+        let moved = (moved(&self.creator_move), moved(&self.opponent_move));
+        require!(
+            matches!(phase, GameStatus::WaitingForCommits | GameStatus::WaitingForReveal)
+                && moved != (true, true),
+            GameError::InvalidGameState
+        );
+
+        match moved {
+            (true, _) => {
+                self.transition(phase, GameStatus::Completed)?;
+                self.winner = Some(self.creator);
+            }
+            (_, true) => {
+                self.transition(phase, GameStatus::Completed)?;
+                self.winner = Some(self.opponent);
+            }
+            _ => self.transition(phase, GameStatus::Cancelled)?,
+        }
+        Ok(())
+    }
+
+    /// How the game ended, or `None` if it was never resolved.
+    pub fn outcome(&self) -> Option<Outcome> {
+        let resolved = matches!(
+            self.status,
+            GameStatus::Draw | GameStatus::Completed | GameStatus::Settled
+        );
+        if !resolved {
+            return None;
+        }
+        Some(match self.winner {
+            None => Outcome::Draw,
+            Some(winner) if winner == self.creator => Outcome::CreatorWins,
+            Some(_) => Outcome::OpponentWins,
+        })
+    }
+
+    /// Lamports owed to each player out of the escrow, once the game is over.
+    pub fn payout(&self) -> Result<Payout> {
+        let outcome = self.outcome().ok_or(GameError::InvalidGameState)?;
+        Ok(morra_core::payout(outcome, self.bet_amount).ok_or(GameError::InvalidBetAmount)?)
+    }
+
+    pub fn resolve_game(&mut self) -> Result<()> {
+        let (Some(creator_move), Some(opponent_move)) =
+            (self.creator_move.revealed(), self.opponent_move.revealed())
+        else {
+            return err!(GameError::InvalidGameState);
+        };
+
+        match morra_core::resolve(creator_move, opponent_move) {
+            Outcome::CreatorWins => {
+                self.transition(GameStatus::WaitingForReveal, GameStatus::Completed)?;
+                self.winner = Some(self.creator);
+            }
+            Outcome::OpponentWins => {
+                self.transition(GameStatus::WaitingForReveal, GameStatus::Completed)?;
+                self.winner = Some(self.opponent);
+            }
+            Outcome::Draw => {
+                self.transition(GameStatus::WaitingForReveal, GameStatus::Draw)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A relayer that paid a player's fees and rent, and the lamports the player
+/// agreed to hand back if they win.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sponsor {
+    pub relayer: Pubkey,
+    pub reimbursement: u64,
+}
+
+impl Sponsor {
+    pub const LEN: usize = 32 + 8;
+
+    /// The sponsorship a player signs up for by letting `payer` cover their
+    /// transaction. A zero reimbursement is free sponsorship; anything more
+    /// must come from a separate payer and can't exceed the profit on a win.
+    pub fn new(
+        player: &Pubkey,
+        payer: &Pubkey,
+        reimbursement: u64,
+        bet_amount: u64,
+    ) -> Result<Option<Self>> {
+        if reimbursement == 0 {
+            return Ok(None);
+        }
+        require!(
+            payer != player && reimbursement <= bet_amount,
+            GameError::InvalidReimbursement
+        );
+        Ok(Some(Self {
+            relayer: *payer,
+            reimbursement,
+        }))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PlayerMove {
+    pub committed_hash: Option<[u8; 32]>,
+    pub card: Option<u8>,
+    pub prediction: Option<u8>,
+}
+
+impl PlayerMove {
+    pub const LEN: usize = (1 + 32) + 2 + 2;
+
+    pub fn committed(commitment: [u8; 32]) -> Self {
+        Self {
+            committed_hash: Some(commitment),
+            ..Self::default()
+        }
+    }
+
+    pub fn revealed(&self) -> Option<Move> {
+        Some(Move::new(self.card?, self.prediction?))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameStatus {
+    WaitingForOpponent,
+    WaitingForReveal,
+    Draw,
+    Completed,
+    Settled,
+    Cancelled,
+    WaitingForCommits,
+}
+
+impl GameStatus {
+    pub const ALL: [GameStatus; 7] = [
+        GameStatus::WaitingForOpponent,
+        GameStatus::WaitingForReveal,
+        GameStatus::Draw,
+        GameStatus::Completed,
+        GameStatus::Settled,
+        GameStatus::Cancelled,
+        GameStatus::WaitingForCommits,
+    ];
+
+    /// Whether the shared lifecycle table has a `self -> to` edge.
+    pub fn can_transition_to(self, to: GameStatus) -> bool {
+        Status::from(self).can_transition_to(to.into())
+    }
+}
+
+impl From<GameStatus> for Status {
+    fn from(status: GameStatus) -> Self {
+        match status {
+            GameStatus::WaitingForOpponent => Status::WaitingForOpponent,
+            GameStatus::WaitingForReveal => Status::WaitingForReveal,
+            GameStatus::Draw => Status::Draw,
+            GameStatus::Completed => Status::Completed,
+            GameStatus::Settled => Status::Settled,
+            GameStatus::Cancelled => Status::Cancelled,
+            GameStatus::WaitingForCommits => Status::WaitingForCommits,
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use morra_core::Outcome;
+
+use crate::constants::*;
+use crate::error::GameError;
+use crate::state::*;
+
+/// The house: an operator key that takes the other seat in solo games, and
+/// the bond it posts as a guarantee that it reveals. The bond is held as
+/// lamports in this account above its rent-exempt minimum, and must cover
+/// every open game's bet so a forfeit can always be slashed in full.
+///
+/// The house's stakes come from the [`Bankroll`], which its liquidity
+/// providers fund.
+///
+/// Seeded `[HOUSE_SEED]`.
+#[account]
+pub struct House {
+    /// The config authority that set the house up, and manages its bond and
+    /// operator.
+    pub authority: Pubkey,
+    /// Signs the house's commitments and reveals.
+    pub operator: Pubkey,
+    pub bond: u64,
+    pub bump: u8,
+}
+
+impl House {
+    pub const LEN: usize = 32 + // authority
+        32 + // operator
+        8 + // bond
+        1; // bump
+
+    /// Pay `player` up to `amount` out of the bond, returning what was paid.
+    /// Open games never reserve more than the bond, so this is all of
+    /// `amount` in practice.
+    pub fn slash<'info>(
+        house: &mut Account<'info, House>,
+        player: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<u64> {
+        let slashed = amount.min(house.bond);
+        house.bond -= slashed;
+        **house.to_account_info().try_borrow_mut_lamports()? -= slashed;
+        **player.try_borrow_mut_lamports()? += slashed;
+        Ok(slashed)
+    }
+}
+
+/// A solo game against the house. The house commits first, the player then
+/// moves in the open, and the house reveals to settle. A house that doesn't
+/// reveal in time forfeits and has its bond slashed, so it gains nothing by
+/// withholding a losing move.
+///
+/// Seeded `[HOUSE_SEED, player, game_id]`, numbered from the player's
+/// profile like their other games; the player's stake is escrowed at
+/// `[GAME_SEED, house_game]`.
+#[account]
+pub struct HouseGame {
+    pub player: Pubkey,
+    pub game_id: u64,
+    pub bump: u8,
+    pub bet_amount: u64,
+    pub status: HouseGameStatus,
+    pub player_move: PlayerMove,
+    pub house_move: PlayerMove,
+    /// Resolution seen from the player's seat, once settled.
+    pub outcome: Option<HouseOutcome>,
+    pub created_at: i64,
+    pub last_action_at: i64,
+    /// The bankroll's house edge when the game was created, which a player
+    /// win is paid under.
+    pub house_edge_bps: u16,
+}
+
+impl HouseGame {
+    pub const LEN: usize = 32 + // player
+        8 + // game_id
+        1 + // bump
+        8 + // bet_amount
+        1 + // status
+        PlayerMove::LEN + // player_move
+        PlayerMove::LEN + // house_move
+        (1 + 1) + // outcome
+        8 + // created_at
+        8 + // last_action_at
+        2; // house_edge_bps
+
+    /// Move from `from` to `to`, the only way status changes after creation.
+    ///
+    /// Fails with `InvalidGameState` if the game isn't in `from`, and with
+    /// `InvalidTransition` if [`HOUSE_TRANSITIONS`] has no `from -> to` edge.
+    pub fn transition(&mut self, from: HouseGameStatus, to: HouseGameStatus) -> Result<()> {
+        require!(self.status == from, GameError::InvalidGameState);
+        require!(from.can_transition_to(to), GameError::InvalidTransition);
+        self.status = to;
+        Ok(())
+    }
+
+    pub fn timed_out(&self, now: i64) -> bool {
+        let waiting = matches!(
+            self.status,
+            HouseGameStatus::WaitingForHouse
+                | HouseGameStatus::WaitingForPlayer
+                | HouseGameStatus::WaitingForReveal
+        );
+        waiting && now >= self.last_action_at + MOVE_TIMEOUT
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HouseGameStatus {
+    /// Created and staked by the player; the house has yet to commit.
+    WaitingForHouse,
+    WaitingForPlayer,
+    WaitingForReveal,
+    Settled,
+    /// The house never committed; the player's stake was refunded.
+    Cancelled,
+}
+
+/// Every legal `(from, to)` house game status change. Anything not listed
+/// is rejected.
+pub const HOUSE_TRANSITIONS: &[(HouseGameStatus, HouseGameStatus)] = &[
+    // The house commits, then the player moves in the open.
+    (HouseGameStatus::WaitingForHouse, HouseGameStatus::WaitingForPlayer),
+    (HouseGameStatus::WaitingForPlayer, HouseGameStatus::WaitingForReveal),
+    // The house reveals, or times out and forfeits.
+    (HouseGameStatus::WaitingForReveal, HouseGameStatus::Settled),
+    // A player who never moves loses their stake.
+    (HouseGameStatus::WaitingForPlayer, HouseGameStatus::Settled),
+    // A house that never commits calls the game off.
+    (HouseGameStatus::WaitingForHouse, HouseGameStatus::Cancelled),
+];
+
+impl HouseGameStatus {
+    pub const ALL: [HouseGameStatus; 5] = [
+        HouseGameStatus::WaitingForHouse,
+        HouseGameStatus::WaitingForPlayer,
+        HouseGameStatus::WaitingForReveal,
+        HouseGameStatus::Settled,
+        HouseGameStatus::Cancelled,
+    ];
+
+    /// Whether [`HOUSE_TRANSITIONS`] has a `self -> to` edge.
+    pub fn can_transition_to(self, to: HouseGameStatus) -> bool {
+        HOUSE_TRANSITIONS.contains(&(self, to))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HouseOutcome {
+    PlayerWins,
+    HouseWins,
+    Draw,
+}
+
+impl From<Outcome> for HouseOutcome {
+    /// Read `outcome` with the player in the creator's seat.
+    fn from(outcome: Outcome) -> Self {
+        match outcome {
+            Outcome::CreatorWins => HouseOutcome::PlayerWins,
+            Outcome::OpponentWins => HouseOutcome::HouseWins,
+            Outcome::Draw => HouseOutcome::Draw,
+        }
+    }
+}
+
+/// Where a settled house game's money goes: the player's stake from
+/// escrow to the player or the bankroll, and on a player win, the house's
+/// matching stake, less the house edge, from the bankroll. The bankroll
+/// never pays more than the bet reserved when the game was created.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HouseSettlement {
+    pub escrow_to_player: u64,
+    pub escrow_to_bankroll: u64,
+    pub bankroll_to_player: u64,
+}
+
+impl HouseSettlement {
+    pub fn new(outcome: HouseOutcome, bet_amount: u64, house_edge_bps: u16) -> Self {
+        match outcome {
+            HouseOutcome::PlayerWins => Self {
+                escrow_to_player: bet_amount,
+                escrow_to_bankroll: 0,
+                bankroll_to_player: bet_amount - morra_core::bps_of(bet_amount, house_edge_bps),
+            },
+            HouseOutcome::HouseWins => Self {
+                escrow_to_player: 0,
+                escrow_to_bankroll: bet_amount,
+                bankroll_to_player: 0,
+            },
+            HouseOutcome::Draw => Self {
+                escrow_to_player: bet_amount,
+                escrow_to_bankroll: 0,
+                bankroll_to_player: 0,
+            },
+        }
+    }
+
+    /// Make the transfers and release the game's exposure.
+    pub fn pay<'info>(
+        &self,
+        bankroll: &mut Account<'info, Bankroll>,
+        game: &Account<'info, HouseGame>,
+        escrow: &AccountInfo<'info>,
+        escrow_bump: u8,
+        player: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        require!(
+            self.bankroll_to_player <= game.bet_amount,
+            GameError::ExposureLimit
+        );
+        bankroll.open_exposure -= game.bet_amount;
+
+        let game_key = game.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            GAME_SEED,
+            game_key.as_ref(),
+            &[escrow_bump],
+        ]];
+        let bankroll_info = bankroll.to_account_info();
+        let transfers = [
+            (player, self.escrow_to_player),
+            (&bankroll_info, self.escrow_to_bankroll),
+        ];
+        for (to, amount) in transfers {
+            if amount == 0 {
+                continue;
+            }
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: escrow.clone(),
+                        to: to.clone(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+        Bankroll::pay(bankroll, player, self.bankroll_to_player)
+    }
+}
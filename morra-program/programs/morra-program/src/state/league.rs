@@ -0,0 +1,163 @@
+use std::cmp::Ordering;
+
+use anchor_lang::prelude::*;
+use morra_core::Outcome;
+
+use crate::error::GameError;
+
+/// Round-robin league over a fixed roster. Every member pays the entry fee
+/// into the league's vault; once the last one joins, every pairing is
+/// scheduled as a fixture, and at season end the pot goes to whoever tops
+/// the standings, split evenly on a tie.
+///
+/// Seeded `[LEAGUE_SEED, organizer, id]`; the vault is
+/// `[LEAGUE_SEED, league]`.
+#[account]
+pub struct League {
+    pub organizer: Pubkey,
+    pub id: u64,
+    pub bump: u8,
+    pub entry_fee: u64,
+    pub season_ends_at: i64,
+    pub status: LeagueStatus,
+    /// One row per roster member, in roster order.
+    pub standings: Vec<Standing>,
+    /// Every pairing, in the order `morra_core::schedule` lays them out.
+    /// Empty until the season starts.
+    pub fixtures: Vec<Fixture>,
+    pub pot: u64,
+    /// Bit `i` is set once roster member `i` has claimed their share.
+    pub prizes_claimed: u8,
+}
+
+impl League {
+    pub const MAX_PLAYERS: usize = 8;
+    pub const MAX_FIXTURES: usize = Self::MAX_PLAYERS * (Self::MAX_PLAYERS - 1) / 2;
+
+    pub const LEN: usize = 32 + // organizer
+        8 + // id
+        1 + // bump
+        8 + // entry_fee
+        8 + // season_ends_at
+        1 + // status
+        4 + Standing::LEN * Self::MAX_PLAYERS + // standings
+        4 + Fixture::LEN * Self::MAX_FIXTURES + // fixtures
+        8 + // pot
+        1; // prizes_claimed
+
+    pub fn roster_index(&self, player: &Pubkey) -> Result<usize> {
+        self.standings
+            .iter()
+            .position(|standing| standing.player == *player)
+            .ok_or_else(|| GameError::NotOnRoster.into())
+    }
+
+    pub fn all_joined(&self) -> bool {
+        self.standings.iter().all(|standing| standing.joined)
+    }
+
+    /// Schedule every pairing and open the season.
+    pub fn start(&mut self) {
+        let mut pairs = [(0, 0); Self::MAX_FIXTURES];
+        let count = morra_core::schedule(self.standings.len(), &mut pairs);
+        self.fixtures = pairs[..count]
+            .iter()
+            .map(|&(home, away)| Fixture {
+                players: [home, away],
+                game: None,
+                played: false,
+            })
+            .collect();
+        self.status = LeagueStatus::InProgress;
+    }
+
+    /// Score fixture `index` and mark it played.
+    pub fn record(&mut self, index: usize, outcome: Outcome) -> Result<()> {
+        let fixture = self.fixtures.get_mut(index).ok_or(GameError::InvalidMatch)?;
+        require!(!fixture.played, GameError::InvalidMatch);
+        fixture.played = true;
+        fixture.game = None;
+
+        let [home, away] = fixture.players.map(usize::from);
+        let (home_points, away_points) = morra_core::fixture_points(outcome);
+        self.standings[home].record(home_points, away_points);
+        self.standings[away].record(away_points, home_points);
+        Ok(())
+    }
+
+    pub fn season_complete(&self) -> bool {
+        self.fixtures.iter().all(|fixture| fixture.played)
+    }
+
+    /// Whether roster member `index` finished level on the most points.
+    pub fn is_leader(&self, index: usize) -> bool {
+        let top = self.standings.iter().map(|standing| standing.points).max();
+        Some(self.standings[index].points) == top
+    }
+
+    /// Roster member `index`'s share of the pot, if they topped the table.
+    /// The first leader in roster order also takes the rounding dust.
+    pub fn prize(&self, index: usize) -> Option<u64> {
+        if !self.is_leader(index) {
+            return None;
+        }
+        let leaders = (0..self.standings.len()).filter(|&i| self.is_leader(i));
+        let first = leaders.clone().next()?;
+        let (share, dust) = morra_core::pot_share(self.pot, leaders.count() as u64);
+        Some(if index == first { share + dust } else { share })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeagueStatus {
+    Registration,
+    InProgress,
+    Completed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Standing {
+    pub player: Pubkey,
+    /// Whether this member has paid the entry fee.
+    pub joined: bool,
+    pub played: u8,
+    pub wins: u8,
+    pub draws: u8,
+    pub losses: u8,
+    pub points: u16,
+}
+
+impl Standing {
+    pub const LEN: usize = 32 + // player
+        1 + // joined
+        1 + // played
+        1 + // wins
+        1 + // draws
+        1 + // losses
+        2; // points
+
+    fn record(&mut self, points: u16, opponent_points: u16) {
+        self.played += 1;
+        match points.cmp(&opponent_points) {
+            Ordering::Greater => self.wins += 1,
+            Ordering::Equal => self.draws += 1,
+            Ordering::Less => self.losses += 1,
+        }
+        self.points += points;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fixture {
+    /// Roster indexes of the creator and opponent seats.
+    pub players: [u8; 2],
+    /// The game being played for this fixture, until it is recorded.
+    pub game: Option<Pubkey>,
+    pub played: bool,
+}
+
+impl Fixture {
+    pub const LEN: usize = 2 + // players
+        (1 + 32) + // game
+        1; // played
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use std::ops::RangeInclusive;
+
+use crate::error::GameError;
+
+/// One page of open games for a single mode, so clients can list joinable
+/// tables with a read or two instead of scanning every game account.
+///
+/// Pages are seeded `[LOBBY_SEED, [ranked], index]`. A game stays on the
+/// page it was created into until it is joined or cancelled.
+#[account]
+pub struct LobbyPage {
+    pub ranked: bool,
+    pub index: u32,
+    pub bump: u8,
+    pub games: Vec<LobbyEntry>,
+}
+
+impl LobbyPage {
+    pub const CAPACITY: usize = 32;
+
+    pub const LEN: usize = 1 + // ranked
+        4 + // index
+        1 + // bump
+        4 + LobbyEntry::LEN * Self::CAPACITY; // games
+
+    pub fn is_full(&self) -> bool {
+        self.games.len() >= Self::CAPACITY
+    }
+
+    pub fn register(&mut self, entry: LobbyEntry) -> Result<()> {
+        require!(!self.is_full(), GameError::LobbyFull);
+        self.games.push(entry);
+        Ok(())
+    }
+
+    /// Drop `game` from the page. Order isn't preserved.
+    pub fn remove(&mut self, game: &Pubkey) -> Result<()> {
+        let position = self
+            .games
+            .iter()
+            .position(|entry| entry.game == *game)
+            .ok_or(GameError::InvalidLobby)?;
+        self.games.swap_remove(position);
+        Ok(())
+    }
+
+    /// Open games whose bet falls within `bets`.
+    pub fn open_games(&self, bets: RangeInclusive<u64>) -> impl Iterator<Item = &LobbyEntry> {
+        self.games
+            .iter()
+            .filter(move |entry| bets.contains(&entry.bet_amount))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LobbyEntry {
+    pub game: Pubkey,
+    pub creator: Pubkey,
+    pub bet_amount: u64,
+    pub created_at: i64,
+}
+
+impl LobbyEntry {
+    pub const LEN: usize = 32 + 32 + 8 + 8;
+}
@@ -0,0 +1,23 @@
+pub mod bankroll;
+pub mod config;
+pub mod game;
+pub mod house;
+pub mod league;
+pub mod lobby;
+pub mod profile;
+pub mod queue;
+pub mod session;
+pub mod side_bet;
+pub mod tournament;
+
+pub use bankroll::*;
+pub use config::*;
+pub use game::*;
+pub use house::*;
+pub use league::*;
+pub use lobby::*;
+pub use profile::*;
+pub use queue::*;
+pub use session::*;
+pub use side_bet::*;
+pub use tournament::*;
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use morra_core::{Outcome, INITIAL_RATING};
+
+use crate::state::HouseOutcome;
+
+/// Lifetime stats for one wallet, updated by the program whenever one of
+/// its games resolves.
+#[account]
+pub struct PlayerProfile {
+    pub player: Pubkey,
+    pub games_played: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub draws: u64,
+    pub total_wagered: u64,
+    pub net_winnings: i64,
+    pub forfeits: u64,
+    /// Consecutive wins when positive, consecutive losses when negative.
+    pub current_streak: i32,
+    /// Elo rating, only moved by ranked games.
+    pub rating: u32,
+    /// Id the next game this player creates will be seeded with.
+    pub next_game_id: u64,
+    pub bump: u8,
+}
+
+impl PlayerProfile {
+    pub const LEN: usize = 32 + // player
+        8 + // games_played
+        8 + // wins
+        8 + // losses
+        8 + // draws
+        8 + // total_wagered
+        8 + // net_winnings
+        8 + // forfeits
+        4 + // current_streak
+        4 + // rating
+        8 + // next_game_id
+        1; // bump
+
+    pub fn new(player: Pubkey, bump: u8) -> Self {
+        Self {
+            player,
+            games_played: 0,
+            wins: 0,
+            losses: 0,
+            draws: 0,
+            total_wagered: 0,
+            net_winnings: 0,
+            forfeits: 0,
+            current_streak: 0,
+            rating: INITIAL_RATING,
+            next_game_id: 0,
+            bump,
+        }
+    }
+
+    /// Claim the id for a new game this player creates.
+    pub fn take_game_id(&mut self) -> u64 {
+        let id = self.next_game_id;
+        self.next_game_id += 1;
+        id
+    }
+
+    /// Record a resolved game on both players' profiles.
+    pub fn record_result(
+        creator: &mut PlayerProfile,
+        opponent: &mut PlayerProfile,
+        outcome: Outcome,
+        bet: u64,
+    ) {
+        match outcome {
+            Outcome::CreatorWins => {
+                creator.record_win(bet);
+                opponent.record_loss(bet);
+            }
+            Outcome::OpponentWins => {
+                creator.record_loss(bet);
+                opponent.record_win(bet);
+            }
+            Outcome::Draw => {
+                creator.record_draw(bet);
+                opponent.record_draw(bet);
+            }
+        }
+    }
+
+    /// Move both players' ratings for a ranked game.
+    pub fn update_ratings(
+        creator: &mut PlayerProfile,
+        opponent: &mut PlayerProfile,
+        outcome: Outcome,
+    ) {
+        (creator.rating, opponent.rating) =
+            morra_core::update_ratings(creator.rating, opponent.rating, outcome);
+    }
+
+    pub fn record_win(&mut self, bet: u64) {
+        self.record_game(bet);
+        self.wins = self.wins.saturating_add(1);
+        self.net_winnings = self.net_winnings.saturating_add_unsigned(bet);
+        self.current_streak = self.current_streak.max(0).saturating_add(1);
+    }
+
+    pub fn record_loss(&mut self, bet: u64) {
+        self.record_game(bet);
+        self.losses = self.losses.saturating_add(1);
+        self.net_winnings = self.net_winnings.saturating_sub_unsigned(bet);
+        self.current_streak = self.current_streak.min(0).saturating_sub(1);
+    }
+
+    pub fn record_draw(&mut self, bet: u64) {
+        self.record_game(bet);
+        self.draws = self.draws.saturating_add(1);
+        self.current_streak = 0;
+    }
+
+    /// Record a settled house game from the player's seat; the house keeps
+    /// no profile.
+    pub fn record_house_result(&mut self, outcome: HouseOutcome, bet: u64) {
+        match outcome {
+            HouseOutcome::PlayerWins => self.record_win(bet),
+            HouseOutcome::HouseWins => self.record_loss(bet),
+            HouseOutcome::Draw => self.record_draw(bet),
+        }
+    }
+
+    /// A forfeit counts as a loss and is also tallied on its own.
+    pub fn record_forfeit(&mut self, bet: u64) {
+        self.record_loss(bet);
+        self.forfeits = self.forfeits.saturating_add(1);
+    }
+
+    fn record_game(&mut self, bet: u64) {
+        self.games_played = self.games_played.saturating_add(1);
+        self.total_wagered = self.total_wagered.saturating_add(bet);
+    }
+}
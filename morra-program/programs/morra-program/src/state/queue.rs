@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GameError;
+
+/// Quick-play queue for one bet tier. Entries are kept oldest first and
+/// their stakes sit in the queue's vault until they are paired or leave.
+///
+/// Seeded `[QUEUE_SEED, [tier]]`; the vault is `[QUEUE_SEED, queue]`.
+#[account]
+pub struct MatchQueue {
+    pub tier: u8,
+    pub bet_amount: u64,
+    pub bump: u8,
+    pub entries: Vec<QueueEntry>,
+}
+
+impl MatchQueue {
+    pub const CAPACITY: usize = 16;
+
+    pub const LEN: usize = 1 + // tier
+        8 + // bet_amount
+        1 + // bump
+        4 + QueueEntry::LEN * Self::CAPACITY; // entries
+
+    pub fn enqueue(&mut self, entry: QueueEntry) -> Result<()> {
+        require!(
+            self.entries.iter().all(|queued| queued.player != entry.player),
+            GameError::AlreadyQueued
+        );
+        require!(self.entries.len() < Self::CAPACITY, GameError::QueueFull);
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Take `player`'s entry out of the queue, keeping everyone else's order.
+    pub fn remove(&mut self, player: &Pubkey) -> Result<QueueEntry> {
+        let position = self
+            .entries
+            .iter()
+            .position(|entry| entry.player == *player)
+            .ok_or(GameError::NotQueued)?;
+        Ok(self.entries.remove(position))
+    }
+
+    /// The two oldest entries that can play each other, oldest first.
+    pub fn next_pair(&self) -> Option<(QueueEntry, QueueEntry)> {
+        let (older, newer) = morra_core::oldest_compatible_pair(&self.entries, |a, b| {
+            a.player != b.player && a.ranked == b.ranked
+        })?;
+        Some((self.entries[older], self.entries[newer]))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueueEntry {
+    pub player: Pubkey,
+    /// Bound to the queue's address rather than a game's, since the game
+    /// doesn't exist until the entry is paired.
+    pub commitment: [u8; 32],
+    pub ranked: bool,
+    pub enqueued_at: i64,
+}
+
+impl QueueEntry {
+    pub const LEN: usize = 32 + 32 + 1 + 8;
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GameError;
+use crate::state::*;
+
+/// An ephemeral key a player has authorized to commit and reveal for them
+/// until `expires_at`, so best-of-N play doesn't prompt the main wallet for
+/// every move. Anything that moves funds still requires the main key.
+///
+/// `scope` is either a single game, or a tournament or league, in which
+/// case the key covers whichever game its bracket match or fixture is
+/// currently being played in.
+///
+/// Seeded `[SESSION_SEED, player, session_key]`.
+#[account]
+pub struct SessionKey {
+    pub player: Pubkey,
+    pub session_key: Pubkey,
+    pub scope: Pubkey,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl SessionKey {
+    pub const LEN: usize = 32 + // player
+        32 + // session_key
+        32 + // scope
+        8 + // expires_at
+        1; // bump
+
+    /// The player `signer` moves for in `game` under this session. `scope`
+    /// is the session's tournament or league account, when it isn't scoped
+    /// to the game itself.
+    pub fn authorize(
+        &self,
+        signer: &Pubkey,
+        game: &Pubkey,
+        scope: Option<&AccountInfo>,
+        now: i64,
+    ) -> Result<Pubkey> {
+        require_keys_eq!(self.session_key, *signer, GameError::InvalidSession);
+        require!(now < self.expires_at, GameError::SessionExpired);
+        require!(self.covers(game, scope)?, GameError::InvalidSession);
+        Ok(self.player)
+    }
+
+    fn covers(&self, game: &Pubkey, scope: Option<&AccountInfo>) -> Result<bool> {
+        if self.scope == *game {
+            return Ok(true);
+        }
+        let Some(scope) = scope.filter(|scope| *scope.key == self.scope) else {
+            return Ok(false);
+        };
+        if *scope.owner != crate::ID {
+            return Ok(false);
+        }
+        let data = scope.try_borrow_data()?;
+        if let Ok(tournament) = Tournament::try_deserialize(&mut &data[..]) {
+            return Ok(tournament
+                .matches
+                .iter()
+                .any(|bracket_match| bracket_match.game == Some(*game)));
+        }
+        if let Ok(league) = League::try_deserialize(&mut &data[..]) {
+            return Ok(league
+                .fixtures
+                .iter()
+                .any(|fixture| fixture.game == Some(*game)));
+        }
+        Ok(false)
+    }
+}
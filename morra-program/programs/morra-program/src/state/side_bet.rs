@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use morra_core::Outcome;
+
+use crate::error::GameError;
+use crate::state::*;
+
+/// Spectator side bets on one game, settled parimutuel: everything staked
+/// is split across the backers of the actual result in proportion to their
+/// stakes. If nobody backed the result or the game is cancelled, every bet
+/// is refunded instead.
+///
+/// Seeded `[SIDE_BET_SEED, game]`; the vault is `[SIDE_BET_SEED, pool]`.
+#[account]
+pub struct SideBetPool {
+    pub game: Pubkey,
+    pub bump: u8,
+    /// Total staked on each side, indexed by `BetSide`.
+    pub totals: [u64; 3],
+    pub status: SideBetPoolStatus,
+    pub winning_side: Option<BetSide>,
+    /// Winning stake that has claimed so far, and what it was paid.
+    pub claimed_stake: u64,
+    pub paid_out: u64,
+}
+
+impl SideBetPool {
+    pub const LEN: usize = 32 + // game
+        1 + // bump
+        8 * 3 + // totals
+        1 + // status
+        (1 + 1) + // winning_side
+        8 + // claimed_stake
+        8; // paid_out
+
+    /// Bets are taken until the game is under way: once the opponent has
+    /// joined and made a move, the players know more than the bettors.
+    pub fn betting_open(game: &Game) -> bool {
+        matches!(
+            game.status,
+            GameStatus::WaitingForOpponent | GameStatus::WaitingForCommits
+        )
+    }
+
+    pub fn total(&self) -> u64 {
+        self.totals.iter().sum()
+    }
+
+    /// Fix the pool's result from its game, the first time anyone claims.
+    /// A game past its own timeout takes no more moves, so it is taken as
+    /// ending the way the crank will leave it, and claiming early can't turn
+    /// a forfeit win into a refund.
+    pub fn settle(&mut self, game: &Game, now: i64) -> Result<()> {
+        if self.status != SideBetPoolStatus::Open {
+            return Ok(());
+        }
+        let mut game = game.clone();
+        if game.timed_out(now) {
+            match game.status {
+                GameStatus::WaitingForOpponent => game.transition(
+                    GameStatus::WaitingForOpponent,
+                    GameStatus::Cancelled,
+                )?,
+                _ => game.forfeit()?,
+            }
+        }
+        if let Some(outcome) = game.outcome() {
+            let side = BetSide::from(outcome);
+            if self.totals[side as usize] == 0 {
+                self.status = SideBetPoolStatus::Refunding;
+            } else {
+                self.status = SideBetPoolStatus::Settled;
+                self.winning_side = Some(side);
+            }
+        } else if game.status == GameStatus::Cancelled {
+            self.status = SideBetPoolStatus::Refunding;
+        } else {
+            return err!(GameError::InvalidGameState);
+        }
+        Ok(())
+    }
+
+    /// Lamports owed for `bet` once the pool has settled. Losing bets are
+    /// owed nothing.
+    pub fn claim(&mut self, bet: &SideBet) -> Result<u64> {
+        match self.status {
+            SideBetPoolStatus::Open => err!(GameError::InvalidGameState),
+            SideBetPoolStatus::Refunding => Ok(bet.amount),
+            SideBetPoolStatus::Settled if self.winning_side == Some(bet.side) => {
+                let amount = morra_core::parimutuel_claim(
+                    bet.amount,
+                    self.totals[bet.side as usize],
+                    self.total(),
+                    self.claimed_stake,
+                    self.paid_out,
+                );
+                self.claimed_stake += bet.amount;
+                self.paid_out += amount;
+                Ok(amount)
+            }
+            SideBetPoolStatus::Settled => Ok(0),
+        }
+    }
+}
+
+/// One spectator's stake in a pool.
+///
+/// Seeded `[SIDE_BET_SEED, pool, bettor]`.
+#[account]
+pub struct SideBet {
+    pub pool: Pubkey,
+    pub bettor: Pubkey,
+    pub side: BetSide,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl SideBet {
+    pub const LEN: usize = 32 + // pool
+        32 + // bettor
+        1 + // side
+        8 + // amount
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SideBetPoolStatus {
+    Open,
+    Settled,
+    Refunding,
+}
+
+/// The result a side bet backs.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BetSide {
+    Creator,
+    Opponent,
+    Draw,
+}
+
+impl From<Outcome> for BetSide {
+    fn from(outcome: Outcome) -> Self {
+        match outcome {
+            Outcome::CreatorWins => BetSide::Creator,
+            Outcome::OpponentWins => BetSide::Opponent,
+            Outcome::Draw => BetSide::Draw,
+        }
+    }
+}
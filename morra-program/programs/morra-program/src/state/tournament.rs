@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GameError;
+
+/// Single-elimination tournament. Entry fees pool in the tournament's vault
+/// and are paid out by placement once the final is decided.
+///
+/// Seeded `[TOURNAMENT_SEED, organizer, id]`; the vault is
+/// `[TOURNAMENT_SEED, tournament]`.
+#[account]
+pub struct Tournament {
+    pub organizer: Pubkey,
+    pub id: u64,
+    pub bump: u8,
+    pub entry_fee: u64,
+    pub max_players: u8,
+    pub registration_ends_at: i64,
+    pub status: TournamentStatus,
+    /// Registered players, in registration order until the tournament
+    /// starts and in seed order from then on.
+    pub players: Vec<Pubkey>,
+    /// The bracket, laid out as `morra_core::bracket` describes. Empty until
+    /// the tournament starts, then sized to the smallest bracket that fits
+    /// everyone who registered.
+    pub matches: Vec<BracketMatch>,
+    pub prize_pool: u64,
+    /// Bit `p` is set once placement `p` has claimed its prize.
+    pub prizes_claimed: u8,
+}
+
+impl Tournament {
+    pub const MAX_PLAYERS: usize = 16;
+
+    pub const LEN: usize = 32 + // organizer
+        8 + // id
+        1 + // bump
+        8 + // entry_fee
+        1 + // max_players
+        8 + // registration_ends_at
+        1 + // status
+        4 + 32 * Self::MAX_PLAYERS + // players
+        4 + BracketMatch::LEN * (Self::MAX_PLAYERS - 1) + // matches
+        8 + // prize_pool
+        1; // prizes_claimed
+
+    pub fn register(&mut self, player: Pubkey) -> Result<()> {
+        require!(
+            !self.players.contains(&player),
+            GameError::AlreadyRegistered
+        );
+        require!(
+            self.players.len() < usize::from(self.max_players),
+            GameError::TournamentFull
+        );
+        self.players.push(player);
+        Ok(())
+    }
+
+    pub fn unregister(&mut self, player: &Pubkey) -> Result<()> {
+        let position = self
+            .players
+            .iter()
+            .position(|registered| registered == player)
+            .ok_or(GameError::NotRegistered)?;
+        self.players.remove(position);
+        Ok(())
+    }
+
+    fn bracket_size(&self) -> usize {
+        self.matches.len() + 1
+    }
+
+    /// Lay out the bracket with `seeded[0]` as the top seed. There are more
+    /// players than half the bracket, so every first-round match has at
+    /// least one of them and byes only ever occur in that round.
+    pub fn start(&mut self, seeded: &[Pubkey]) -> Result<()> {
+        require!(seeded.len() >= 2, GameError::NotEnoughPlayers);
+        let size = seeded.len().next_power_of_two();
+        let mut leaves = [0; Self::MAX_PLAYERS];
+        morra_core::seed_leaves(size, &mut leaves);
+
+        self.players = seeded.to_vec();
+        self.matches = vec![BracketMatch::default(); morra_core::match_count(size)];
+        for index in 0..size / 2 {
+            let seat = |leaf: usize| seeded.get(usize::from(leaves[leaf])).copied();
+            self.matches[index].players = [seat(2 * index), seat(2 * index + 1)];
+        }
+        self.status = TournamentStatus::InProgress;
+        for index in 0..size / 2 {
+            if let [Some(player), None] | [None, Some(player)] = self.matches[index].players {
+                self.advance(index, player)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Record `winner` for match `index` and seat them in the next round,
+    /// completing the tournament if that was the final.
+    pub fn advance(&mut self, index: usize, winner: Pubkey) -> Result<()> {
+        let bracket_match = self.matches.get_mut(index).ok_or(GameError::InvalidMatch)?;
+        require!(bracket_match.winner.is_none(), GameError::InvalidMatch);
+        bracket_match.winner = Some(winner);
+        bracket_match.game = None;
+
+        match morra_core::next_match(self.bracket_size(), index) {
+            Some((next, seat)) => self.matches[next].players[seat] = Some(winner),
+            None => self.status = TournamentStatus::Completed,
+        }
+        Ok(())
+    }
+
+    /// The better-seeded of match `index`'s players.
+    pub fn higher_seed(&self, index: usize) -> Option<Pubkey> {
+        let bracket_match = self.matches.get(index)?;
+        let seed = |player: &Pubkey| self.players.iter().position(|seeded| seeded == player);
+        bracket_match.players.iter().flatten().copied().min_by_key(seed)
+    }
+
+    /// Which prize placement `player` finished in: 0 for the champion, 1 for
+    /// the runner-up, 2 and 3 for the losing semifinalists.
+    pub fn placement(&self, player: &Pubkey) -> Option<usize> {
+        (0..4).find(|&place| self.placement_holder(place) == Some(*player))
+    }
+
+    /// Prize for each placement, with the shares of placements nobody holds
+    /// folded into the champion's.
+    pub fn prizes(&self) -> [u64; 4] {
+        let present = [0, 1, 2, 3].map(|place| self.placement_holder(place).is_some());
+        morra_core::placement_prizes(self.prize_pool, present)
+    }
+
+    fn placement_holder(&self, place: usize) -> Option<Pubkey> {
+        let last = self.matches.len().checked_sub(1)?;
+        match place {
+            0 => self.matches[last].winner,
+            1 => self.matches[last].loser(),
+            // The two semifinals sit just before the final.
+            2 | 3 if last >= 2 => self.matches[last + place - 4].loser(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TournamentStatus {
+    Registration,
+    InProgress,
+    Completed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BracketMatch {
+    pub players: [Option<Pubkey>; 2],
+    /// The game currently being played for this match. Cleared when the
+    /// match is decided, or when a drawn game needs a rematch.
+    pub game: Option<Pubkey>,
+    pub winner: Option<Pubkey>,
+    /// Games for this match cancelled because neither player moved.
+    pub no_shows: u8,
+}
+
+impl BracketMatch {
+    pub const LEN: usize = (1 + 32) * 2 + // players
+        (1 + 32) + // game
+        (1 + 32) + // winner
+        1; // no_shows
+
+    /// The player knocked out by this match, unless it was a bye.
+    pub fn loser(&self) -> Option<Pubkey> {
+        let winner = self.winner?;
+        match self.players {
+            [Some(a), Some(b)] => Some(if a == winner { b } else { a }),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,277 @@
+mod common;
+
+use common::{
+    assert_game_error, commitment, house_address, Harness, Player, BET, STARTING_BALANCE,
+};
+use morra_core::{redemption_value, shares_for_deposit, Move, VIRTUAL_SHARES};
+use morra_program::{GameError, HouseOutcome, WITHDRAWAL_COOLDOWN, WITHDRAWAL_WINDOW};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+const SALT: [u8; 32] = [9; 32];
+
+/// A harness with the house and an empty bankroll. The harness creator
+/// plays the house.
+async fn pool_harness(max_exposure_bps: u16, house_edge_bps: u16) -> (Harness, Keypair) {
+    let mut harness = Harness::new(Player::new(2, 5, 1), Player::new(3, 7, 2)).await;
+    let operator = Keypair::new();
+    harness.initialize_config(0).await.unwrap();
+    harness.initialize_house(operator.pubkey(), BET).await.unwrap();
+    harness
+        .initialize_bankroll(max_exposure_bps, house_edge_bps)
+        .await
+        .unwrap();
+    (harness, operator)
+}
+
+fn house_commitment(game: &Pubkey, card: u8, prediction: u8) -> [u8; 32] {
+    commitment(&house_address(), game, Move::new(card, prediction), &SALT)
+}
+
+/// A funded provider with an open position, and their balance once it's
+/// open.
+async fn provider(harness: &mut Harness) -> (Keypair, u64) {
+    let provider = Keypair::new();
+    harness.fund(provider.pubkey(), STARTING_BALANCE).await;
+    harness.create_lp_position(&provider).await.unwrap();
+    let balance = harness.balance(provider.pubkey()).await;
+    (provider, balance)
+}
+
+/// Play and settle one `BET` house game the player wins or loses.
+async fn settle(harness: &mut Harness, operator: &Keypair, player_wins: bool) -> Pubkey {
+    // Total 5, only the player calls it; or total 7, only the house does.
+    let (house, player) = if player_wins {
+        ((3, 7), (2, 5))
+    } else {
+        ((5, 7), (2, 9))
+    };
+    let player_key = harness.creator.keypair.insecure_clone();
+    let game = harness.create_house_game(&player_key, BET).await.unwrap();
+    let commitment = house_commitment(&game, house.0, house.1);
+    harness.house_commit(game, operator, commitment).await.unwrap();
+    harness
+        .play_house_game(game, &player_key, player.0, player.1)
+        .await
+        .unwrap();
+    harness
+        .house_reveal(game, operator, house.0, house.1, SALT)
+        .await
+        .unwrap();
+    game
+}
+
+async fn withdraw_all(harness: &mut Harness, provider: &Keypair) {
+    let shares = harness.lp_position(provider.pubkey()).await.shares;
+    harness.request_withdrawal(provider, shares).await.unwrap();
+    let now = harness.now().await;
+    harness.warp_to(now + WITHDRAWAL_COOLDOWN).await;
+    harness.withdraw_bankroll(provider).await.unwrap();
+}
+
+#[tokio::test]
+async fn shares_follow_the_pool_and_lamports_are_conserved() {
+    let (mut harness, operator) = pool_harness(5_000, 1_000).await;
+    let (first, first_start) = provider(&mut harness).await;
+    let (second, second_start) = provider(&mut harness).await;
+    let player = harness.creator.pubkey();
+
+    harness.deposit_bankroll(&first, 4 * BET).await.unwrap();
+    let first_shares = 4 * BET * VIRTUAL_SHARES;
+    assert_eq!(harness.lp_position(first.pubkey()).await.shares, first_shares);
+
+    // The player wins and is paid the bet less the 10% edge.
+    let before = harness.balance(player).await;
+    let won = settle(&mut harness, &operator, true).await;
+    assert_eq!(
+        harness.house_game(won).await.outcome,
+        Some(HouseOutcome::PlayerWins)
+    );
+    // Net of the rent the player put into the game account.
+    let rent = harness.balance(won).await;
+    assert_eq!(harness.balance(player).await, before - rent + BET - BET / 10);
+    assert_eq!(harness.bankroll_assets().await, 4 * BET - BET + BET / 10);
+
+    // A later deposit buys in at the lower price, and an odd amount rounds
+    // in the pool's favour.
+    let deposit = 3 * BET + 7;
+    harness.deposit_bankroll(&second, deposit).await.unwrap();
+    let minted = shares_for_deposit(deposit, first_shares, 3 * BET + BET / 10).unwrap();
+    assert_eq!(harness.lp_position(second.pubkey()).await.shares, minted);
+    assert!(minted > 3 * BET * VIRTUAL_SHARES);
+    assert_eq!(harness.bankroll().await.total_shares, first_shares + minted);
+
+    // The house wins the next one.
+    settle(&mut harness, &operator, false).await;
+
+    withdraw_all(&mut harness, &first).await;
+    withdraw_all(&mut harness, &second).await;
+    let bankroll = harness.bankroll().await;
+    assert_eq!((bankroll.total_shares, bankroll.open_exposure), (0, 0));
+    // Only the virtual stake's rounding dust is left behind.
+    let dust = harness.bankroll_assets().await;
+    assert!(dust <= 2);
+
+    // Providers took out what they put in, plus the player's stake the
+    // house won, less what it paid on the player's win.
+    let first_gain = harness.balance(first.pubkey()).await as i128 - first_start as i128;
+    let second_gain = harness.balance(second.pubkey()).await as i128 - second_start as i128;
+    assert_eq!(
+        first_gain + second_gain + i128::from(dust),
+        i128::from(BET / 10)
+    );
+    // Each provider's share of that follows when they bought in.
+    assert!(first_gain < 0 && second_gain > 0);
+}
+
+#[tokio::test]
+async fn withdrawals_wait_out_the_cooldown() {
+    let (mut harness, operator) = pool_harness(5_000, 0).await;
+    let (provider, start) = provider(&mut harness).await;
+    harness.deposit_bankroll(&provider, 4 * BET).await.unwrap();
+
+    assert_game_error(
+        harness.withdraw_bankroll(&provider).await,
+        GameError::InvalidShareAmount,
+    );
+    assert_game_error(
+        harness
+            .request_withdrawal(&provider, 4 * BET * VIRTUAL_SHARES + 1)
+            .await,
+        GameError::InvalidShareAmount,
+    );
+    harness
+        .request_withdrawal(&provider, 2 * BET * VIRTUAL_SHARES)
+        .await
+        .unwrap();
+    let requested_at = harness.lp_position(provider.pubkey()).await.requested_at;
+    harness
+        .warp_to(requested_at + WITHDRAWAL_COOLDOWN - 1)
+        .await;
+    assert_game_error(
+        harness.withdraw_bankroll(&provider).await,
+        GameError::CooldownNotElapsed,
+    );
+
+    // Queued shares still carry the pool's losses until they're redeemed.
+    settle(&mut harness, &operator, true).await;
+    harness.warp_to(requested_at + WITHDRAWAL_COOLDOWN).await;
+    harness.withdraw_bankroll(&provider).await.unwrap();
+    let shares = 4 * BET * VIRTUAL_SHARES;
+    let paid = redemption_value(shares / 2, shares, 3 * BET);
+    assert_eq!(harness.balance(provider.pubkey()).await, start - 4 * BET + paid);
+    let position = harness.lp_position(provider.pubkey()).await;
+    assert_eq!((position.shares, position.pending_shares), (shares / 2, 0));
+    assert_eq!(harness.bankroll_assets().await, 3 * BET - paid);
+
+    // A request left unredeemed past its window lapses.
+    harness.request_withdrawal(&provider, BET).await.unwrap();
+    let requested_at = harness.lp_position(provider.pubkey()).await.requested_at;
+    harness
+        .warp_to(requested_at + WITHDRAWAL_COOLDOWN + WITHDRAWAL_WINDOW)
+        .await;
+    assert_game_error(
+        harness.withdraw_bankroll(&provider).await,
+        GameError::WithdrawalExpired,
+    );
+
+    // A new request restarts the clock, and zero shares cancels it.
+    harness.request_withdrawal(&provider, BET).await.unwrap();
+    harness.request_withdrawal(&provider, 0).await.unwrap();
+    let now = harness.now().await;
+    harness.warp_to(now + WITHDRAWAL_COOLDOWN).await;
+    assert_game_error(
+        harness.withdraw_bankroll(&provider).await,
+        GameError::InvalidShareAmount,
+    );
+}
+
+#[tokio::test]
+async fn rates_are_capped_and_set_by_the_authority() {
+    let (mut harness, operator) = pool_harness(1_000, 0).await;
+    let (provider, _) = provider(&mut harness).await;
+    harness.deposit_bankroll(&provider, 5 * BET).await.unwrap();
+    let player = harness.creator.keypair.insecure_clone();
+
+    // 10% of 5 SOL doesn't cover a 1 SOL bet.
+    assert_game_error(
+        harness.create_house_game(&player, BET).await.map(|_| ()),
+        GameError::ExposureLimit,
+    );
+    let authority = harness.context.payer.insecure_clone();
+    assert_game_error(
+        harness.update_bankroll(&authority, 10_001, 0).await,
+        GameError::InvalidRate,
+    );
+    assert_game_error(
+        harness.update_bankroll(&player, 2_000, 0).await,
+        GameError::Unauthorized,
+    );
+    harness.update_bankroll(&authority, 2_000, 0).await.unwrap();
+
+    // A game keeps the edge it was created under.
+    let game = harness.create_house_game(&player, BET).await.unwrap();
+    let commitment = house_commitment(&game, 3, 7);
+    harness.update_bankroll(&authority, 2_000, 5_000).await.unwrap();
+    harness.house_commit(game, &operator, commitment).await.unwrap();
+    harness.play_house_game(game, &player, 2, 5).await.unwrap();
+    let before = harness.balance(player.pubkey()).await;
+    harness
+        .house_reveal(game, &operator, 3, 7, SALT)
+        .await
+        .unwrap();
+    assert_eq!(harness.balance(player.pubkey()).await, before + 2 * BET);
+    assert_eq!(harness.bankroll().await.house_edge_bps, 5_000);
+}
+
+#[tokio::test]
+async fn withdrawals_are_valued_at_total_assets() {
+    let (mut harness, operator) = pool_harness(5_000, 0).await;
+    let (leaver, leaver_start) = provider(&mut harness).await;
+    let (stayer, stayer_start) = provider(&mut harness).await;
+    harness.deposit_bankroll(&leaver, 4 * BET).await.unwrap();
+    harness.deposit_bankroll(&stayer, 4 * BET).await.unwrap();
+
+    // Both file early and wait out the cooldown.
+    let shares = harness.lp_position(leaver.pubkey()).await.shares;
+    harness.request_withdrawal(&leaver, shares).await.unwrap();
+    harness.request_withdrawal(&stayer, shares).await.unwrap();
+    let now = harness.now().await;
+    harness.warp_to(now + WITHDRAWAL_COOLDOWN).await;
+
+    // The player calls the total, so the bankroll pays on the reveal.
+    let player = harness.creator.keypair.insecure_clone();
+    let game = harness.create_house_game(&player, BET).await.unwrap();
+    let commitment = house_commitment(&game, 3, 7);
+    harness.house_commit(game, &operator, commitment).await.unwrap();
+    harness.play_house_game(game, &player, 2, 5).await.unwrap();
+    assert_eq!(harness.bankroll().await.open_exposure, BET);
+
+    // Half the shares are worth half of everything, exposure included.
+    harness.withdraw_bankroll(&leaver).await.unwrap();
+    let withdrawn = harness.balance(leaver.pubkey()).await - (leaver_start - 4 * BET);
+    assert_eq!(withdrawn, redemption_value(shares, 2 * shares, 8 * BET));
+
+    // The rest is worth more than the bet leaves unreserved, so it waits.
+    assert_game_error(
+        harness.withdraw_bankroll(&stayer).await,
+        GameError::ExposureLimit,
+    );
+
+    harness
+        .house_reveal(game, &operator, 3, 7, SALT)
+        .await
+        .unwrap();
+    assert_eq!(harness.bankroll().await.open_exposure, 0);
+
+    // Settled, the remaining provider redeems whatever is left.
+    harness.request_withdrawal(&stayer, shares).await.unwrap();
+    let now = harness.now().await;
+    harness.warp_to(now + WITHDRAWAL_COOLDOWN).await;
+    let assets = harness.bankroll_assets().await;
+    assert_eq!(assets, 7 * BET - withdrawn);
+    harness.withdraw_bankroll(&stayer).await.unwrap();
+    let redeemed = harness.balance(stayer.pubkey()).await - (stayer_start - 4 * BET);
+    assert_eq!(redeemed, redemption_value(shares, shares, assets));
+}
@@ -0,0 +1,1589 @@
+#![allow(dead_code)]
+
+use anchor_lang::prelude::{AccountMeta, Clock};
+use anchor_lang::solana_program::instruction::{Instruction, InstructionError};
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use morra_core::Move;
+use morra_program::{
+    Bankroll, BetSide, Config, Game, GameError, House, HouseGame, League, LobbyPage,
+    LpPosition, MatchQueue, PlayerProfile, SideBetPool, Tournament,
+};
+use solana_keypair::Keypair;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use solana_transaction_error::TransactionError;
+
+pub const BET: u64 = 1_000_000_000;
+pub const STARTING_BALANCE: u64 = 10_000_000_000;
+
+// Moves for a game spawned with both seats filled: the creator's win, the
+// opponent's win, or both call the total and draw.
+pub const CREATOR_WINS: [(u8, u8); 2] = [(3, 5), (2, 7)];
+pub const OPPONENT_WINS: [(u8, u8); 2] = [(4, 6), (4, 8)];
+pub const DRAW: [(u8, u8); 2] = [(2, 5), (3, 5)];
+
+// Anchor's generated `entry` ties the account slice to the `AccountInfo`
+// lifetime, which the builtin processor signature can't express.
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[anchor_lang::prelude::AccountInfo],
+    data: &[u8],
+) -> anchor_lang::solana_program::entrypoint::ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    morra_program::entry(program_id, accounts, data)
+}
+
+#[derive(Debug)]
+pub struct Player {
+    pub keypair: Keypair,
+    pub card: u8,
+    pub prediction: u8,
+    pub salt: [u8; 32],
+}
+
+impl Player {
+    pub fn new(card: u8, prediction: u8, salt: u8) -> Self {
+        Self {
+            keypair: Keypair::new(),
+            card,
+            prediction,
+            salt: [salt; 32],
+        }
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        self.keypair.pubkey()
+    }
+
+    /// The player's commitment in the game, or queue, at `scope`.
+    pub fn commitment(&self, scope: &Pubkey) -> [u8; 32] {
+        commitment(
+            &self.pubkey(),
+            scope,
+            Move::new(self.card, self.prediction),
+            &self.salt,
+        )
+    }
+}
+
+pub struct Harness {
+    pub context: ProgramTestContext,
+    pub creator: Player,
+    pub opponent: Player,
+    pub game: Pubkey,
+    pub game_account: Pubkey,
+    pub bet: u64,
+    pub ranked: bool,
+    /// Lobby page `create_game` registers into and `join_game` and
+    /// `cancel_game` remove from.
+    pub lobby: Pubkey,
+    /// Session accounts passed with every commit and reveal, when set.
+    pub session: Option<SessionAccounts>,
+}
+
+/// The session account signing moves for a player, and the tournament or
+/// league it is scoped to, if it isn't scoped to a single game.
+#[derive(Clone, Copy)]
+pub struct SessionAccounts {
+    pub session: Pubkey,
+    pub scope: Option<Pubkey>,
+}
+
+impl Harness {
+    pub async fn new(creator: Player, opponent: Player) -> Self {
+        Self::with_bet(creator, opponent, BET).await
+    }
+
+    pub async fn with_bet(creator: Player, opponent: Player, bet: u64) -> Self {
+        let mut program_test = ProgramTest::new(
+            "morra_program",
+            morra_program::ID,
+            processor!(process_instruction),
+        );
+        for player in [&creator, &opponent] {
+            program_test.add_account(
+                player.pubkey(),
+                solana_account::Account::new(STARTING_BALANCE, 0, &system_program::ID),
+            );
+        }
+        let context = program_test.start_with_context().await;
+
+        // The creator's first game.
+        let game = game_address(&creator.pubkey(), 0);
+        let game_account = escrow_address(&game);
+
+        let mut harness = Self {
+            context,
+            creator,
+            opponent,
+            game,
+            game_account,
+            bet,
+            ranked: false,
+            lobby: lobby_address(false, 0),
+            session: None,
+        };
+        for ranked in [false, true] {
+            harness.create_lobby_page(ranked, 0).await.unwrap();
+        }
+        for player in [
+            harness.creator.keypair.insecure_clone(),
+            harness.opponent.keypair.insecure_clone(),
+        ] {
+            harness.create_profile(&player).await.unwrap();
+        }
+        harness
+    }
+
+    pub async fn process(
+        &mut self,
+        instruction: Instruction,
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+        let mut keypairs = vec![&self.context.payer];
+        keypairs.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.context.payer.pubkey()),
+            &keypairs,
+            blockhash,
+        );
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    /// Switch the harness to ranked games on the first ranked lobby page.
+    pub fn set_ranked(&mut self, ranked: bool) {
+        self.ranked = ranked;
+        self.lobby = lobby_address(ranked, 0);
+    }
+
+    pub async fn create_lobby_page(
+        &mut self,
+        ranked: bool,
+        index: u32,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CreateLobbyPage {
+                lobby: lobby_address(ranked, index),
+                payer: self.context.payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CreateLobbyPage { ranked, index }.data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    /// Create `player`'s profile, with the harness payer covering rent.
+    pub async fn create_profile(&mut self, player: &Keypair) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CreateProfile {
+                profile: profile_address(&player.pubkey()),
+                player: player.pubkey(),
+                payer: self.context.payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CreateProfile {}.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    pub async fn create_game(&mut self) -> Result<(), BanksClientError> {
+        let creator = self.creator.keypair.insecure_clone();
+        self.create_game_sponsored(&creator, 0).await
+    }
+
+    /// Create the harness game with `payer` covering its rent, owed
+    /// `reimbursement` if the creator wins.
+    pub async fn create_game_sponsored(
+        &mut self,
+        payer: &Keypair,
+        reimbursement: u64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CreateGame {
+                game: self.game,
+                creator: self.creator.pubkey(),
+                payer: payer.pubkey(),
+                creator_profile: profile_address(&self.creator.pubkey()),
+                lobby: self.lobby,
+                game_account: self.game_account,
+                system_program: system_program::ID,
+                rent: anchor_lang::solana_program::sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CreateGame {
+                bet_amount: self.bet,
+                commitment: self.creator.commitment(&self.game),
+                ranked: self.ranked,
+                reimbursement,
+            }
+            .data(),
+        };
+        let signer = self.creator.keypair.insecure_clone();
+        self.process(instruction, &[&signer, payer]).await
+    }
+
+    pub async fn join_game_as(
+        &mut self,
+        opponent: &Keypair,
+        commitment: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        self.join_game_sponsored(opponent, commitment, opponent, 0).await
+    }
+
+    pub async fn join_game_sponsored(
+        &mut self,
+        opponent: &Keypair,
+        commitment: [u8; 32],
+        payer: &Keypair,
+        reimbursement: u64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::JoinGame {
+                game: self.game,
+                opponent: opponent.pubkey(),
+                payer: payer.pubkey(),
+                opponent_profile: profile_address(&opponent.pubkey()),
+                lobby: self.lobby,
+                game_account: self.game_account,
+                system_program: system_program::ID,
+                side_bet_pool: side_bet_pool_address(&self.game),
+                opponent_side_bet: side_bet_address(
+                    &side_bet_pool_address(&self.game),
+                    &opponent.pubkey(),
+                ),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::JoinGame {
+                commitment,
+                reimbursement,
+            }
+            .data(),
+        };
+        self.process(instruction, &[opponent, payer]).await
+    }
+
+    pub async fn join_game(&mut self) -> Result<(), BanksClientError> {
+        let opponent = self.opponent.keypair.insecure_clone();
+        let commitment = self.opponent.commitment(&self.game);
+        self.join_game_as(&opponent, commitment).await
+    }
+
+    pub async fn cancel_game(&mut self, creator: &Keypair) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CancelGame {
+                game: self.game,
+                creator: creator.pubkey(),
+                lobby: self.lobby,
+                game_account: self.game_account,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CancelGame {}.data(),
+        };
+        self.process(instruction, &[creator]).await
+    }
+
+    pub async fn create_match_queue(&mut self, tier: u8) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CreateMatchQueue {
+                queue: queue_address(tier),
+                payer: self.context.payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CreateMatchQueue { tier }.data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn enqueue(
+        &mut self,
+        tier: u8,
+        player: &Keypair,
+        commitment: [u8; 32],
+        ranked: bool,
+    ) -> Result<(), BanksClientError> {
+        let queue = queue_address(tier);
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::Enqueue {
+                player: player.pubkey(),
+                profile: profile_address(&player.pubkey()),
+                queue,
+                queue_vault: escrow_address_for(b"queue", &queue),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::Enqueue { commitment, ranked }.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    pub async fn leave_queue(
+        &mut self,
+        tier: u8,
+        player: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let queue = queue_address(tier);
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::LeaveQueue {
+                player: player.pubkey(),
+                queue,
+                queue_vault: escrow_address_for(b"queue", &queue),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::LeaveQueue {}.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    /// Pair `creator` and `opponent` out of the tier's queue into the
+    /// creator's next game, and return that game's address.
+    pub async fn pair_match(
+        &mut self,
+        tier: u8,
+        creator: Pubkey,
+        opponent: Pubkey,
+    ) -> Result<Pubkey, BanksClientError> {
+        let queue = queue_address(tier);
+        let game = game_address(&creator, self.profile(creator).await.next_game_id);
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::PairMatch {
+                payer: self.context.payer.pubkey(),
+                queue,
+                queue_vault: escrow_address_for(b"queue", &queue),
+                creator_profile: profile_address(&creator),
+                opponent_profile: profile_address(&opponent),
+                game,
+                game_account: escrow_address(&game),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::PairMatch {}.data(),
+        };
+        self.process(instruction, &[]).await.map(|()| game)
+    }
+
+    pub async fn commit_move(
+        &mut self,
+        game: Pubkey,
+        player: &Keypair,
+        commitment: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CommitMove {
+                game,
+                player: player.pubkey(),
+                session: self.session.map(|session| session.session),
+                session_scope: self.session.and_then(|session| session.scope),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CommitMove { commitment }.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    pub async fn reveal_move(
+        &mut self,
+        player: &Keypair,
+        card: u8,
+        prediction: u8,
+        salt: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let (game, creator, opponent) =
+            (self.game, self.creator.pubkey(), self.opponent.pubkey());
+        self.reveal_move_in(game, creator, opponent, player, card, prediction, salt)
+            .await
+    }
+
+    /// Reveal in any game, given the addresses of its two players.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reveal_move_in(
+        &mut self,
+        game: Pubkey,
+        creator: Pubkey,
+        opponent: Pubkey,
+        player: &Keypair,
+        card: u8,
+        prediction: u8,
+        salt: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::RevealMove {
+                game,
+                player: player.pubkey(),
+                creator_profile: profile_address(&creator),
+                opponent_profile: profile_address(&opponent),
+                session: self.session.map(|session| session.session),
+                session_scope: self.session.and_then(|session| session.scope),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::RevealMove {
+                card,
+                prediction,
+                salt,
+            }
+            .data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    pub async fn reveal_creator(&mut self) -> Result<(), BanksClientError> {
+        let keypair = self.creator.keypair.insecure_clone();
+        let (card, prediction, salt) =
+            (self.creator.card, self.creator.prediction, self.creator.salt);
+        self.reveal_move(&keypair, card, prediction, salt).await
+    }
+
+    pub async fn reveal_opponent(&mut self) -> Result<(), BanksClientError> {
+        let keypair = self.opponent.keypair.insecure_clone();
+        let (card, prediction, salt) = (
+            self.opponent.card,
+            self.opponent.prediction,
+            self.opponent.salt,
+        );
+        self.reveal_move(&keypair, card, prediction, salt).await
+    }
+
+    pub async fn claim_winnings(&mut self, winner: &Keypair) -> Result<(), BanksClientError> {
+        self.claim_winnings_with(winner, None).await
+    }
+
+    /// Claim with the winner's sponsor account, if they have one.
+    pub async fn claim_winnings_with(
+        &mut self,
+        winner: &Keypair,
+        sponsor: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::ClaimWinnings {
+                game: self.game,
+                winner: winner.pubkey(),
+                game_account: self.game_account,
+                sponsor,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::ClaimWinnings {}.data(),
+        };
+        self.process(instruction, &[winner]).await
+    }
+
+    pub async fn refund_draw(&mut self) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::RefundDraw {
+                game: self.game,
+                creator: self.creator.pubkey(),
+                opponent: self.opponent.pubkey(),
+                game_account: self.game_account,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::RefundDraw {}.data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn create_session(
+        &mut self,
+        player: &Keypair,
+        session_key: Pubkey,
+        scope: Pubkey,
+        expires_at: i64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CreateSession {
+                player: player.pubkey(),
+                session: session_address(&player.pubkey(), &session_key),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CreateSession {
+                session_key,
+                scope,
+                expires_at,
+            }
+            .data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    pub async fn revoke_session(
+        &mut self,
+        player: &Keypair,
+        session_key: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::RevokeSession {
+                player: player.pubkey(),
+                session: session_address(&player.pubkey(), &session_key),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::RevokeSession {}.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    /// Create a tournament organized by the harness payer.
+    pub async fn create_tournament(
+        &mut self,
+        id: u64,
+        entry_fee: u64,
+        max_players: u8,
+        registration_ends_at: i64,
+    ) -> Result<(), BanksClientError> {
+        let organizer = self.context.payer.pubkey();
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CreateTournament {
+                organizer,
+                tournament: tournament_address(&organizer, id),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CreateTournament {
+                id,
+                entry_fee,
+                max_players,
+                registration_ends_at,
+            }
+            .data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn register_tournament(
+        &mut self,
+        tournament: Pubkey,
+        player: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::RegisterTournament {
+                player: player.pubkey(),
+                profile: profile_address(&player.pubkey()),
+                tournament,
+                prize_vault: escrow_address_for(b"tournament", &tournament),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::RegisterTournament {}.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    pub async fn leave_tournament(
+        &mut self,
+        tournament: Pubkey,
+        player: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::LeaveTournament {
+                player: player.pubkey(),
+                tournament,
+                prize_vault: escrow_address_for(b"tournament", &tournament),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::LeaveTournament {}.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    /// Start `tournament`, passing its registered players' profiles.
+    pub async fn start_tournament(&mut self, tournament: Pubkey) -> Result<(), BanksClientError> {
+        let players = self.tournament(tournament).await.players;
+        let mut accounts =
+            morra_program::accounts::StartTournament { tournament }.to_account_metas(None);
+        accounts.extend(
+            players
+                .iter()
+                .map(|player| AccountMeta::new_readonly(profile_address(player), false)),
+        );
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts,
+            data: morra_program::instruction::StartTournament {}.data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    /// Spawn the game for bracket match `match_index` and return its address.
+    pub async fn spawn_match_game(
+        &mut self,
+        tournament: Pubkey,
+        match_index: u8,
+    ) -> Result<Pubkey, BanksClientError> {
+        let state = self.tournament(tournament).await;
+        let creator = state.matches[usize::from(match_index)].players[0]
+            .expect("match has a first seat");
+        let game = game_address(&creator, self.profile(creator).await.next_game_id);
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::SpawnMatchGame {
+                payer: self.context.payer.pubkey(),
+                tournament,
+                creator_profile: profile_address(&creator),
+                game,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::SpawnMatchGame { match_index }.data(),
+        };
+        self.process(instruction, &[]).await.map(|()| game)
+    }
+
+    pub async fn advance_tournament(
+        &mut self,
+        tournament: Pubkey,
+        game: Pubkey,
+        match_index: u8,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::AdvanceTournament { tournament, game }
+                .to_account_metas(None),
+            data: morra_program::instruction::AdvanceTournament { match_index }.data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn claim_tournament_prize(
+        &mut self,
+        tournament: Pubkey,
+        player: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::ClaimTournamentPrize {
+                player: player.pubkey(),
+                tournament,
+                prize_vault: escrow_address_for(b"tournament", &tournament),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::ClaimTournamentPrize {}.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    /// Create a league organized by the harness payer.
+    pub async fn create_league(
+        &mut self,
+        id: u64,
+        entry_fee: u64,
+        roster: Vec<Pubkey>,
+        season_ends_at: i64,
+    ) -> Result<(), BanksClientError> {
+        let organizer = self.context.payer.pubkey();
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CreateLeague {
+                organizer,
+                league: league_address(&organizer, id),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CreateLeague {
+                id,
+                entry_fee,
+                roster,
+                season_ends_at,
+            }
+            .data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn join_league(
+        &mut self,
+        league: Pubkey,
+        player: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::JoinLeague {
+                player: player.pubkey(),
+                profile: profile_address(&player.pubkey()),
+                league,
+                league_vault: escrow_address_for(b"league", &league),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::JoinLeague {}.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    pub async fn leave_league(
+        &mut self,
+        league: Pubkey,
+        player: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::LeaveLeague {
+                player: player.pubkey(),
+                league,
+                league_vault: escrow_address_for(b"league", &league),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::LeaveLeague {}.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    /// Spawn the game for fixture `fixture_index` and return its address.
+    pub async fn spawn_fixture_game(
+        &mut self,
+        league: Pubkey,
+        fixture_index: u8,
+    ) -> Result<Pubkey, BanksClientError> {
+        let state = self.league(league).await;
+        let home = state.fixtures[usize::from(fixture_index)].players[0];
+        let creator = state.standings[usize::from(home)].player;
+        let game = game_address(&creator, self.profile(creator).await.next_game_id);
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::SpawnFixtureGame {
+                payer: self.context.payer.pubkey(),
+                league,
+                creator_profile: profile_address(&creator),
+                game,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::SpawnFixtureGame { fixture_index }.data(),
+        };
+        self.process(instruction, &[]).await.map(|()| game)
+    }
+
+    pub async fn record_fixture(
+        &mut self,
+        league: Pubkey,
+        game: Pubkey,
+        fixture_index: u8,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::RecordFixture { league, game }
+                .to_account_metas(None),
+            data: morra_program::instruction::RecordFixture { fixture_index }.data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn settle_league(&mut self, league: Pubkey) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::SettleLeague { league }.to_account_metas(None),
+            data: morra_program::instruction::SettleLeague {}.data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn claim_league_prize(
+        &mut self,
+        league: Pubkey,
+        player: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::ClaimLeaguePrize {
+                player: player.pubkey(),
+                league,
+                league_vault: escrow_address_for(b"league", &league),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::ClaimLeaguePrize {}.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    pub async fn create_side_bet_pool(&mut self, game: Pubkey) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CreateSideBetPool {
+                payer: self.context.payer.pubkey(),
+                game,
+                pool: side_bet_pool_address(&game),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CreateSideBetPool {}.data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn place_side_bet(
+        &mut self,
+        game: Pubkey,
+        bettor: &Keypair,
+        side: BetSide,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let pool = side_bet_pool_address(&game);
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::PlaceSideBet {
+                bettor: bettor.pubkey(),
+                game,
+                pool,
+                bet: side_bet_address(&pool, &bettor.pubkey()),
+                pool_vault: escrow_address_for(b"side_bet", &pool),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::PlaceSideBet { side, amount }.data(),
+        };
+        self.process(instruction, &[bettor]).await
+    }
+
+    pub async fn claim_side_bet(
+        &mut self,
+        game: Pubkey,
+        bettor: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let pool = side_bet_pool_address(&game);
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::ClaimSideBet {
+                bettor: bettor.pubkey(),
+                game,
+                pool,
+                bet: side_bet_address(&pool, &bettor.pubkey()),
+                pool_vault: escrow_address_for(b"side_bet", &pool),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::ClaimSideBet {}.data(),
+        };
+        self.process(instruction, &[bettor]).await
+    }
+
+    /// Create the program config with the harness payer as its authority.
+    pub async fn initialize_config(&mut self, crank_reward: u64) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::InitializeConfig {
+                authority: self.context.payer.pubkey(),
+                config: config_address(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::InitializeConfig { crank_reward }.data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn update_config(
+        &mut self,
+        authority: &Keypair,
+        crank_reward: u64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::UpdateConfig {
+                authority: authority.pubkey(),
+                config: config_address(),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::UpdateConfig { crank_reward }.data(),
+        };
+        self.process(instruction, &[authority]).await
+    }
+
+    /// Expire `game` as `cranker`, passing the config when `rewarded`.
+    pub async fn expire_game(
+        &mut self,
+        game: Pubkey,
+        cranker: &Keypair,
+        rewarded: bool,
+    ) -> Result<(), BanksClientError> {
+        let state = self.game_at(game).await;
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::ExpireGame {
+                game,
+                creator: state.creator,
+                lobby: lobby_address(state.ranked, state.lobby_page),
+                game_account: escrow_address(&game),
+                config: rewarded.then(config_address),
+                cranker: cranker.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::ExpireGame {}.data(),
+        };
+        self.process(instruction, &[cranker]).await
+    }
+
+    /// Forfeit `game` as `cranker`, passing the config when `rewarded`.
+    pub async fn forfeit_game(
+        &mut self,
+        game: Pubkey,
+        cranker: &Keypair,
+        rewarded: bool,
+    ) -> Result<(), BanksClientError> {
+        let state = self.game_at(game).await;
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::ForfeitGame {
+                game,
+                creator: state.creator,
+                opponent: state.opponent,
+                creator_profile: profile_address(&state.creator),
+                opponent_profile: profile_address(&state.opponent),
+                game_account: escrow_address(&game),
+                config: rewarded.then(config_address),
+                cranker: cranker.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::ForfeitGame {}.data(),
+        };
+        self.process(instruction, &[cranker]).await
+    }
+
+    /// Set up the house under the config authority, the harness payer.
+    pub async fn initialize_house(
+        &mut self,
+        operator: Pubkey,
+        bond: u64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::InitializeHouse {
+                authority: self.context.payer.pubkey(),
+                config: config_address(),
+                house: house_address(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::InitializeHouse { operator, bond }.data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn fund_house_bond(
+        &mut self,
+        authority: &Keypair,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::FundHouseBond {
+                authority: authority.pubkey(),
+                house: house_address(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::FundHouseBond { amount }.data(),
+        };
+        self.process(instruction, &[authority]).await
+    }
+
+    pub async fn withdraw_house_bond(
+        &mut self,
+        authority: &Keypair,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::WithdrawHouseBond {
+                authority: authority.pubkey(),
+                house: house_address(),
+                bankroll: bankroll_address(),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::WithdrawHouseBond { amount }.data(),
+        };
+        self.process(instruction, &[authority]).await
+    }
+
+    pub async fn set_house_operator(
+        &mut self,
+        authority: &Keypair,
+        operator: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::SetHouseOperator {
+                authority: authority.pubkey(),
+                house: house_address(),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::SetHouseOperator { operator }.data(),
+        };
+        self.process(instruction, &[authority]).await
+    }
+
+    /// Create the bankroll under the config authority, the harness payer.
+    pub async fn initialize_bankroll(
+        &mut self,
+        max_exposure_bps: u16,
+        house_edge_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::InitializeBankroll {
+                authority: self.context.payer.pubkey(),
+                config: config_address(),
+                bankroll: bankroll_address(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::InitializeBankroll {
+                max_exposure_bps,
+                house_edge_bps,
+            }
+            .data(),
+        };
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn update_bankroll(
+        &mut self,
+        authority: &Keypair,
+        max_exposure_bps: u16,
+        house_edge_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::UpdateBankroll {
+                authority: authority.pubkey(),
+                bankroll: bankroll_address(),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::UpdateBankroll {
+                max_exposure_bps,
+                house_edge_bps,
+            }
+            .data(),
+        };
+        self.process(instruction, &[authority]).await
+    }
+
+    pub async fn create_lp_position(&mut self, owner: &Keypair) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CreateLpPosition {
+                owner: owner.pubkey(),
+                position: lp_address(&owner.pubkey()),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CreateLpPosition {}.data(),
+        };
+        self.process(instruction, &[owner]).await
+    }
+
+    pub async fn deposit_bankroll(
+        &mut self,
+        owner: &Keypair,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::DepositBankroll {
+                owner: owner.pubkey(),
+                position: lp_address(&owner.pubkey()),
+                bankroll: bankroll_address(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::DepositBankroll { amount }.data(),
+        };
+        self.process(instruction, &[owner]).await
+    }
+
+    pub async fn request_withdrawal(
+        &mut self,
+        owner: &Keypair,
+        shares: u64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::RequestWithdrawal {
+                owner: owner.pubkey(),
+                position: lp_address(&owner.pubkey()),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::RequestWithdrawal { shares }.data(),
+        };
+        self.process(instruction, &[owner]).await
+    }
+
+    pub async fn withdraw_bankroll(&mut self, owner: &Keypair) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::WithdrawBankroll {
+                owner: owner.pubkey(),
+                position: lp_address(&owner.pubkey()),
+                bankroll: bankroll_address(),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::WithdrawBankroll {}.data(),
+        };
+        self.process(instruction, &[owner]).await
+    }
+
+    /// Stake `bet` against the house as `player`, returning the game's
+    /// address.
+    pub async fn create_house_game(
+        &mut self,
+        player: &Keypair,
+        bet_amount: u64,
+    ) -> Result<Pubkey, BanksClientError> {
+        let game_id = self.profile(player.pubkey()).await.next_game_id;
+        let house_game = house_game_address(&player.pubkey(), game_id);
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::CreateHouseGame {
+                player: player.pubkey(),
+                player_profile: profile_address(&player.pubkey()),
+                house: house_address(),
+                house_game,
+                game_account: escrow_address(&house_game),
+                bankroll: bankroll_address(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::CreateHouseGame { bet_amount }.data(),
+        };
+        self.process(instruction, &[player]).await?;
+        Ok(house_game)
+    }
+
+    pub async fn house_commit(
+        &mut self,
+        house_game: Pubkey,
+        operator: &Keypair,
+        commitment: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::HouseCommit {
+                house_game,
+                house: house_address(),
+                operator: operator.pubkey(),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::HouseCommit { commitment }.data(),
+        };
+        self.process(instruction, &[operator]).await
+    }
+
+    pub async fn play_house_game(
+        &mut self,
+        house_game: Pubkey,
+        player: &Keypair,
+        card: u8,
+        prediction: u8,
+    ) -> Result<(), BanksClientError> {
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::PlayHouseGame {
+                house_game,
+                player: player.pubkey(),
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::PlayHouseGame { card, prediction }.data(),
+        };
+        self.process(instruction, &[player]).await
+    }
+
+    pub async fn house_reveal(
+        &mut self,
+        house_game: Pubkey,
+        operator: &Keypair,
+        card: u8,
+        prediction: u8,
+        salt: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let player = self.house_game(house_game).await.player;
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::HouseReveal {
+                house_game,
+                house: house_address(),
+                operator: operator.pubkey(),
+                player,
+                player_profile: profile_address(&player),
+                game_account: escrow_address(&house_game),
+                bankroll: bankroll_address(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::HouseReveal {
+                card,
+                prediction,
+                salt,
+            }
+            .data(),
+        };
+        self.process(instruction, &[operator]).await
+    }
+
+    pub async fn forfeit_house_game(
+        &mut self,
+        house_game: Pubkey,
+        cranker: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let player = self.house_game(house_game).await.player;
+        let instruction = Instruction {
+            program_id: morra_program::ID,
+            accounts: morra_program::accounts::ForfeitHouseGame {
+                house_game,
+                house: house_address(),
+                player,
+                player_profile: profile_address(&player),
+                game_account: escrow_address(&house_game),
+                bankroll: bankroll_address(),
+                cranker: cranker.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: morra_program::instruction::ForfeitHouseGame {}.data(),
+        };
+        self.process(instruction, &[cranker]).await
+    }
+
+    /// Commit and reveal `moves` for the creator and opponent of a game
+    /// spawned waiting for commits, salting each with its card.
+    pub async fn play_seated_game(
+        &mut self,
+        game: Pubkey,
+        seats: [&Keypair; 2],
+        moves: [(u8, u8); 2],
+    ) {
+        for (seat, (card, prediction)) in seats.into_iter().zip(moves) {
+            let commitment =
+                commitment(&seat.pubkey(), &game, Move::new(card, prediction), &[card; 32]);
+            self.commit_move(game, seat, commitment).await.unwrap();
+        }
+        let [creator, opponent] = seats.map(|seat| seat.pubkey());
+        for (seat, (card, prediction)) in seats.into_iter().zip(moves) {
+            self.reveal_move_in(game, creator, opponent, seat, card, prediction, [card; 32])
+                .await
+                .unwrap();
+        }
+    }
+
+    /// Send `lamports` from the harness payer to `to`.
+    pub async fn fund(&mut self, to: Pubkey, lamports: u64) {
+        let instruction = anchor_lang::solana_program::system_instruction::transfer(
+            &self.context.payer.pubkey(),
+            &to,
+            lamports,
+        );
+        self.process(instruction, &[]).await.unwrap();
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        let clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp
+    }
+
+    /// Move the bank's clock to `unix_timestamp`.
+    pub async fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        self.context.set_sysvar(&clock);
+    }
+
+    pub async fn play_to_resolution(&mut self) {
+        self.create_game().await.unwrap();
+        self.join_game().await.unwrap();
+        self.reveal_creator().await.unwrap();
+        self.reveal_opponent().await.unwrap();
+    }
+
+    pub async fn balance(&mut self, address: Pubkey) -> u64 {
+        self.context.banks_client.get_balance(address).await.unwrap()
+    }
+
+    pub async fn game_rent(&mut self) -> u64 {
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        rent.minimum_balance(8 + Game::LEN)
+    }
+
+    pub async fn profile(&mut self, player: Pubkey) -> PlayerProfile {
+        let account = self
+            .context
+            .banks_client
+            .get_account(profile_address(&player))
+            .await
+            .unwrap()
+            .expect("profile account exists");
+        PlayerProfile::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn lobby_page(&mut self, address: Pubkey) -> LobbyPage {
+        let account = self
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .expect("lobby page exists");
+        LobbyPage::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn match_queue(&mut self, tier: u8) -> MatchQueue {
+        let account = self
+            .context
+            .banks_client
+            .get_account(queue_address(tier))
+            .await
+            .unwrap()
+            .expect("match queue exists");
+        MatchQueue::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn side_bet_pool(&mut self, game: Pubkey) -> SideBetPool {
+        let account = self
+            .context
+            .banks_client
+            .get_account(side_bet_pool_address(&game))
+            .await
+            .unwrap()
+            .expect("side-bet pool exists");
+        SideBetPool::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn league(&mut self, address: Pubkey) -> League {
+        let account = self
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .expect("league exists");
+        League::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn tournament(&mut self, address: Pubkey) -> Tournament {
+        let account = self
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .expect("tournament exists");
+        Tournament::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn config(&mut self) -> Config {
+        let account = self
+            .context
+            .banks_client
+            .get_account(config_address())
+            .await
+            .unwrap()
+            .expect("config exists");
+        Config::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn house(&mut self) -> House {
+        let account = self
+            .context
+            .banks_client
+            .get_account(house_address())
+            .await
+            .unwrap()
+            .expect("house exists");
+        House::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn bankroll(&mut self) -> Bankroll {
+        let account = self
+            .context
+            .banks_client
+            .get_account(bankroll_address())
+            .await
+            .unwrap()
+            .expect("bankroll exists");
+        Bankroll::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    /// The bankroll's lamports above its rent-exempt minimum.
+    pub async fn bankroll_assets(&mut self) -> u64 {
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let reserve = rent.minimum_balance(8 + Bankroll::LEN);
+        self.balance(bankroll_address()).await - reserve
+    }
+
+    pub async fn lp_position(&mut self, owner: Pubkey) -> LpPosition {
+        let account = self
+            .context
+            .banks_client
+            .get_account(lp_address(&owner))
+            .await
+            .unwrap()
+            .expect("lp position exists");
+        LpPosition::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn house_game(&mut self, address: Pubkey) -> HouseGame {
+        let account = self
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .expect("house game exists");
+        HouseGame::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn game_state(&mut self) -> Game {
+        self.game_at(self.game).await
+    }
+
+    pub async fn game_at(&mut self, address: Pubkey) -> Game {
+        let account = self
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .expect("game account exists");
+        Game::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+}
+
+/// `player`'s commitment to `mv` in the game, or queue, at `scope`. Tests
+/// commit off-chain with morra-core so every reveal checks it against the
+/// program's syscall hash of the same encoding.
+pub fn commitment(player: &Pubkey, scope: &Pubkey, mv: Move, salt: &[u8; 32]) -> [u8; 32] {
+    morra_core::commitment(&player.to_bytes(), &scope.to_bytes(), mv, salt)
+}
+
+pub fn profile_address(player: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"profile", player.as_ref()], &morra_program::ID).0
+}
+
+pub fn game_address(creator: &Pubkey, game_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"game", creator.as_ref(), &game_id.to_le_bytes()],
+        &morra_program::ID,
+    )
+    .0
+}
+
+pub fn escrow_address(game: &Pubkey) -> Pubkey {
+    escrow_address_for(b"game", game)
+}
+
+/// The system-owned vault PDA holding lamports on behalf of `owner`.
+pub fn escrow_address_for(seed: &[u8], owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[seed, owner.as_ref()], &morra_program::ID).0
+}
+
+pub fn queue_address(tier: u8) -> Pubkey {
+    Pubkey::find_program_address(&[b"queue", &[tier]], &morra_program::ID).0
+}
+
+pub fn tournament_address(organizer: &Pubkey, id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"tournament", organizer.as_ref(), &id.to_le_bytes()],
+        &morra_program::ID,
+    )
+    .0
+}
+
+pub fn league_address(organizer: &Pubkey, id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"league", organizer.as_ref(), &id.to_le_bytes()],
+        &morra_program::ID,
+    )
+    .0
+}
+
+pub fn side_bet_pool_address(game: &Pubkey) -> Pubkey {
+    escrow_address_for(b"side_bet", game)
+}
+
+pub fn side_bet_address(pool: &Pubkey, bettor: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"side_bet", pool.as_ref(), bettor.as_ref()],
+        &morra_program::ID,
+    )
+    .0
+}
+
+pub fn session_address(player: &Pubkey, session_key: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"session", player.as_ref(), session_key.as_ref()],
+        &morra_program::ID,
+    )
+    .0
+}
+
+pub fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"config"], &morra_program::ID).0
+}
+
+pub fn house_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"house"], &morra_program::ID).0
+}
+
+pub fn bankroll_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"bankroll"], &morra_program::ID).0
+}
+
+pub fn lp_address(owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"lp", owner.as_ref()], &morra_program::ID).0
+}
+
+pub fn house_game_address(player: &Pubkey, game_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"house", player.as_ref(), &game_id.to_le_bytes()],
+        &morra_program::ID,
+    )
+    .0
+}
+
+pub fn lobby_address(ranked: bool, index: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"lobby", &[u8::from(ranked)], &index.to_le_bytes()],
+        &morra_program::ID,
+    )
+    .0
+}
+
+pub fn assert_game_error(result: Result<(), BanksClientError>, expected: GameError) {
+    let err = result.expect_err("instruction should fail");
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(expected.into())),
+    );
+}
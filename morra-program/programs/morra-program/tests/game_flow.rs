@@ -0,0 +1,314 @@
+mod common;
+
+use common::{assert_game_error, Harness, Player, BET, STARTING_BALANCE};
+use morra_program::{GameError, GameStatus, MAX_BET, MIN_BET};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+#[tokio::test]
+async fn create_game_escrows_creator_bet() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.pubkey();
+    let rent = harness.game_rent().await;
+
+    harness.create_game().await.unwrap();
+
+    assert_eq!(harness.balance(creator).await, STARTING_BALANCE - BET - rent);
+    assert_eq!(harness.balance(harness.game_account).await, BET);
+    let game = harness.game_state().await;
+    assert_eq!(game.creator, creator);
+    assert_eq!(game.bet_amount, BET);
+    assert!(game.status == GameStatus::WaitingForOpponent);
+}
+
+#[tokio::test]
+async fn join_game_escrows_opponent_bet() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let opponent = harness.opponent.keypair.insecure_clone();
+    harness.create_game().await.unwrap();
+
+    harness.join_game().await.unwrap();
+
+    assert_eq!(
+        harness.balance(opponent.pubkey()).await,
+        STARTING_BALANCE - BET
+    );
+    assert_eq!(harness.balance(harness.game_account).await, 2 * BET);
+    let game = harness.game_state().await;
+    assert_eq!(game.opponent, opponent.pubkey());
+    assert!(game.status == GameStatus::WaitingForReveal);
+}
+
+#[tokio::test]
+async fn creator_wins_and_claims_pot() {
+    // 3 + 2 = 5: only the creator predicted the total.
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    let rent = harness.game_rent().await;
+    harness.play_to_resolution().await;
+
+    let game = harness.game_state().await;
+    assert!(game.status == GameStatus::Completed);
+    assert_eq!(game.winner, Some(creator.pubkey()));
+    assert_eq!(harness.balance(harness.game_account).await, 2 * BET);
+
+    harness.claim_winnings(&creator).await.unwrap();
+
+    assert_eq!(
+        harness.balance(creator.pubkey()).await,
+        STARTING_BALANCE + BET - rent
+    );
+    assert_eq!(
+        harness.balance(harness.opponent.pubkey()).await,
+        STARTING_BALANCE - BET
+    );
+    assert_eq!(harness.balance(harness.game_account).await, 0);
+    assert!(harness.game_state().await.status == GameStatus::Settled);
+
+    assert_game_error(
+        harness.claim_winnings(&creator).await,
+        GameError::InvalidGameState,
+    );
+}
+
+#[tokio::test]
+async fn opponent_wins_and_claims_pot() {
+    // 4 + 4 = 8: only the opponent predicted the total.
+    let mut harness = Harness::new(Player::new(4, 6, 1), Player::new(4, 8, 2)).await;
+    let opponent = harness.opponent.keypair.insecure_clone();
+    let rent = harness.game_rent().await;
+    harness.play_to_resolution().await;
+
+    let game = harness.game_state().await;
+    assert!(game.status == GameStatus::Completed);
+    assert_eq!(game.winner, Some(opponent.pubkey()));
+
+    harness.claim_winnings(&opponent).await.unwrap();
+
+    assert_eq!(
+        harness.balance(opponent.pubkey()).await,
+        STARTING_BALANCE + BET
+    );
+    assert_eq!(
+        harness.balance(harness.creator.pubkey()).await,
+        STARTING_BALANCE - BET - rent
+    );
+    assert_eq!(harness.balance(harness.game_account).await, 0);
+}
+
+#[tokio::test]
+async fn reveals_resolve_in_either_order() {
+    let mut harness = Harness::new(Player::new(1, 2, 1), Player::new(1, 9, 2)).await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+
+    harness.reveal_opponent().await.unwrap();
+    assert!(harness.game_state().await.status == GameStatus::WaitingForReveal);
+    harness.reveal_creator().await.unwrap();
+
+    let game = harness.game_state().await;
+    assert!(game.status == GameStatus::Completed);
+    assert_eq!(game.winner, Some(harness.creator.pubkey()));
+}
+
+#[tokio::test]
+async fn both_correct_is_a_draw_and_keeps_escrow() {
+    let mut harness = Harness::new(Player::new(2, 5, 1), Player::new(3, 5, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    harness.play_to_resolution().await;
+
+    let game = harness.game_state().await;
+    assert!(game.status == GameStatus::Draw);
+    assert_eq!(game.winner, None);
+    assert_eq!(harness.balance(harness.game_account).await, 2 * BET);
+
+    assert_game_error(
+        harness.claim_winnings(&creator).await,
+        GameError::InvalidGameState,
+    );
+    assert_eq!(harness.balance(harness.game_account).await, 2 * BET);
+}
+
+#[tokio::test]
+async fn neither_correct_is_a_draw() {
+    let mut harness = Harness::new(Player::new(2, 9, 1), Player::new(3, 2, 2)).await;
+    harness.play_to_resolution().await;
+
+    let game = harness.game_state().await;
+    assert!(game.status == GameStatus::Draw);
+    assert_eq!(game.winner, None);
+    assert_eq!(harness.balance(harness.game_account).await, 2 * BET);
+}
+
+#[tokio::test]
+async fn draw_refunds_both_bets() {
+    let mut harness = Harness::new(Player::new(2, 5, 1), Player::new(3, 5, 2)).await;
+    let rent = harness.game_rent().await;
+    harness.play_to_resolution().await;
+
+    harness.refund_draw().await.unwrap();
+
+    assert_eq!(
+        harness.balance(harness.creator.pubkey()).await,
+        STARTING_BALANCE - rent
+    );
+    assert_eq!(
+        harness.balance(harness.opponent.pubkey()).await,
+        STARTING_BALANCE
+    );
+    assert_eq!(harness.balance(harness.game_account).await, 0);
+    assert!(harness.game_state().await.status == GameStatus::Settled);
+
+    assert_game_error(harness.refund_draw().await, GameError::InvalidGameState);
+}
+
+#[tokio::test]
+async fn refund_outside_draw_fails() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.play_to_resolution().await;
+
+    assert_game_error(harness.refund_draw().await, GameError::InvalidGameState);
+    assert_eq!(harness.balance(harness.game_account).await, 2 * BET);
+}
+
+#[tokio::test]
+async fn joining_a_full_game_fails() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.play_to_resolution().await;
+    let late = Player::new(1, 1, 3);
+    harness.create_profile(&late.keypair).await.unwrap();
+    let before = harness.balance(harness.game_account).await;
+
+    // The latecomer has no lamports, but the status check fails first.
+    assert_game_error(
+        harness.join_game_as(&late.keypair, late.commitment(&harness.game)).await,
+        GameError::InvalidGameState,
+    );
+    assert_eq!(harness.balance(harness.game_account).await, before);
+}
+
+#[tokio::test]
+async fn copied_commitment_cannot_be_opened() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+
+    // The opponent joins with the creator's public commitment, hoping to
+    // reveal whatever the creator does and force a draw.
+    let copied = harness.game_state().await.creator_move.committed_hash.unwrap();
+    let opponent = harness.opponent.keypair.insecure_clone();
+    harness.join_game_as(&opponent, copied).await.unwrap();
+    harness.reveal_creator().await.unwrap();
+
+    let (card, prediction, salt) =
+        (harness.creator.card, harness.creator.prediction, harness.creator.salt);
+    assert_game_error(
+        harness.reveal_move(&opponent, card, prediction, salt).await,
+        GameError::InvalidCommitment,
+    );
+    assert!(harness.game_state().await.status == GameStatus::WaitingForReveal);
+}
+
+#[tokio::test]
+async fn reveal_with_wrong_salt_fails() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+
+    let creator = harness.creator.keypair.insecure_clone();
+    assert_game_error(
+        harness.reveal_move(&creator, 3, 5, [9; 32]).await,
+        GameError::InvalidCommitment,
+    );
+    assert_game_error(
+        harness.reveal_move(&creator, 4, 5, [1; 32]).await,
+        GameError::InvalidCommitment,
+    );
+    let game = harness.game_state().await;
+    assert_eq!(game.creator_move.card, None);
+    assert!(game.status == GameStatus::WaitingForReveal);
+}
+
+#[tokio::test]
+async fn loser_cannot_claim() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let opponent = harness.opponent.keypair.insecure_clone();
+    harness.play_to_resolution().await;
+    let before = harness.balance(opponent.pubkey()).await;
+
+    assert_game_error(
+        harness.claim_winnings(&opponent).await,
+        GameError::NotWinner,
+    );
+    assert_eq!(harness.balance(opponent.pubkey()).await, before);
+    assert_eq!(harness.balance(harness.game_account).await, 2 * BET);
+}
+
+#[tokio::test]
+async fn claim_before_resolution_fails() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    harness.create_game().await.unwrap();
+
+    assert_game_error(
+        harness.claim_winnings(&creator).await,
+        GameError::InvalidGameState,
+    );
+    assert_eq!(harness.balance(harness.game_account).await, BET);
+}
+
+#[tokio::test]
+async fn bet_outside_limits_is_rejected() {
+    for bet in [MIN_BET - 1, MAX_BET + 1] {
+        let mut harness =
+            Harness::with_bet(Player::new(3, 5, 1), Player::new(2, 7, 2), bet).await;
+        let creator = harness.creator.pubkey();
+
+        assert_game_error(harness.create_game().await, GameError::InvalidBetAmount);
+        assert_eq!(harness.balance(creator).await, STARTING_BALANCE);
+    }
+}
+
+#[tokio::test]
+async fn creator_cannot_join_own_game() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    harness.create_game().await.unwrap();
+
+    assert_game_error(
+        harness.join_game_as(&creator, [0; 32]).await,
+        GameError::CannotJoinOwnGame,
+    );
+    assert!(harness.game_state().await.status == GameStatus::WaitingForOpponent);
+}
+
+#[tokio::test]
+async fn reveal_before_join_fails() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+
+    assert_game_error(harness.reveal_creator().await, GameError::InvalidGameState);
+}
+
+#[tokio::test]
+async fn outsider_cannot_reveal() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+
+    let outsider = Keypair::new();
+    assert_game_error(
+        harness.reveal_move(&outsider, 2, 7, [2; 32]).await,
+        GameError::InvalidPlayer,
+    );
+}
+
+#[tokio::test]
+async fn reveal_out_of_range_move_fails() {
+    let mut harness = Harness::new(Player::new(6, 8, 1), Player::new(2, 11, 2)).await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+
+    assert_game_error(harness.reveal_creator().await, GameError::InvalidCard);
+    assert_game_error(harness.reveal_opponent().await, GameError::InvalidPrediction);
+    assert!(harness.game_state().await.status == GameStatus::WaitingForReveal);
+}
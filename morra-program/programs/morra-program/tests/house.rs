@@ -0,0 +1,372 @@
+mod common;
+
+use common::{
+    assert_game_error, commitment, escrow_address, house_address, Harness, Player, BET,
+    STARTING_BALANCE,
+};
+use morra_core::Move;
+use morra_program::{
+    GameError, HouseGameStatus, HouseOutcome, MIN_BET, MOVE_TIMEOUT, WITHDRAWAL_COOLDOWN,
+};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+const BOND: u64 = 2 * BET;
+const BANKROLL: u64 = 5 * BET;
+const HOUSE_SALT: [u8; 32] = [7; 32];
+
+/// A harness with the house set up: an operator bonded with `bond`, and a
+/// bankroll with no house edge funded by one provider. The harness creator
+/// plays the house.
+async fn house_harness(max_exposure_bps: u16, bond: u64) -> (Harness, Keypair) {
+    let mut harness = Harness::new(Player::new(2, 5, 1), Player::new(3, 7, 2)).await;
+    let operator = Keypair::new();
+    harness.initialize_config(0).await.unwrap();
+    harness.initialize_house(operator.pubkey(), bond).await.unwrap();
+    harness.initialize_bankroll(max_exposure_bps, 0).await.unwrap();
+    let provider = Keypair::new();
+    harness.fund(provider.pubkey(), 2 * BANKROLL).await;
+    harness.create_lp_position(&provider).await.unwrap();
+    harness.deposit_bankroll(&provider, BANKROLL).await.unwrap();
+    (harness, operator)
+}
+
+fn house_commitment(game: &Pubkey, card: u8, prediction: u8) -> [u8; 32] {
+    commitment(&house_address(), game, Move::new(card, prediction), &HOUSE_SALT)
+}
+
+/// Play a house game to the house's reveal: the house commits `house`, the
+/// harness creator answers with `player`.
+async fn play(
+    harness: &mut Harness,
+    operator: &Keypair,
+    house: (u8, u8),
+    player: (u8, u8),
+) -> Pubkey {
+    let player_key = harness.creator.keypair.insecure_clone();
+    let game = harness.create_house_game(&player_key, BET).await.unwrap();
+    harness
+        .house_commit(game, operator, house_commitment(&game, house.0, house.1))
+        .await
+        .unwrap();
+    harness
+        .play_house_game(game, &player_key, player.0, player.1)
+        .await
+        .unwrap();
+    game
+}
+
+#[tokio::test]
+async fn player_win_is_paid_from_the_bankroll() {
+    let (mut harness, operator) = house_harness(2_000, BOND).await;
+    // Total 5: only the player calls it.
+    let game = play(&mut harness, &operator, (3, 7), (2, 5)).await;
+    assert_eq!(harness.bankroll().await.open_exposure, BET);
+    let player = harness.creator.pubkey();
+    let before = harness.balance(player).await;
+
+    harness
+        .house_reveal(game, &operator, 3, 7, HOUSE_SALT)
+        .await
+        .unwrap();
+
+    let state = harness.house_game(game).await;
+    assert!(state.status == HouseGameStatus::Settled);
+    assert_eq!(state.outcome, Some(HouseOutcome::PlayerWins));
+    assert_eq!(harness.balance(player).await, before + 2 * BET);
+    let profile = harness.profile(player).await;
+    assert_eq!((profile.games_played, profile.wins), (1, 1));
+    assert_eq!(harness.bankroll_assets().await, BANKROLL - BET);
+    assert_eq!(harness.balance(escrow_address(&game)).await, 0);
+    assert_eq!(harness.bankroll().await.open_exposure, 0);
+}
+
+#[tokio::test]
+async fn house_win_and_draw_settle_the_stake() {
+    let (mut harness, operator) = house_harness(2_000, BOND).await;
+    let player = harness.creator.pubkey();
+
+    // Total 7: only the house calls it.
+    let won = play(&mut harness, &operator, (5, 7), (2, 9)).await;
+    let before = harness.balance(player).await;
+    harness
+        .house_reveal(won, &operator, 5, 7, HOUSE_SALT)
+        .await
+        .unwrap();
+    assert_eq!(
+        harness.house_game(won).await.outcome,
+        Some(HouseOutcome::HouseWins)
+    );
+    assert_eq!(harness.balance(player).await, before);
+    assert_eq!(harness.bankroll_assets().await, BANKROLL + BET);
+
+    // Total 5: both call it.
+    let drawn = play(&mut harness, &operator, (3, 5), (2, 5)).await;
+    let before = harness.balance(player).await;
+    harness
+        .house_reveal(drawn, &operator, 3, 5, HOUSE_SALT)
+        .await
+        .unwrap();
+    assert_eq!(
+        harness.house_game(drawn).await.outcome,
+        Some(HouseOutcome::Draw)
+    );
+    assert_eq!(harness.balance(player).await, before + BET);
+    assert_eq!(harness.bankroll_assets().await, BANKROLL + BET);
+    assert_eq!(harness.bankroll().await.open_exposure, 0);
+    let profile = harness.profile(player).await;
+    assert_eq!((profile.losses, profile.draws, profile.net_winnings), (1, 1, -(BET as i64)));
+}
+
+#[tokio::test]
+async fn house_is_bound_to_its_commitment() {
+    let (mut harness, operator) = house_harness(2_000, BOND).await;
+    let player = harness.creator.keypair.insecure_clone();
+    let game = harness.create_house_game(&player, BET).await.unwrap();
+
+    // The player can't move before the house is committed.
+    assert_game_error(
+        harness.play_house_game(game, &player, 2, 5).await,
+        GameError::InvalidGameState,
+    );
+    assert_game_error(
+        harness
+            .house_commit(game, &player, house_commitment(&game, 3, 7))
+            .await,
+        GameError::NotHouseOperator,
+    );
+    harness
+        .house_commit(game, &operator, house_commitment(&game, 3, 7))
+        .await
+        .unwrap();
+    harness.play_house_game(game, &player, 2, 5).await.unwrap();
+
+    // Having seen the player's move, the house can't switch to a winner.
+    assert_game_error(
+        harness.house_reveal(game, &operator, 3, 5, HOUSE_SALT).await,
+        GameError::InvalidCommitment,
+    );
+    assert!(harness.house_game(game).await.status == HouseGameStatus::WaitingForReveal);
+}
+
+#[tokio::test]
+async fn exposure_is_limited_per_game_and_by_the_bankroll() {
+    let (mut harness, _operator) = house_harness(4_000, 6 * BET).await;
+    let player = harness.creator.keypair.insecure_clone();
+    assert_game_error(
+        harness.create_house_game(&player, 3 * BET).await.map(|_| ()),
+        GameError::ExposureLimit,
+    );
+
+    // Two games reserve 4 of the bankroll's 5 SOL; a third doesn't fit.
+    harness.create_house_game(&player, 2 * BET).await.unwrap();
+    harness.create_house_game(&player, 2 * BET).await.unwrap();
+    assert_eq!(harness.bankroll().await.open_exposure, 4 * BET);
+    assert_game_error(
+        harness.create_house_game(&player, 2 * BET).await.map(|_| ()),
+        GameError::ExposureLimit,
+    );
+
+    // Reserved lamports can't be withdrawn either.
+    let provider = Keypair::new();
+    harness.fund(provider.pubkey(), STARTING_BALANCE).await;
+    harness.create_lp_position(&provider).await.unwrap();
+    harness.deposit_bankroll(&provider, BET).await.unwrap();
+    let shares = harness.lp_position(provider.pubkey()).await.shares;
+    harness.request_withdrawal(&provider, shares).await.unwrap();
+    let now = harness.now().await;
+    harness.warp_to(now + WITHDRAWAL_COOLDOWN).await;
+    harness.create_house_game(&player, 2 * BET).await.unwrap();
+    assert_game_error(
+        harness.withdraw_bankroll(&provider).await,
+        GameError::ExposureLimit,
+    );
+}
+
+#[tokio::test]
+async fn withheld_reveal_forfeits_and_slashes_the_bond() {
+    let (mut harness, operator) = house_harness(2_000, BOND).await;
+    let cranker = Keypair::new();
+    harness.fund(cranker.pubkey(), STARTING_BALANCE).await;
+    // The house would lose, so it stays quiet.
+    let game = play(&mut harness, &operator, (3, 7), (2, 5)).await;
+    let last_action_at = harness.house_game(game).await.last_action_at;
+
+    harness.warp_to(last_action_at + MOVE_TIMEOUT - 1).await;
+    assert_game_error(
+        harness.forfeit_house_game(game, &cranker).await,
+        GameError::TimeoutNotReached,
+    );
+    harness.warp_to(last_action_at + MOVE_TIMEOUT).await;
+    let player = harness.creator.pubkey();
+    let before = harness.balance(player).await;
+    let house_before = harness.balance(house_address()).await;
+    harness.forfeit_house_game(game, &cranker).await.unwrap();
+
+    assert_eq!(
+        harness.house_game(game).await.outcome,
+        Some(HouseOutcome::PlayerWins)
+    );
+    // The stake back, and the win out of the bond.
+    assert_eq!(harness.balance(player).await, before + 2 * BET);
+    assert_eq!(harness.balance(house_address()).await, house_before - BET);
+    assert_eq!(harness.house().await.bond, BOND - BET);
+    assert_eq!(harness.profile(player).await.wins, 1);
+}
+
+#[tokio::test]
+async fn house_forfeit_leaves_provider_assets_whole() {
+    let (mut harness, operator) = house_harness(2_000, BOND).await;
+    let cranker = Keypair::new();
+    harness.fund(cranker.pubkey(), STARTING_BALANCE).await;
+    let shares = harness.bankroll().await.total_shares;
+    let game = play(&mut harness, &operator, (3, 7), (2, 5)).await;
+
+    let last_action_at = harness.house_game(game).await.last_action_at;
+    harness.warp_to(last_action_at + MOVE_TIMEOUT).await;
+    harness.forfeit_house_game(game, &cranker).await.unwrap();
+
+    // The operator's stall costs its bond, not the providers.
+    let bankroll = harness.bankroll().await;
+    assert_eq!((bankroll.open_exposure, bankroll.total_shares), (0, shares));
+    assert_eq!(harness.bankroll_assets().await, BANKROLL);
+    assert_eq!(harness.balance(escrow_address(&game)).await, 0);
+}
+
+#[tokio::test]
+async fn stalled_commit_refunds_and_stalled_move_loses_the_stake() {
+    let (mut harness, operator) = house_harness(2_000, BOND).await;
+    let cranker = Keypair::new();
+    harness.fund(cranker.pubkey(), STARTING_BALANCE).await;
+    let player = harness.creator.keypair.insecure_clone();
+
+    let uncommitted = harness.create_house_game(&player, BET).await.unwrap();
+    let unplayed = harness.create_house_game(&player, BET).await.unwrap();
+    harness
+        .house_commit(unplayed, &operator, house_commitment(&unplayed, 3, 7))
+        .await
+        .unwrap();
+    let now = harness.now().await;
+    harness.warp_to(now + MOVE_TIMEOUT).await;
+    let before = harness.balance(player.pubkey()).await;
+
+    harness
+        .forfeit_house_game(uncommitted, &cranker)
+        .await
+        .unwrap();
+    let state = harness.house_game(uncommitted).await;
+    assert!(state.status == HouseGameStatus::Cancelled);
+    assert_eq!(state.outcome, None);
+    assert_eq!(harness.balance(player.pubkey()).await, before + BET);
+
+    harness.forfeit_house_game(unplayed, &cranker).await.unwrap();
+    assert_eq!(
+        harness.house_game(unplayed).await.outcome,
+        Some(HouseOutcome::HouseWins)
+    );
+    assert_eq!(harness.bankroll_assets().await, BANKROLL + BET);
+    assert_eq!(harness.bankroll().await.open_exposure, 0);
+    assert_eq!(harness.house().await.bond, BOND);
+    // Only the game the player walked away from counts against them.
+    let profile = harness.profile(player.pubkey()).await;
+    assert_eq!((profile.games_played, profile.forfeits), (1, 1));
+}
+
+#[tokio::test]
+async fn house_takes_no_game_its_bond_cannot_cover() {
+    let (mut harness, operator) = house_harness(10_000, BET).await;
+    let cranker = Keypair::new();
+    harness.fund(cranker.pubkey(), STARTING_BALANCE).await;
+    let player = harness.creator.keypair.insecure_clone();
+
+    // The bond covers one open game's slash.
+    let game = play(&mut harness, &operator, (3, 7), (2, 5)).await;
+    assert_game_error(
+        harness.create_house_game(&player, MIN_BET).await.map(|_| ()),
+        GameError::BondExhausted,
+    );
+
+    // Slashed to nothing, the house can't take another game.
+    let last_action_at = harness.house_game(game).await.last_action_at;
+    harness.warp_to(last_action_at + MOVE_TIMEOUT).await;
+    harness.forfeit_house_game(game, &cranker).await.unwrap();
+    assert_eq!(harness.house().await.bond, 0);
+    assert_game_error(
+        harness.create_house_game(&player, MIN_BET).await.map(|_| ()),
+        GameError::BondExhausted,
+    );
+}
+
+#[tokio::test]
+async fn authority_manages_the_bond_above_open_exposure() {
+    let (mut harness, operator) = house_harness(10_000, BET).await;
+    let authority = harness.context.payer.insecure_clone();
+    let stranger = Keypair::new();
+    harness.fund(stranger.pubkey(), STARTING_BALANCE).await;
+
+    assert_game_error(
+        harness.fund_house_bond(&stranger, BET).await,
+        GameError::Unauthorized,
+    );
+    assert_game_error(
+        harness.withdraw_house_bond(&stranger, BET).await,
+        GameError::Unauthorized,
+    );
+
+    let before = harness.balance(house_address()).await;
+    harness.fund_house_bond(&authority, BET).await.unwrap();
+    assert_eq!(harness.house().await.bond, 2 * BET);
+    assert_eq!(harness.balance(house_address()).await, before + BET);
+
+    // With one game open, only the bond beyond its bet can leave.
+    let game = play(&mut harness, &operator, (3, 7), (2, 5)).await;
+    assert_game_error(
+        harness.withdraw_house_bond(&authority, BET + 1).await,
+        GameError::BondLocked,
+    );
+    harness.withdraw_house_bond(&authority, BET).await.unwrap();
+    assert_eq!(harness.house().await.bond, BET);
+    assert_eq!(harness.balance(house_address()).await, before);
+
+    // Once the game settles, the rest is free.
+    harness
+        .house_reveal(game, &operator, 3, 7, HOUSE_SALT)
+        .await
+        .unwrap();
+    harness.withdraw_house_bond(&authority, BET).await.unwrap();
+    assert_eq!(harness.house().await.bond, 0);
+    assert_eq!(harness.balance(house_address()).await, before - BET);
+}
+
+#[tokio::test]
+async fn rotated_operator_reveals_open_games() {
+    let (mut harness, operator) = house_harness(2_000, BOND).await;
+    let authority = harness.context.payer.insecure_clone();
+    let rotated = Keypair::new();
+    harness.fund(rotated.pubkey(), STARTING_BALANCE).await;
+    let game = play(&mut harness, &operator, (3, 7), (2, 5)).await;
+
+    assert_game_error(
+        harness.set_house_operator(&operator, rotated.pubkey()).await,
+        GameError::Unauthorized,
+    );
+    harness
+        .set_house_operator(&authority, rotated.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(harness.house().await.operator, rotated.pubkey());
+
+    assert_game_error(
+        harness.house_reveal(game, &operator, 3, 7, HOUSE_SALT).await,
+        GameError::NotHouseOperator,
+    );
+    harness
+        .house_reveal(game, &rotated, 3, 7, HOUSE_SALT)
+        .await
+        .unwrap();
+    assert_eq!(
+        harness.house_game(game).await.outcome,
+        Some(HouseOutcome::PlayerWins)
+    );
+}
@@ -0,0 +1,319 @@
+mod common;
+
+use common::{
+    assert_game_error, escrow_address_for, league_address, Harness, Player, BET, CREATOR_WINS,
+    DRAW, OPPONENT_WINS, STARTING_BALANCE,
+};
+use morra_program::{GameError, LeagueStatus, MIN_BET, MOVE_TIMEOUT};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+const SEASON: i64 = 30 * 24 * 3_600;
+
+struct Season {
+    harness: Harness,
+    league: Pubkey,
+    players: Vec<Keypair>,
+}
+
+impl Season {
+    /// A funded roster of `size` players with profiles, the harness's two
+    /// players first.
+    async fn roster(size: usize) -> (Harness, Vec<Keypair>) {
+        let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+        let mut players = vec![
+            harness.creator.keypair.insecure_clone(),
+            harness.opponent.keypair.insecure_clone(),
+        ];
+        while players.len() < size {
+            let player = Keypair::new();
+            harness.fund(player.pubkey(), STARTING_BALANCE).await;
+            harness.create_profile(&player).await.unwrap();
+            players.push(player);
+        }
+        (harness, players)
+    }
+
+    /// A league over a roster of `size` nobody has joined yet.
+    async fn new(size: usize, entry_fee: u64) -> Self {
+        let (mut harness, players) = Self::roster(size).await;
+        let roster = players.iter().map(|player| player.pubkey()).collect();
+        let ends_at = harness.now().await + SEASON;
+        harness
+            .create_league(0, entry_fee, roster, ends_at)
+            .await
+            .unwrap();
+        let league = league_address(&harness.context.payer.pubkey(), 0);
+        Self {
+            harness,
+            league,
+            players,
+        }
+    }
+
+    fn vault(&self) -> Pubkey {
+        escrow_address_for(b"league", &self.league)
+    }
+
+    async fn join_all(&mut self) {
+        for player in &self.players {
+            let player = player.insecure_clone();
+            self.harness.join_league(self.league, &player).await.unwrap();
+        }
+    }
+
+    /// Spawn, play and record fixture `index` with the seats playing
+    /// `moves`.
+    async fn play(&mut self, index: u8, moves: [(u8, u8); 2]) -> Pubkey {
+        let game = self
+            .harness
+            .spawn_fixture_game(self.league, index)
+            .await
+            .unwrap();
+        let fixture = self.harness.league(self.league).await.fixtures[usize::from(index)];
+        let [home, away] = fixture
+            .players
+            .map(|player| self.players[usize::from(player)].insecure_clone());
+        self.harness.play_seated_game(game, [&home, &away], moves).await;
+        self.harness
+            .record_fixture(self.league, game, index)
+            .await
+            .unwrap();
+        game
+    }
+
+    /// Claim every roster member's share and check each received `prizes`.
+    async fn claim_all(&mut self, prizes: &[u64]) {
+        for (player, &prize) in self.players.iter().zip(prizes) {
+            let player = player.insecure_clone();
+            let before = self.harness.balance(player.pubkey()).await;
+            let result = self.harness.claim_league_prize(self.league, &player).await;
+            if prize == 0 {
+                assert_game_error(result, GameError::NoPrize);
+            } else {
+                result.unwrap();
+            }
+            assert_eq!(self.harness.balance(player.pubkey()).await, before + prize);
+        }
+        assert_eq!(self.harness.balance(self.vault()).await, 0);
+    }
+}
+
+#[tokio::test]
+async fn full_season_pays_the_table_leader() {
+    let mut season = Season::new(3, BET).await;
+    season.join_all().await;
+    assert_eq!(season.harness.balance(season.vault()).await, 3 * BET);
+
+    // The last member to join starts the season with every pairing.
+    let league = season.harness.league(season.league).await;
+    assert!(league.status == LeagueStatus::InProgress);
+    let fixtures: Vec<[u8; 2]> = league.fixtures.iter().map(|fixture| fixture.players).collect();
+    assert_eq!(fixtures, [[1, 2], [1, 0], [0, 2]]);
+
+    season.play(0, CREATOR_WINS).await;
+    season.play(1, DRAW).await;
+    season.play(2, OPPONENT_WINS).await;
+
+    let league = season.harness.league(season.league).await;
+    let table: Vec<_> = league
+        .standings
+        .iter()
+        .map(|row| (row.played, row.wins, row.draws, row.losses, row.points))
+        .collect();
+    assert_eq!(table, [(2, 0, 1, 1, 1), (2, 1, 1, 0, 4), (2, 1, 0, 1, 3)]);
+
+    // Every fixture is in, so the season can close before its deadline.
+    season.harness.settle_league(season.league).await.unwrap();
+    assert!(season.harness.league(season.league).await.status == LeagueStatus::Completed);
+    season.claim_all(&[0, 3 * BET, 0]).await;
+
+    let leader = season.players[1].insecure_clone();
+    assert_game_error(
+        season.harness.claim_league_prize(season.league, &leader).await,
+        GameError::NoPrize,
+    );
+}
+
+#[tokio::test]
+async fn tied_leaders_split_the_pot() {
+    // An odd fee leaves a lamport of dust for the first leader.
+    let fee = MIN_BET + 1;
+    let mut season = Season::new(3, fee).await;
+    season.join_all().await;
+
+    season.play(0, DRAW).await;
+    season.play(1, CREATOR_WINS).await;
+    season.play(2, OPPONENT_WINS).await;
+    season.harness.settle_league(season.league).await.unwrap();
+
+    let pot = 3 * fee;
+    season.claim_all(&[0, pot / 2 + 1, pot / 2]).await;
+}
+
+#[tokio::test]
+async fn deadline_settles_an_unfinished_season() {
+    let mut season = Season::new(3, BET).await;
+    season.join_all().await;
+    season.play(0, CREATOR_WINS).await;
+
+    assert_game_error(
+        season.harness.settle_league(season.league).await,
+        GameError::SeasonNotOver,
+    );
+    let deadline = season.harness.league(season.league).await.season_ends_at;
+    season.harness.warp_to(deadline).await;
+    season.harness.settle_league(season.league).await.unwrap();
+
+    assert_game_error(
+        season.harness.spawn_fixture_game(season.league, 1).await.map(drop),
+        GameError::InvalidLeagueState,
+    );
+    season.claim_all(&[0, 3 * BET, 0]).await;
+}
+
+#[tokio::test]
+async fn league_config_is_validated() {
+    let (mut harness, players) = Season::roster(2).await;
+    let [a, b] = [0, 1].map(|player| players[player].pubkey());
+    let now = harness.now().await;
+    let ends_at = now + SEASON;
+
+    let too_many = (0..9).map(|_| Pubkey::new_unique()).collect();
+    for roster in [vec![a], vec![a, a], too_many] {
+        assert_game_error(
+            harness.create_league(0, BET, roster, ends_at).await,
+            GameError::InvalidLeagueConfig,
+        );
+    }
+    assert_game_error(
+        harness.create_league(0, BET, vec![a, b], now).await,
+        GameError::InvalidLeagueConfig,
+    );
+    assert_game_error(
+        harness.create_league(0, MIN_BET - 1, vec![a, b], ends_at).await,
+        GameError::InvalidBetAmount,
+    );
+}
+
+#[tokio::test]
+async fn only_the_roster_can_join() {
+    let mut season = Season::new(2, BET).await;
+    let [first, second] = [0, 1].map(|player| season.players[player].insecure_clone());
+
+    let outsider = Keypair::new();
+    season.harness.fund(outsider.pubkey(), STARTING_BALANCE).await;
+    season.harness.create_profile(&outsider).await.unwrap();
+    assert_game_error(
+        season.harness.join_league(season.league, &outsider).await,
+        GameError::NotOnRoster,
+    );
+
+    season.harness.join_league(season.league, &first).await.unwrap();
+    assert_game_error(
+        season.harness.join_league(season.league, &first).await,
+        GameError::AlreadyRegistered,
+    );
+    assert!(season.harness.league(season.league).await.status == LeagueStatus::Registration);
+
+    season.harness.join_league(season.league, &second).await.unwrap();
+    assert_game_error(
+        season.harness.leave_league(season.league, &first).await,
+        GameError::InvalidLeagueState,
+    );
+}
+
+#[tokio::test]
+async fn leaving_before_the_season_refunds_the_fee() {
+    let mut season = Season::new(3, BET).await;
+    let player = season.players[0].insecure_clone();
+    season.harness.join_league(season.league, &player).await.unwrap();
+    assert_eq!(
+        season.harness.balance(player.pubkey()).await,
+        STARTING_BALANCE - BET
+    );
+
+    season.harness.leave_league(season.league, &player).await.unwrap();
+
+    assert_eq!(season.harness.balance(player.pubkey()).await, STARTING_BALANCE);
+    assert_eq!(season.harness.balance(season.vault()).await, 0);
+    assert_eq!(season.harness.league(season.league).await.pot, 0);
+    assert_game_error(
+        season.harness.leave_league(season.league, &player).await,
+        GameError::NotRegistered,
+    );
+}
+
+#[tokio::test]
+async fn fixtures_are_recorded_once_from_their_own_game() {
+    let mut season = Season::new(3, BET).await;
+    season.join_all().await;
+    let game = season
+        .harness
+        .spawn_fixture_game(season.league, 0)
+        .await
+        .unwrap();
+    assert_game_error(
+        season.harness.spawn_fixture_game(season.league, 0).await.map(drop),
+        GameError::InvalidMatch,
+    );
+    assert_game_error(
+        season.harness.record_fixture(season.league, game, 0).await,
+        GameError::InvalidGameState,
+    );
+
+    let [home, away] = [1, 2].map(|player| season.players[player].insecure_clone());
+    season
+        .harness
+        .play_seated_game(game, [&home, &away], CREATOR_WINS)
+        .await;
+    assert_game_error(
+        season.harness.record_fixture(season.league, game, 1).await,
+        GameError::InvalidMatch,
+    );
+    season.harness.record_fixture(season.league, game, 0).await.unwrap();
+    assert_game_error(
+        season.harness.record_fixture(season.league, game, 0).await,
+        GameError::InvalidMatch,
+    );
+    assert_game_error(
+        season.harness.spawn_fixture_game(season.league, 0).await.map(drop),
+        GameError::InvalidMatch,
+    );
+}
+
+#[tokio::test]
+async fn cancelled_fixture_game_is_replayed() {
+    let mut season = Season::new(2, MIN_BET).await;
+    season.join_all().await;
+
+    let abandoned = season
+        .harness
+        .spawn_fixture_game(season.league, 0)
+        .await
+        .unwrap();
+    let last_action_at = season.harness.game_at(abandoned).await.last_action_at;
+    season.harness.warp_to(last_action_at + MOVE_TIMEOUT).await;
+    let cranker = Keypair::new();
+    season.harness.fund(cranker.pubkey(), STARTING_BALANCE).await;
+    season
+        .harness
+        .forfeit_game(abandoned, &cranker, false)
+        .await
+        .unwrap();
+    season
+        .harness
+        .record_fixture(season.league, abandoned, 0)
+        .await
+        .unwrap();
+    let state = season.harness.league(season.league).await;
+    assert_eq!(state.fixtures[0].game, None);
+    assert!(!state.fixtures[0].played);
+    assert_eq!(state.standings[0].played, 0);
+
+    season.play(0, CREATOR_WINS).await;
+    let state = season.harness.league(season.league).await;
+    assert!(state.fixtures[0].played);
+    assert_eq!(state.standings[0].wins, 1);
+}
@@ -0,0 +1,174 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::{
+    assert_game_error, escrow_address, game_address, lobby_address, Harness, Player, BET,
+    STARTING_BALANCE,
+};
+use morra_program::{GameError, GameStatus, LobbyEntry, LobbyPage};
+use solana_signer::Signer;
+
+fn entry(bet_amount: u64) -> LobbyEntry {
+    LobbyEntry {
+        game: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        bet_amount,
+        created_at: 0,
+    }
+}
+
+#[tokio::test]
+async fn create_game_registers_in_lobby() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+
+    let lobby = harness.lobby_page(lobby_address(false, 0)).await;
+    assert_eq!(lobby.games.len(), 1);
+    assert_eq!(lobby.games[0].game, harness.game);
+    assert_eq!(lobby.games[0].creator, harness.creator.pubkey());
+    assert_eq!(lobby.games[0].bet_amount, BET);
+    assert!(harness.lobby_page(lobby_address(true, 0)).await.games.is_empty());
+}
+
+#[tokio::test]
+async fn join_game_delists() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+
+    assert!(harness.lobby_page(harness.lobby).await.games.is_empty());
+}
+
+#[tokio::test]
+async fn games_list_on_the_page_they_were_created_into() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_lobby_page(true, 1).await.unwrap();
+    harness.ranked = true;
+    harness.lobby = lobby_address(true, 1);
+    harness.create_game().await.unwrap();
+
+    let game = harness.game_state().await;
+    assert!(game.ranked);
+    assert_eq!(game.lobby_page, 1);
+    assert_eq!(harness.lobby_page(harness.lobby).await.games.len(), 1);
+
+    harness.join_game().await.unwrap();
+    assert!(harness.lobby_page(harness.lobby).await.games.is_empty());
+}
+
+#[tokio::test]
+async fn ranked_game_needs_a_ranked_page() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.ranked = true;
+
+    assert_game_error(harness.create_game().await, GameError::InvalidLobby);
+}
+
+#[tokio::test]
+async fn cancel_refunds_creator_and_delists() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    let rent = harness.game_rent().await;
+    harness.create_game().await.unwrap();
+
+    harness.cancel_game(&creator).await.unwrap();
+
+    assert_eq!(
+        harness.balance(creator.pubkey()).await,
+        STARTING_BALANCE - rent
+    );
+    assert_eq!(harness.balance(harness.game_account).await, 0);
+    assert!(harness.game_state().await.status == GameStatus::Cancelled);
+    assert!(harness.lobby_page(harness.lobby).await.games.is_empty());
+
+    assert_game_error(harness.join_game().await, GameError::InvalidGameState);
+    assert_game_error(
+        harness.cancel_game(&creator).await,
+        GameError::InvalidGameState,
+    );
+}
+
+#[tokio::test]
+async fn creator_can_open_another_game_after_cancelling() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    harness.create_game().await.unwrap();
+    harness.cancel_game(&creator).await.unwrap();
+
+    harness.game = game_address(&creator.pubkey(), 1);
+    harness.game_account = escrow_address(&harness.game);
+    harness.create_game().await.unwrap();
+
+    let game = harness.game_state().await;
+    assert_eq!(game.game_id, 1);
+    assert!(game.status == GameStatus::WaitingForOpponent);
+    assert_eq!(harness.profile(creator.pubkey()).await.next_game_id, 2);
+    assert_eq!(harness.lobby_page(harness.lobby).await.games.len(), 1);
+}
+
+#[tokio::test]
+async fn only_creator_can_cancel() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let opponent = harness.opponent.keypair.insecure_clone();
+    harness.create_game().await.unwrap();
+
+    assert_game_error(
+        harness.cancel_game(&opponent).await,
+        GameError::InvalidPlayer,
+    );
+    assert_eq!(harness.balance(harness.game_account).await, BET);
+}
+
+#[tokio::test]
+async fn joined_game_cannot_be_cancelled() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+
+    assert_game_error(
+        harness.cancel_game(&creator).await,
+        GameError::InvalidGameState,
+    );
+    assert_eq!(harness.balance(harness.game_account).await, 2 * BET);
+}
+
+#[test]
+fn full_page_rejects_new_games() {
+    let mut page = LobbyPage {
+        ranked: false,
+        index: 0,
+        bump: 0,
+        games: Vec::new(),
+    };
+    for _ in 0..LobbyPage::CAPACITY {
+        page.register(entry(BET)).unwrap();
+    }
+
+    assert!(page.is_full());
+    assert!(page.register(entry(BET)).is_err());
+    assert_eq!(page.games.len(), LobbyPage::CAPACITY);
+}
+
+#[test]
+fn open_games_filters_by_bet() {
+    let mut page = LobbyPage {
+        ranked: false,
+        index: 0,
+        bump: 0,
+        games: Vec::new(),
+    };
+    let (small, medium, large) = (entry(BET / 2), entry(BET), entry(5 * BET));
+    for game in [small, medium, large] {
+        page.register(game).unwrap();
+    }
+
+    let listed: Vec<_> = page.open_games(BET..=5 * BET).copied().collect();
+    assert_eq!(listed, [medium, large]);
+
+    page.remove(&medium.game).unwrap();
+    assert!(page.remove(&medium.game).is_err());
+    let listed: Vec<_> = page.open_games(0..=u64::MAX).copied().collect();
+    assert_eq!(listed.len(), 2);
+    assert!(!listed.contains(&medium));
+}
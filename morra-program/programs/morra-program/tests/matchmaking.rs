@@ -0,0 +1,201 @@
+mod common;
+
+use common::{
+    assert_game_error, escrow_address, escrow_address_for, game_address, queue_address,
+    Harness, Player, BET, STARTING_BALANCE,
+};
+use morra_program::{GameError, GameStatus, BET_TIERS};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+/// The 1 SOL tier, so queued stakes match the harness's `BET`.
+const TIER: u8 = 2;
+
+async fn queued_harness(creator: Player, opponent: Player) -> Harness {
+    let mut harness = Harness::new(creator, opponent).await;
+    harness.create_match_queue(TIER).await.unwrap();
+    harness
+}
+
+async fn enqueue_both(harness: &mut Harness, ranked: bool) {
+    let creator = harness.creator.keypair.insecure_clone();
+    let opponent = harness.opponent.keypair.insecure_clone();
+    let queue = queue_address(TIER);
+    let (creator_commitment, opponent_commitment) =
+        (harness.creator.commitment(&queue), harness.opponent.commitment(&queue));
+    harness
+        .enqueue(TIER, &creator, creator_commitment, ranked)
+        .await
+        .unwrap();
+    harness
+        .enqueue(TIER, &opponent, opponent_commitment, ranked)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn queue_uses_the_tier_stake() {
+    let mut harness = queued_harness(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    assert_eq!(BET_TIERS[usize::from(TIER)], BET);
+
+    let queue = harness.match_queue(TIER).await;
+    assert_eq!(queue.tier, TIER);
+    assert_eq!(queue.bet_amount, BET);
+
+    let bad_tier = u8::try_from(BET_TIERS.len()).unwrap();
+    assert_game_error(
+        harness.create_match_queue(bad_tier).await,
+        GameError::InvalidBetAmount,
+    );
+}
+
+#[tokio::test]
+async fn paired_game_starts_in_reveal_phase() {
+    let mut harness = queued_harness(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let (creator, opponent) = (harness.creator.pubkey(), harness.opponent.pubkey());
+    let vault = escrow_address_for(b"queue", &queue_address(TIER));
+    enqueue_both(&mut harness, false).await;
+    assert_eq!(harness.balance(vault).await, 2 * BET);
+    assert_eq!(harness.balance(creator).await, STARTING_BALANCE - BET);
+
+    let game = harness.pair_match(TIER, creator, opponent).await.unwrap();
+
+    assert_eq!(game, game_address(&creator, 0));
+    assert_eq!(harness.profile(creator).await.next_game_id, 1);
+    assert!(harness.match_queue(TIER).await.entries.is_empty());
+    assert_eq!(harness.balance(vault).await, 0);
+    harness.game = game;
+    harness.game_account = escrow_address(&game);
+    assert_eq!(harness.balance(harness.game_account).await, 2 * BET);
+
+    let state = harness.game_state().await;
+    assert!(state.status == GameStatus::WaitingForReveal);
+    assert_eq!(state.creator, creator);
+    assert_eq!(state.opponent, opponent);
+    assert_eq!(state.bet_amount, BET);
+    let queue = queue_address(TIER);
+    assert_eq!(state.queue, Some(queue));
+    assert_eq!(state.creator_move.committed_hash, Some(harness.creator.commitment(&queue)));
+    assert_eq!(state.opponent_move.committed_hash, Some(harness.opponent.commitment(&queue)));
+}
+
+#[tokio::test]
+async fn paired_game_plays_out_like_any_other() {
+    let mut harness = queued_harness(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    let opponent = harness.opponent.pubkey();
+    enqueue_both(&mut harness, true).await;
+    let game = harness
+        .pair_match(TIER, creator.pubkey(), opponent)
+        .await
+        .unwrap();
+    harness.game = game;
+    harness.game_account = escrow_address(&game);
+
+    harness.reveal_creator().await.unwrap();
+    harness.reveal_opponent().await.unwrap();
+    harness.claim_winnings(&creator).await.unwrap();
+
+    // The crank paid the game's rent, so the winner nets exactly one bet.
+    assert_eq!(
+        harness.balance(creator.pubkey()).await,
+        STARTING_BALANCE + BET
+    );
+    assert_eq!(harness.balance(opponent).await, STARTING_BALANCE - BET);
+    let profile = harness.profile(creator.pubkey()).await;
+    assert_eq!(profile.wins, 1);
+    assert!(harness.game_state().await.ranked);
+}
+
+#[tokio::test]
+async fn oldest_compatible_entries_pair_first() {
+    let mut harness = queued_harness(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let (creator, opponent) = (
+        harness.creator.keypair.insecure_clone(),
+        harness.opponent.keypair.insecure_clone(),
+    );
+    let late = Keypair::new();
+    harness.create_profile(&late).await.unwrap();
+    harness
+        .context
+        .set_account(
+            &late.pubkey(),
+            &solana_account::Account::new(
+                STARTING_BALANCE,
+                0,
+                &anchor_lang::solana_program::system_program::ID,
+            )
+            .into(),
+        );
+
+    // A ranked player queues first but only casual players follow.
+    harness.enqueue(TIER, &creator, [1; 32], true).await.unwrap();
+    harness.enqueue(TIER, &opponent, [2; 32], false).await.unwrap();
+    harness.enqueue(TIER, &late, [3; 32], false).await.unwrap();
+
+    assert_game_error(
+        harness
+            .pair_match(TIER, creator.pubkey(), opponent.pubkey())
+            .await
+            .map(|_| ()),
+        GameError::InvalidProfile,
+    );
+    harness
+        .pair_match(TIER, opponent.pubkey(), late.pubkey())
+        .await
+        .unwrap();
+
+    let queue = harness.match_queue(TIER).await;
+    assert_eq!(queue.entries.len(), 1);
+    assert_eq!(queue.entries[0].player, creator.pubkey());
+}
+
+#[tokio::test]
+async fn lone_entry_has_no_match() {
+    let mut harness = queued_harness(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    let opponent = harness.opponent.keypair.insecure_clone();
+    harness.enqueue(TIER, &creator, [1; 32], false).await.unwrap();
+    harness.enqueue(TIER, &opponent, [2; 32], true).await.unwrap();
+
+    assert_game_error(
+        harness
+            .pair_match(TIER, creator.pubkey(), opponent.pubkey())
+            .await
+            .map(|_| ()),
+        GameError::NoMatch,
+    );
+}
+
+#[tokio::test]
+async fn player_cannot_queue_twice() {
+    let mut harness = queued_harness(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    harness.enqueue(TIER, &creator, [1; 32], false).await.unwrap();
+
+    assert_game_error(
+        harness.enqueue(TIER, &creator, [9; 32], false).await,
+        GameError::AlreadyQueued,
+    );
+    assert_eq!(
+        harness.balance(creator.pubkey()).await,
+        STARTING_BALANCE - BET
+    );
+}
+
+#[tokio::test]
+async fn leaving_the_queue_refunds_the_stake() {
+    let mut harness = queued_harness(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    let opponent = harness.opponent.keypair.insecure_clone();
+    harness.enqueue(TIER, &creator, [1; 32], false).await.unwrap();
+
+    assert_game_error(
+        harness.leave_queue(TIER, &opponent).await,
+        GameError::NotQueued,
+    );
+    harness.leave_queue(TIER, &creator).await.unwrap();
+
+    assert_eq!(harness.balance(creator.pubkey()).await, STARTING_BALANCE);
+    assert!(harness.match_queue(TIER).await.entries.is_empty());
+}
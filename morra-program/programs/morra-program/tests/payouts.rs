@@ -0,0 +1,84 @@
+mod common;
+
+use common::{Harness, Player};
+use morra_program::{GameStatus, MAX_BET, MIN_BET};
+use proptest::prelude::*;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+fn player() -> impl Strategy<Value = Player> {
+    (
+        morra_core::MIN_CARD..=morra_core::MAX_CARD,
+        morra_core::MIN_PREDICTION..=morra_core::MAX_PREDICTION,
+        any::<[u8; 32]>(),
+    )
+        .prop_map(|(card, prediction, salt)| Player {
+            keypair: Keypair::new(),
+            card,
+            prediction,
+            salt,
+        })
+}
+
+async fn total_lamports(harness: &mut Harness) -> u64 {
+    let creator = harness.creator.pubkey();
+    let opponent = harness.opponent.pubkey();
+    harness.balance(creator).await
+        + harness.balance(opponent).await
+        + harness.balance(harness.game).await
+        + harness.balance(harness.game_account).await
+}
+
+proptest! {
+    // Every case boots a fresh bank, so keep the count modest.
+    #![proptest_config(ProptestConfig::with_cases(24))]
+
+    #[test]
+    fn payouts_conserve_lamports(
+        creator in player(),
+        opponent in player(),
+        bet in MIN_BET..=MAX_BET,
+    ) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async move {
+            let mut harness = Harness::with_bet(creator, opponent, bet).await;
+            let creator = harness.creator.keypair.insecure_clone();
+            let opponent = harness.opponent.keypair.insecure_clone();
+            let rent = harness.game_rent().await;
+            let creator_start = harness.balance(creator.pubkey()).await;
+            let opponent_start = harness.balance(opponent.pubkey()).await;
+            let total_start = total_lamports(&mut harness).await;
+
+            harness.play_to_resolution().await;
+            prop_assert_eq!(total_lamports(&mut harness).await, total_start);
+            prop_assert_eq!(harness.balance(harness.game_account).await, 2 * bet);
+
+            let game = harness.game_state().await;
+            let (creator_end, opponent_end) = match game.winner {
+                Some(winner) if winner == creator.pubkey() => {
+                    harness.claim_winnings(&creator).await.unwrap();
+                    (creator_start - rent + bet, opponent_start - bet)
+                }
+                Some(winner) => {
+                    prop_assert_eq!(winner, opponent.pubkey());
+                    harness.claim_winnings(&opponent).await.unwrap();
+                    (creator_start - rent - bet, opponent_start + bet)
+                }
+                None => {
+                    prop_assert!(game.status == GameStatus::Draw);
+                    harness.refund_draw().await.unwrap();
+                    (creator_start - rent, opponent_start)
+                }
+            };
+
+            prop_assert_eq!(harness.balance(creator.pubkey()).await, creator_end);
+            prop_assert_eq!(harness.balance(opponent.pubkey()).await, opponent_end);
+            prop_assert_eq!(harness.balance(harness.game_account).await, 0);
+            prop_assert_eq!(total_lamports(&mut harness).await, total_start);
+            Ok(())
+        })?;
+    }
+}
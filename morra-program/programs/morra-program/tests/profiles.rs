@@ -0,0 +1,171 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{Instruction, InstructionError};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::{assert_game_error, profile_address, Harness, Player, BET};
+use morra_core::{update_ratings, Outcome, INITIAL_RATING};
+use morra_program::{GameError, GameStatus, PlayerProfile};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solana_transaction_error::TransactionError;
+
+#[tokio::test]
+async fn new_profile_starts_empty() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.pubkey();
+
+    let profile = harness.profile(creator).await;
+    assert_eq!(profile.player, creator);
+    assert_eq!(profile.games_played, 0);
+    assert_eq!(profile.total_wagered, 0);
+    assert_eq!(profile.net_winnings, 0);
+    assert_eq!(profile.current_streak, 0);
+    assert_eq!(profile.rating, INITIAL_RATING);
+}
+
+#[tokio::test]
+async fn joining_without_a_profile_fails() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+
+    let stranger = Keypair::new();
+    let err = harness
+        .join_game_as(&stranger, [0; 32])
+        .await
+        .expect_err("join should fail");
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                anchor_lang::error::ErrorCode::AccountNotInitialized.into()
+            ),
+        ),
+    );
+    assert!(harness.game_state().await.status == GameStatus::WaitingForOpponent);
+}
+
+#[tokio::test]
+async fn resolution_records_win_and_loss() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.play_to_resolution().await;
+
+    let winner = harness.profile(harness.creator.pubkey()).await;
+    assert_eq!(winner.games_played, 1);
+    assert_eq!(winner.wins, 1);
+    assert_eq!(winner.losses, 0);
+    assert_eq!(winner.total_wagered, BET);
+    assert_eq!(winner.net_winnings, BET as i64);
+    assert_eq!(winner.current_streak, 1);
+
+    let loser = harness.profile(harness.opponent.pubkey()).await;
+    assert_eq!(loser.games_played, 1);
+    assert_eq!(loser.wins, 0);
+    assert_eq!(loser.losses, 1);
+    assert_eq!(loser.total_wagered, BET);
+    assert_eq!(loser.net_winnings, -(BET as i64));
+    assert_eq!(loser.current_streak, -1);
+
+    // Casual games leave ratings alone.
+    assert_eq!(winner.rating, INITIAL_RATING);
+    assert_eq!(loser.rating, INITIAL_RATING);
+}
+
+#[tokio::test]
+async fn ranked_resolution_moves_ratings() {
+    let mut harness = Harness::new(Player::new(4, 6, 1), Player::new(4, 8, 2)).await;
+    harness.set_ranked(true);
+    harness.play_to_resolution().await;
+
+    assert!(harness.game_state().await.ranked);
+    let creator = harness.profile(harness.creator.pubkey()).await;
+    let opponent = harness.profile(harness.opponent.pubkey()).await;
+    let (creator_rating, opponent_rating) =
+        update_ratings(INITIAL_RATING, INITIAL_RATING, Outcome::OpponentWins);
+    assert_eq!(creator.rating, creator_rating);
+    assert_eq!(opponent.rating, opponent_rating);
+    assert!(opponent.rating > INITIAL_RATING);
+}
+
+#[tokio::test]
+async fn ranked_draw_between_equals_keeps_ratings() {
+    let mut harness = Harness::new(Player::new(2, 5, 1), Player::new(3, 5, 2)).await;
+    harness.set_ranked(true);
+    harness.play_to_resolution().await;
+
+    for player in [harness.creator.pubkey(), harness.opponent.pubkey()] {
+        assert_eq!(harness.profile(player).await.rating, INITIAL_RATING);
+    }
+}
+
+#[tokio::test]
+async fn resolution_records_draw_for_both() {
+    let mut harness = Harness::new(Player::new(2, 5, 1), Player::new(3, 5, 2)).await;
+    harness.play_to_resolution().await;
+
+    for player in [harness.creator.pubkey(), harness.opponent.pubkey()] {
+        let profile = harness.profile(player).await;
+        assert_eq!(profile.games_played, 1);
+        assert_eq!(profile.draws, 1);
+        assert_eq!(profile.net_winnings, 0);
+        assert_eq!(profile.current_streak, 0);
+    }
+}
+
+#[tokio::test]
+async fn resolving_with_swapped_profiles_fails() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+    harness.reveal_creator().await.unwrap();
+
+    let opponent = harness.opponent.keypair.insecure_clone();
+    let instruction = Instruction {
+        program_id: morra_program::ID,
+        accounts: morra_program::accounts::RevealMove {
+            game: harness.game,
+            player: opponent.pubkey(),
+            creator_profile: profile_address(&opponent.pubkey()),
+            opponent_profile: profile_address(&harness.creator.pubkey()),
+            session: None,
+            session_scope: None,
+        }
+        .to_account_metas(None),
+        data: morra_program::instruction::RevealMove {
+            card: harness.opponent.card,
+            prediction: harness.opponent.prediction,
+            salt: harness.opponent.salt,
+        }
+        .data(),
+    };
+
+    assert_game_error(
+        harness.process(instruction, &[&opponent]).await,
+        GameError::InvalidProfile,
+    );
+    assert!(harness.game_state().await.status == GameStatus::WaitingForReveal);
+}
+
+#[test]
+fn streak_tracks_the_current_run() {
+    let mut profile = PlayerProfile::new(Pubkey::new_unique(), 0);
+
+    profile.record_win(BET);
+    profile.record_win(BET);
+    assert_eq!(profile.current_streak, 2);
+
+    profile.record_loss(BET);
+    assert_eq!(profile.current_streak, -1);
+    profile.record_forfeit(BET);
+    assert_eq!(profile.current_streak, -2);
+
+    profile.record_draw(BET);
+    assert_eq!(profile.current_streak, 0);
+
+    assert_eq!(profile.games_played, 5);
+    assert_eq!((profile.wins, profile.losses, profile.draws), (2, 2, 1));
+    assert_eq!(profile.forfeits, 1);
+    assert_eq!(profile.total_wagered, 5 * BET);
+    assert_eq!(profile.net_winnings, 0);
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::Pubkey;
+use morra_program::{Game, GameStatus, PlayerMove};
+use proptest::prelude::*;
+
+fn game_with_moves(
+    creator_move: Option<(u8, u8)>,
+    opponent_move: Option<(u8, u8)>,
+) -> Game {
+    let player_move = |revealed: Option<(u8, u8)>| PlayerMove {
+        committed_hash: Some([0; 32]),
+        card: revealed.map(|(card, _)| card),
+        prediction: revealed.map(|(_, prediction)| prediction),
+    };
+    Game {
+        creator: Pubkey::new_unique(),
+        game_id: 0,
+        bump: 0,
+        opponent: Pubkey::new_unique(),
+        bet_amount: 1_000_000_000,
+        ranked: false,
+        lobby_page: 0,
+        status: GameStatus::WaitingForReveal,
+        creator_move: player_move(creator_move),
+        opponent_move: player_move(opponent_move),
+        winner: None,
+        created_at: 0,
+        last_action_at: 0,
+        creator_sponsor: None,
+        opponent_sponsor: None,
+        queue: None,
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(4096))]
+
+    #[test]
+    fn resolution_has_at_most_one_winner(
+        creator_move in any::<(u8, u8)>(),
+        opponent_move in any::<(u8, u8)>(),
+    ) {
+        let mut game = game_with_moves(Some(creator_move), Some(opponent_move));
+        game.resolve_game().unwrap();
+
+        let total = u16::from(creator_move.0) + u16::from(opponent_move.0);
+        let creator_correct = u16::from(creator_move.1) == total;
+        let opponent_correct = u16::from(opponent_move.1) == total;
+        match game.winner {
+            Some(winner) => {
+                prop_assert!(game.status == GameStatus::Completed);
+                if winner == game.creator {
+                    prop_assert!(creator_correct && !opponent_correct);
+                } else {
+                    prop_assert_eq!(winner, game.opponent);
+                    prop_assert!(opponent_correct && !creator_correct);
+                }
+            }
+            None => {
+                prop_assert!(game.status == GameStatus::Draw);
+                prop_assert_eq!(creator_correct, opponent_correct);
+            }
+        }
+    }
+
+    #[test]
+    fn resolution_is_symmetric(
+        creator_move in any::<(u8, u8)>(),
+        opponent_move in any::<(u8, u8)>(),
+    ) {
+        let mut game = game_with_moves(Some(creator_move), Some(opponent_move));
+        let mut swapped = game_with_moves(Some(opponent_move), Some(creator_move));
+        game.resolve_game().unwrap();
+        swapped.resolve_game().unwrap();
+
+        let winner_side = game.winner.map(|winner| winner == game.creator);
+        let swapped_winner_side = swapped.winner.map(|winner| winner == swapped.opponent);
+        prop_assert_eq!(winner_side, swapped_winner_side);
+    }
+
+    #[test]
+    fn resolution_without_both_reveals_fails(
+        creator_move in any::<(u8, u8)>(),
+        opponent_move in any::<(u8, u8)>(),
+        (creator_revealed, opponent_revealed) in prop_oneof![
+            Just((true, false)),
+            Just((false, true)),
+            Just((false, false)),
+        ],
+    ) {
+        let mut game = game_with_moves(
+            Some(creator_move).filter(|_| creator_revealed),
+            Some(opponent_move).filter(|_| opponent_revealed),
+        );
+
+        prop_assert!(game.resolve_game().is_err());
+        prop_assert!(game.status == GameStatus::WaitingForReveal);
+        prop_assert_eq!(game.winner, None);
+    }
+}
@@ -0,0 +1,227 @@
+mod common;
+
+use common::{
+    assert_game_error, commitment, session_address, tournament_address, Harness, Player,
+    SessionAccounts, BET, CREATOR_WINS,
+};
+use morra_core::Move;
+use morra_program::{GameError, GameStatus, TournamentStatus, MAX_SESSION_DURATION};
+use solana_keypair::Keypair;
+use solana_program_test::BanksClientError;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+const HOUR: i64 = 3_600;
+
+/// Authorize a fresh session key for the harness creator, scoped to
+/// `scope`, and return it.
+async fn creator_session(harness: &mut Harness, scope: Pubkey) -> Keypair {
+    let creator = harness.creator.keypair.insecure_clone();
+    let session_key = Keypair::new();
+    let expires_at = harness.now().await + HOUR;
+    harness
+        .create_session(&creator, session_key.pubkey(), scope, expires_at)
+        .await
+        .unwrap();
+    session_key
+}
+
+fn sign_with(harness: &mut Harness, session_key: &Keypair, scope: Option<Pubkey>) {
+    harness.session = Some(SessionAccounts {
+        session: session_address(&harness.creator.pubkey(), &session_key.pubkey()),
+        scope,
+    });
+}
+
+async fn reveal_creator_as(
+    harness: &mut Harness,
+    signer: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (card, prediction, salt) = (
+        harness.creator.card,
+        harness.creator.prediction,
+        harness.creator.salt,
+    );
+    harness.reveal_move(signer, card, prediction, salt).await
+}
+
+#[tokio::test]
+async fn session_key_reveals_for_its_player() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+    let game = harness.game;
+    let session_key = creator_session(&mut harness, game).await;
+
+    sign_with(&mut harness, &session_key, None);
+    reveal_creator_as(&mut harness, &session_key).await.unwrap();
+    harness.session = None;
+    harness.reveal_opponent().await.unwrap();
+
+    let game = harness.game_state().await;
+    assert!(game.status == GameStatus::Completed);
+    assert_eq!(game.winner, Some(harness.creator.pubkey()));
+
+    // Moving the winnings still takes the main key.
+    assert_game_error(
+        harness.claim_winnings(&session_key).await,
+        GameError::NotWinner,
+    );
+    let creator = harness.creator.keypair.insecure_clone();
+    harness.claim_winnings(&creator).await.unwrap();
+}
+
+#[tokio::test]
+async fn tournament_session_covers_its_match_games() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let [creator, opponent] = [&harness.creator, &harness.opponent]
+        .map(|player| player.keypair.insecure_clone());
+    let ends_at = harness.now().await + HOUR;
+    harness.create_tournament(0, BET, 2, ends_at).await.unwrap();
+    let tournament = tournament_address(&harness.context.payer.pubkey(), 0);
+    for player in [&creator, &opponent] {
+        harness.register_tournament(tournament, player).await.unwrap();
+    }
+    harness.start_tournament(tournament).await.unwrap();
+    let game = harness.spawn_match_game(tournament, 0).await.unwrap();
+    let session_key = creator_session(&mut harness, tournament).await;
+
+    let [(card, prediction), opponent_move] = CREATOR_WINS;
+    let creator_commitment = commitment(
+        &creator.pubkey(),
+        &game,
+        Move::new(card, prediction),
+        &[card; 32],
+    );
+    sign_with(&mut harness, &session_key, None);
+    assert_game_error(
+        harness.commit_move(game, &session_key, creator_commitment).await,
+        GameError::InvalidSession,
+    );
+    sign_with(&mut harness, &session_key, Some(tournament));
+    harness.commit_move(game, &session_key, creator_commitment).await.unwrap();
+
+    harness.session = None;
+    let (opponent_card, opponent_prediction) = opponent_move;
+    let opponent_commitment = commitment(
+        &opponent.pubkey(),
+        &game,
+        Move::new(opponent_card, opponent_prediction),
+        &[opponent_card; 32],
+    );
+    harness
+        .commit_move(game, &opponent, opponent_commitment)
+        .await
+        .unwrap();
+    harness
+        .reveal_move_in(
+            game,
+            creator.pubkey(),
+            opponent.pubkey(),
+            &opponent,
+            opponent_card,
+            opponent_prediction,
+            [opponent_card; 32],
+        )
+        .await
+        .unwrap();
+
+    sign_with(&mut harness, &session_key, Some(tournament));
+    harness
+        .reveal_move_in(
+            game,
+            creator.pubkey(),
+            opponent.pubkey(),
+            &session_key,
+            card,
+            prediction,
+            [card; 32],
+        )
+        .await
+        .unwrap();
+
+    harness.advance_tournament(tournament, game, 0).await.unwrap();
+    let state = harness.tournament(tournament).await;
+    assert!(state.status == TournamentStatus::Completed);
+    assert_eq!(state.matches[0].winner, Some(creator.pubkey()));
+}
+
+#[tokio::test]
+async fn sessions_only_work_in_scope_and_in_time() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+
+    let elsewhere = creator_session(&mut harness, Pubkey::new_unique()).await;
+    sign_with(&mut harness, &elsewhere, None);
+    assert_game_error(
+        reveal_creator_as(&mut harness, &elsewhere).await,
+        GameError::InvalidSession,
+    );
+
+    // Without its session account, a session key is just an outsider.
+    let game = harness.game;
+    let session_key = creator_session(&mut harness, game).await;
+    harness.session = None;
+    assert_game_error(
+        reveal_creator_as(&mut harness, &session_key).await,
+        GameError::InvalidPlayer,
+    );
+
+    // Another key can't borrow the creator's session account.
+    sign_with(&mut harness, &session_key, None);
+    assert!(reveal_creator_as(&mut harness, &Keypair::new()).await.is_err());
+
+    let now = harness.now().await;
+    harness.warp_to(now + HOUR).await;
+    assert_game_error(
+        reveal_creator_as(&mut harness, &session_key).await,
+        GameError::SessionExpired,
+    );
+    assert_eq!(harness.game_state().await.creator_move.card, None);
+}
+
+#[tokio::test]
+async fn revoked_session_stops_working() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+    let creator = harness.creator.keypair.insecure_clone();
+    let game = harness.game;
+    let session_key = creator_session(&mut harness, game).await;
+    let session = session_address(&creator.pubkey(), &session_key.pubkey());
+    let before = harness.balance(creator.pubkey()).await;
+    let rent = harness.balance(session).await;
+
+    harness
+        .revoke_session(&creator, session_key.pubkey())
+        .await
+        .unwrap();
+
+    assert_eq!(harness.balance(creator.pubkey()).await, before + rent);
+    sign_with(&mut harness, &session_key, None);
+    assert!(reveal_creator_as(&mut harness, &session_key).await.is_err());
+}
+
+#[tokio::test]
+async fn session_terms_are_validated() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    let now = harness.now().await;
+    let session_key = Keypair::new().pubkey();
+
+    for expires_at in [now, now + MAX_SESSION_DURATION + 1] {
+        assert_game_error(
+            harness
+                .create_session(&creator, session_key, Pubkey::new_unique(), expires_at)
+                .await,
+            GameError::InvalidSession,
+        );
+    }
+    assert_game_error(
+        harness
+            .create_session(&creator, creator.pubkey(), Pubkey::new_unique(), now + HOUR)
+            .await,
+        GameError::InvalidSession,
+    );
+}
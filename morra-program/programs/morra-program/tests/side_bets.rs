@@ -0,0 +1,223 @@
+mod common;
+
+use common::{
+    assert_game_error, escrow_address_for, side_bet_pool_address, Harness, Player, BET,
+    STARTING_BALANCE,
+};
+use morra_program::{
+    BetSide, GameError, SideBet, SideBetPoolStatus, MIN_SIDE_BET, MOVE_TIMEOUT,
+};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+/// A harness whose creator wins, with a pool open on its game and
+/// `count` funded spectators.
+async fn betting_harness(count: usize) -> (Harness, Vec<Keypair>) {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    harness.create_game().await.unwrap();
+    harness.create_side_bet_pool(harness.game).await.unwrap();
+    let mut spectators = Vec::new();
+    for _ in 0..count {
+        let spectator = Keypair::new();
+        harness.fund(spectator.pubkey(), STARTING_BALANCE).await;
+        spectators.push(spectator);
+    }
+    (harness, spectators)
+}
+
+fn vault(harness: &Harness) -> Pubkey {
+    escrow_address_for(b"side_bet", &side_bet_pool_address(&harness.game))
+}
+
+async fn bet_rent(harness: &mut Harness) -> u64 {
+    let rent = harness.context.banks_client.get_rent().await.unwrap();
+    rent.minimum_balance(8 + SideBet::LEN)
+}
+
+/// Claim for each spectator and check they got `amounts` back, on top of
+/// their bet account's rent.
+async fn claim_all(harness: &mut Harness, spectators: &[Keypair], amounts: &[u64]) {
+    let rent = bet_rent(harness).await;
+    for (spectator, &amount) in spectators.iter().zip(amounts) {
+        let before = harness.balance(spectator.pubkey()).await;
+        harness.claim_side_bet(harness.game, spectator).await.unwrap();
+        assert_eq!(harness.balance(spectator.pubkey()).await, before + amount + rent);
+    }
+    assert_eq!(harness.balance(vault(harness)).await, 0);
+}
+
+#[tokio::test]
+async fn backers_of_the_result_split_the_pool() {
+    let (mut harness, spectators) = betting_harness(3).await;
+    let game = harness.game;
+    let stakes = [
+        (BetSide::Creator, BET),
+        (BetSide::Creator, 3 * BET),
+        (BetSide::Opponent, 2 * BET),
+    ];
+    for (spectator, (side, amount)) in spectators.iter().zip(stakes) {
+        harness.place_side_bet(game, spectator, side, amount).await.unwrap();
+    }
+    assert_eq!(harness.balance(vault(&harness)).await, 6 * BET);
+    assert_eq!(harness.side_bet_pool(game).await.totals, [4 * BET, 2 * BET, 0]);
+
+    harness.join_game().await.unwrap();
+    harness.reveal_creator().await.unwrap();
+    harness.reveal_opponent().await.unwrap();
+
+    claim_all(&mut harness, &spectators, &[6 * BET / 4, 6 * BET * 3 / 4, 0]).await;
+    let pool = harness.side_bet_pool(game).await;
+    assert!(pool.status == SideBetPoolStatus::Settled);
+    assert_eq!(pool.winning_side, Some(BetSide::Creator));
+    assert_eq!(pool.paid_out, 6 * BET);
+
+    assert!(
+        harness.claim_side_bet(game, &spectators[0]).await.is_err(),
+        "a claimed bet is closed"
+    );
+}
+
+#[tokio::test]
+async fn unbacked_result_refunds_everyone() {
+    let (mut harness, spectators) = betting_harness(2).await;
+    let game = harness.game;
+    harness
+        .place_side_bet(game, &spectators[0], BetSide::Opponent, BET)
+        .await
+        .unwrap();
+    harness
+        .place_side_bet(game, &spectators[1], BetSide::Draw, 2 * BET)
+        .await
+        .unwrap();
+    harness.join_game().await.unwrap();
+    harness.reveal_creator().await.unwrap();
+    harness.reveal_opponent().await.unwrap();
+
+    claim_all(&mut harness, &spectators, &[BET, 2 * BET]).await;
+    assert!(harness.side_bet_pool(game).await.status == SideBetPoolStatus::Refunding);
+}
+
+#[tokio::test]
+async fn cancelled_game_refunds_side_bets() {
+    let (mut harness, spectators) = betting_harness(1).await;
+    let game = harness.game;
+    harness
+        .place_side_bet(game, &spectators[0], BetSide::Creator, BET)
+        .await
+        .unwrap();
+    assert_game_error(
+        harness.claim_side_bet(game, &spectators[0]).await,
+        GameError::InvalidGameState,
+    );
+
+    let creator = harness.creator.keypair.insecure_clone();
+    harness.cancel_game(&creator).await.unwrap();
+
+    claim_all(&mut harness, &spectators, &[BET]).await;
+}
+
+#[tokio::test]
+async fn stalled_game_refunds_side_bets() {
+    let (mut harness, spectators) = betting_harness(1).await;
+    let game = harness.game;
+    harness
+        .place_side_bet(game, &spectators[0], BetSide::Opponent, BET)
+        .await
+        .unwrap();
+    harness.join_game().await.unwrap();
+
+    // Neither player revealed, so the game's own timeout calls it off.
+    let last_action_at = harness.game_state().await.last_action_at;
+    harness.warp_to(last_action_at + MOVE_TIMEOUT - 1).await;
+    assert_game_error(
+        harness.claim_side_bet(game, &spectators[0]).await,
+        GameError::InvalidGameState,
+    );
+    harness.warp_to(last_action_at + MOVE_TIMEOUT).await;
+
+    claim_all(&mut harness, &spectators, &[BET]).await;
+}
+
+#[tokio::test]
+async fn timed_out_game_settles_as_its_forfeit_will() {
+    let (mut harness, spectators) = betting_harness(2).await;
+    let game = harness.game;
+    harness
+        .place_side_bet(game, &spectators[0], BetSide::Creator, BET)
+        .await
+        .unwrap();
+    harness
+        .place_side_bet(game, &spectators[1], BetSide::Opponent, BET)
+        .await
+        .unwrap();
+    harness.join_game().await.unwrap();
+    harness.reveal_creator().await.unwrap();
+
+    // The opponent never reveals. Claiming before anyone sends the forfeit
+    // still pays the creator's backers rather than refunding, and a late
+    // reveal can't turn the game against them.
+    let last_action_at = harness.game_state().await.last_action_at;
+    harness.warp_to(last_action_at + MOVE_TIMEOUT).await;
+    assert_game_error(harness.reveal_opponent().await, GameError::GameTimedOut);
+    claim_all(&mut harness, &spectators, &[2 * BET, 0]).await;
+    let pool = harness.side_bet_pool(game).await;
+    assert_eq!(pool.winning_side, Some(BetSide::Creator));
+}
+
+#[tokio::test]
+async fn betting_closes_once_the_opponent_joins() {
+    let (mut harness, spectators) = betting_harness(2).await;
+    let game = harness.game;
+    harness
+        .place_side_bet(game, &spectators[0], BetSide::Draw, BET)
+        .await
+        .unwrap();
+
+    harness.join_game().await.unwrap();
+
+    assert_game_error(
+        harness
+            .place_side_bet(game, &spectators[1], BetSide::Creator, BET)
+            .await,
+        GameError::BettingClosed,
+    );
+}
+
+#[tokio::test]
+async fn side_bets_are_for_spectators() {
+    let (mut harness, spectators) = betting_harness(1).await;
+    let game = harness.game;
+    let creator = harness.creator.keypair.insecure_clone();
+
+    assert_game_error(
+        harness.place_side_bet(game, &creator, BetSide::Creator, BET).await,
+        GameError::PlayerCannotBet,
+    );
+    assert_game_error(
+        harness
+            .place_side_bet(game, &spectators[0], BetSide::Creator, MIN_SIDE_BET - 1)
+            .await,
+        GameError::InvalidBetAmount,
+    );
+    assert_eq!(harness.side_bet_pool(game).await.totals, [0; 3]);
+}
+
+#[tokio::test]
+async fn bettors_cannot_take_the_open_seat() {
+    let (mut harness, spectators) = betting_harness(1).await;
+    let game = harness.game;
+    let spectator = &spectators[0];
+    harness.create_profile(spectator).await.unwrap();
+    harness
+        .place_side_bet(game, spectator, BetSide::Creator, BET)
+        .await
+        .unwrap();
+
+    // Seated as the opponent, they could throw the game their bet backs.
+    assert_game_error(
+        harness.join_game_as(spectator, [1; 32]).await,
+        GameError::BettorCannotJoin,
+    );
+    harness.join_game().await.unwrap();
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use common::{assert_game_error, Harness, Player, BET, STARTING_BALANCE};
+use morra_program::{Game, GameError, Sponsor};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+const REIMBURSEMENT: u64 = 5_000_000;
+
+async fn funded_relayer(harness: &mut Harness) -> Keypair {
+    let relayer = Keypair::new();
+    harness.fund(relayer.pubkey(), STARTING_BALANCE).await;
+    relayer
+}
+
+#[tokio::test]
+async fn sponsored_winner_repays_the_relayer() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let relayer = funded_relayer(&mut harness).await;
+    let creator = harness.creator.keypair.insecure_clone();
+
+    harness
+        .create_game_sponsored(&relayer, REIMBURSEMENT)
+        .await
+        .unwrap();
+
+    // The relayer covers the rent; the creator only stakes the bet.
+    let rent = harness.context.banks_client.get_rent().await.unwrap();
+    let game_rent = rent.minimum_balance(8 + Game::LEN);
+    assert_eq!(
+        harness.balance(relayer.pubkey()).await,
+        STARTING_BALANCE - game_rent
+    );
+    assert_eq!(harness.balance(creator.pubkey()).await, STARTING_BALANCE - BET);
+    assert_eq!(
+        harness.game_state().await.creator_sponsor,
+        Some(Sponsor {
+            relayer: relayer.pubkey(),
+            reimbursement: REIMBURSEMENT,
+        })
+    );
+
+    harness.join_game().await.unwrap();
+    harness.reveal_creator().await.unwrap();
+    harness.reveal_opponent().await.unwrap();
+
+    let before = harness.balance(relayer.pubkey()).await;
+    harness
+        .claim_winnings_with(&creator, Some(relayer.pubkey()))
+        .await
+        .unwrap();
+
+    assert_eq!(harness.balance(relayer.pubkey()).await, before + REIMBURSEMENT);
+    assert_eq!(
+        harness.balance(creator.pubkey()).await,
+        STARTING_BALANCE + BET - REIMBURSEMENT
+    );
+    assert_eq!(harness.balance(harness.game_account).await, 0);
+}
+
+#[tokio::test]
+async fn reimbursement_goes_only_to_the_recorded_relayer() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let relayer = funded_relayer(&mut harness).await;
+    let creator = harness.creator.keypair.insecure_clone();
+    harness
+        .create_game_sponsored(&relayer, REIMBURSEMENT)
+        .await
+        .unwrap();
+    harness.join_game().await.unwrap();
+    harness.reveal_creator().await.unwrap();
+    harness.reveal_opponent().await.unwrap();
+
+    assert_game_error(
+        harness.claim_winnings(&creator).await,
+        GameError::InvalidSponsor,
+    );
+    assert_game_error(
+        harness
+            .claim_winnings_with(&creator, Some(Pubkey::new_unique()))
+            .await,
+        GameError::InvalidSponsor,
+    );
+    harness
+        .claim_winnings_with(&creator, Some(relayer.pubkey()))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn sponsored_loser_owes_nothing() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let relayer = funded_relayer(&mut harness).await;
+    let (opponent, creator) = (
+        harness.opponent.keypair.insecure_clone(),
+        harness.creator.keypair.insecure_clone(),
+    );
+    harness.create_game().await.unwrap();
+    let commitment = harness.opponent.commitment(&harness.game);
+    harness
+        .join_game_sponsored(&opponent, commitment, &relayer, REIMBURSEMENT)
+        .await
+        .unwrap();
+    assert!(harness.game_state().await.opponent_sponsor.is_some());
+    harness.reveal_creator().await.unwrap();
+    harness.reveal_opponent().await.unwrap();
+
+    let relayer_before = harness.balance(relayer.pubkey()).await;
+    let creator_before = harness.balance(creator.pubkey()).await;
+    harness.claim_winnings(&creator).await.unwrap();
+
+    assert_eq!(harness.balance(relayer.pubkey()).await, relayer_before);
+    assert_eq!(harness.balance(creator.pubkey()).await, creator_before + 2 * BET);
+}
+
+#[tokio::test]
+async fn reimbursement_terms_are_validated() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let relayer = funded_relayer(&mut harness).await;
+    let creator = harness.creator.keypair.insecure_clone();
+
+    assert_game_error(
+        harness.create_game_sponsored(&relayer, BET + 1).await,
+        GameError::InvalidReimbursement,
+    );
+    assert_game_error(
+        harness.create_game_sponsored(&creator, REIMBURSEMENT).await,
+        GameError::InvalidReimbursement,
+    );
+
+    // Free sponsorship records nothing to repay.
+    harness.create_game_sponsored(&relayer, 0).await.unwrap();
+    assert_eq!(harness.game_state().await.creator_sponsor, None);
+}
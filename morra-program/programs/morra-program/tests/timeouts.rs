@@ -0,0 +1,188 @@
+mod common;
+
+use common::{
+    assert_game_error, config_address, escrow_address, game_address, Harness, Player, BET,
+    STARTING_BALANCE,
+};
+use morra_program::{GameError, GameStatus, JOIN_TIMEOUT, MOVE_TIMEOUT};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+const REWARD: u64 = 5_000_000;
+
+/// A harness and a funded cranker with no stake in its game.
+async fn crank_harness() -> (Harness, Keypair) {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let cranker = Keypair::new();
+    harness.fund(cranker.pubkey(), STARTING_BALANCE).await;
+    (harness, cranker)
+}
+
+#[tokio::test]
+async fn unjoined_game_expires() {
+    let (mut harness, cranker) = crank_harness().await;
+    harness.create_game().await.unwrap();
+    let game = harness.game;
+    let created_at = harness.game_state().await.created_at;
+
+    harness.warp_to(created_at + JOIN_TIMEOUT - 1).await;
+    assert_game_error(
+        harness.expire_game(game, &cranker, false).await,
+        GameError::TimeoutNotReached,
+    );
+    harness.warp_to(created_at + JOIN_TIMEOUT).await;
+    let before = harness.balance(harness.creator.pubkey()).await;
+    harness.expire_game(game, &cranker, false).await.unwrap();
+
+    assert!(harness.game_state().await.status == GameStatus::Cancelled);
+    assert_eq!(
+        harness.balance(harness.creator.pubkey()).await,
+        before + BET
+    );
+    assert_eq!(harness.balance(harness.game_account).await, 0);
+    let lobby = harness.lobby;
+    assert!(!harness.lobby_page(lobby).await.games.iter().any(|entry| entry.game == game));
+}
+
+#[tokio::test]
+async fn silent_player_forfeits_to_the_one_who_revealed() {
+    let (mut harness, cranker) = crank_harness().await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+    harness.reveal_creator().await.unwrap();
+    let game = harness.game;
+    let last_action_at = harness.game_state().await.last_action_at;
+
+    harness.warp_to(last_action_at + MOVE_TIMEOUT - 1).await;
+    assert_game_error(
+        harness.forfeit_game(game, &cranker, false).await,
+        GameError::TimeoutNotReached,
+    );
+    harness.warp_to(last_action_at + MOVE_TIMEOUT).await;
+    harness.forfeit_game(game, &cranker, false).await.unwrap();
+
+    let state = harness.game_state().await;
+    assert!(state.status == GameStatus::Completed);
+    assert_eq!(state.winner, Some(harness.creator.pubkey()));
+    let staller = harness.profile(harness.opponent.pubkey()).await;
+    assert_eq!((staller.losses, staller.forfeits), (1, 1));
+    assert_eq!(harness.profile(harness.creator.pubkey()).await.wins, 1);
+
+    // The winner collects the whole pot the usual way.
+    let creator = harness.creator.keypair.insecure_clone();
+    let before = harness.balance(creator.pubkey()).await;
+    harness.claim_winnings(&creator).await.unwrap();
+    assert_eq!(harness.balance(creator.pubkey()).await, before + 2 * BET);
+}
+
+#[tokio::test]
+async fn game_abandoned_by_both_refunds_both() {
+    let (mut harness, cranker) = crank_harness().await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+    let players = [harness.creator.pubkey(), harness.opponent.pubkey()];
+    let mut before = [0; 2];
+    for (balance, player) in before.iter_mut().zip(players) {
+        *balance = harness.balance(player).await;
+    }
+
+    let last_action_at = harness.game_state().await.last_action_at;
+    harness.warp_to(last_action_at + MOVE_TIMEOUT).await;
+    harness
+        .forfeit_game(harness.game, &cranker, false)
+        .await
+        .unwrap();
+
+    assert!(harness.game_state().await.status == GameStatus::Cancelled);
+    for (balance, player) in before.into_iter().zip(players) {
+        assert_eq!(harness.balance(player).await, balance + BET);
+        assert_eq!(harness.profile(player).await.games_played, 0);
+    }
+    assert_eq!(harness.balance(harness.game_account).await, 0);
+}
+
+#[tokio::test]
+async fn crank_reward_comes_from_the_config_pool() {
+    let (mut harness, cranker) = crank_harness().await;
+    harness.initialize_config(REWARD).await.unwrap();
+    let games = [0, 1].map(|id| game_address(&harness.creator.pubkey(), id));
+    for game in games {
+        harness.game = game;
+        harness.game_account = escrow_address(&game);
+        harness.create_game().await.unwrap();
+    }
+    let created_at = harness.game_at(games[1]).await.created_at;
+    harness.warp_to(created_at + JOIN_TIMEOUT).await;
+
+    // An empty pool pays nothing, but the timeout still goes through.
+    let before = harness.balance(cranker.pubkey()).await;
+    harness.expire_game(games[0], &cranker, true).await.unwrap();
+    assert_eq!(harness.balance(cranker.pubkey()).await, before);
+
+    let pool = harness.balance(config_address()).await;
+    harness.fund(config_address(), REWARD).await;
+    harness.expire_game(games[1], &cranker, true).await.unwrap();
+    assert_eq!(harness.balance(cranker.pubkey()).await, before + REWARD);
+    assert_eq!(harness.balance(config_address()).await, pool);
+}
+
+#[tokio::test]
+async fn only_the_authority_updates_the_config() {
+    let (mut harness, cranker) = crank_harness().await;
+    harness.initialize_config(REWARD).await.unwrap();
+
+    assert_game_error(
+        harness.update_config(&cranker, 2 * REWARD).await,
+        GameError::Unauthorized,
+    );
+    let authority = harness.context.payer.insecure_clone();
+    harness.update_config(&authority, 0).await.unwrap();
+    let config = harness.config().await;
+    assert_eq!(config.authority, authority.pubkey());
+    assert_eq!(config.crank_reward, 0);
+}
+
+#[tokio::test]
+async fn timeouts_leave_moving_and_finished_games_alone() {
+    let (mut harness, cranker) = crank_harness().await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+    let game = harness.game;
+    assert_game_error(
+        harness.expire_game(game, &cranker, false).await,
+        GameError::InvalidGameState,
+    );
+    harness.reveal_creator().await.unwrap();
+    harness.reveal_opponent().await.unwrap();
+
+    let created_at = harness.game_state().await.created_at;
+    harness.warp_to(created_at + JOIN_TIMEOUT).await;
+    assert_game_error(
+        harness.expire_game(game, &cranker, false).await,
+        GameError::InvalidGameState,
+    );
+    assert_game_error(
+        harness.forfeit_game(game, &cranker, false).await,
+        GameError::InvalidGameState,
+    );
+}
+
+#[tokio::test]
+async fn timed_out_games_take_no_more_moves() {
+    let (mut harness, _) = crank_harness().await;
+    harness.create_game().await.unwrap();
+    let created_at = harness.game_state().await.created_at;
+    harness.warp_to(created_at + JOIN_TIMEOUT).await;
+    assert_game_error(harness.join_game().await, GameError::GameTimedOut);
+
+    let (mut harness, _) = crank_harness().await;
+    harness.create_game().await.unwrap();
+    harness.join_game().await.unwrap();
+    harness.reveal_creator().await.unwrap();
+    let last_action_at = harness.game_state().await.last_action_at;
+    harness.warp_to(last_action_at + MOVE_TIMEOUT).await;
+
+    // A late reveal can't overturn the forfeit the crank is owed.
+    assert_game_error(harness.reveal_opponent().await, GameError::GameTimedOut);
+    assert!(harness.game_state().await.status == GameStatus::WaitingForReveal);
+}
@@ -0,0 +1,431 @@
+mod common;
+
+use common::{
+    assert_game_error, commitment, escrow_address_for, tournament_address, Harness, Player, BET,
+    CREATOR_WINS, DRAW, OPPONENT_WINS, STARTING_BALANCE,
+};
+use morra_core::Move;
+use morra_program::{
+    GameError, GameStatus, TournamentStatus, MAX_NO_SHOW_REMATCHES, MIN_BET, MOVE_TIMEOUT,
+};
+use solana_keypair::Keypair;
+use solana_program_test::BanksClientError;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+
+const ENTRY_FEE: u64 = BET;
+const REGISTRATION_WINDOW: i64 = 3_600;
+
+struct Cup {
+    harness: Harness,
+    tournament: Pubkey,
+    players: Vec<Keypair>,
+}
+
+impl Cup {
+    /// A tournament with `max_players` seats and `entrants` funded players
+    /// with profiles, the harness's two players first. Nobody is registered.
+    async fn new(max_players: u8, entrants: usize) -> Self {
+        let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+        let mut players = vec![
+            harness.creator.keypair.insecure_clone(),
+            harness.opponent.keypair.insecure_clone(),
+        ];
+        while players.len() < entrants {
+            let player = Keypair::new();
+            harness.fund(player.pubkey(), STARTING_BALANCE).await;
+            harness.create_profile(&player).await.unwrap();
+            players.push(player);
+        }
+        let ends_at = harness.now().await + REGISTRATION_WINDOW;
+        harness
+            .create_tournament(0, ENTRY_FEE, max_players, ends_at)
+            .await
+            .unwrap();
+        let tournament = tournament_address(&harness.context.payer.pubkey(), 0);
+        Self {
+            harness,
+            tournament,
+            players,
+        }
+    }
+
+    fn keypair(&self, player: Pubkey) -> Keypair {
+        self.players
+            .iter()
+            .find(|keypair| keypair.pubkey() == player)
+            .expect("player is an entrant")
+            .insecure_clone()
+    }
+
+    fn vault(&self) -> Pubkey {
+        escrow_address_for(b"tournament", &self.tournament)
+    }
+
+    async fn register(&mut self, player: usize) -> Result<(), BanksClientError> {
+        let keypair = self.players[player].insecure_clone();
+        self.harness.register_tournament(self.tournament, &keypair).await
+    }
+
+    async fn register_all(&mut self) {
+        for player in 0..self.players.len() {
+            self.register(player).await.unwrap();
+        }
+    }
+
+    /// Spawn, commit, reveal and advance one game of match `index` with the
+    /// seats playing `moves`, returning the game.
+    async fn play(&mut self, index: u8, moves: [(u8, u8); 2]) -> Pubkey {
+        let game = self
+            .harness
+            .spawn_match_game(self.tournament, index)
+            .await
+            .unwrap();
+        let state = self.harness.game_at(game).await;
+        let [creator, opponent] = [state.creator, state.opponent].map(|seat| self.keypair(seat));
+        self.harness
+            .play_seated_game(game, [&creator, &opponent], moves)
+            .await;
+        self.harness
+            .advance_tournament(self.tournament, game, index)
+            .await
+            .unwrap();
+        game
+    }
+
+    /// Spawn a game for match `index` that neither seat plays, let the
+    /// timeout cancel it, and advance it, returning the game.
+    async fn abandon(&mut self, index: u8) -> Pubkey {
+        let game = self
+            .harness
+            .spawn_match_game(self.tournament, index)
+            .await
+            .unwrap();
+        let last_action_at = self.harness.game_at(game).await.last_action_at;
+        self.harness.warp_to(last_action_at + MOVE_TIMEOUT).await;
+        let cranker = Keypair::new();
+        self.harness.fund(cranker.pubkey(), STARTING_BALANCE).await;
+        self.harness
+            .forfeit_game(game, &cranker, false)
+            .await
+            .unwrap();
+        assert!(self.harness.game_at(game).await.status == GameStatus::Cancelled);
+        self.harness
+            .advance_tournament(self.tournament, game, index)
+            .await
+            .unwrap();
+        game
+    }
+}
+
+#[tokio::test]
+async fn four_player_bracket_pays_by_placement() {
+    let mut cup = Cup::new(4, 4).await;
+    cup.register_all().await;
+    let players: Vec<Pubkey> = cup.players.iter().map(|keypair| keypair.pubkey()).collect();
+    assert_eq!(cup.harness.balance(cup.vault()).await, 4 * ENTRY_FEE);
+
+    // A full bracket can start before registration closes. Equal ratings
+    // seed by registration order, so seeds 1 and 4 meet, as do 2 and 3.
+    cup.harness.start_tournament(cup.tournament).await.unwrap();
+    let state = cup.harness.tournament(cup.tournament).await;
+    assert!(state.status == TournamentStatus::InProgress);
+    assert_eq!(state.matches.len(), 3);
+    assert_eq!(state.matches[0].players, [Some(players[0]), Some(players[3])]);
+    assert_eq!(state.matches[1].players, [Some(players[1]), Some(players[2])]);
+
+    cup.play(0, CREATOR_WINS).await;
+    cup.play(1, OPPONENT_WINS).await;
+    let state = cup.harness.tournament(cup.tournament).await;
+    assert_eq!(state.matches[2].players, [Some(players[0]), Some(players[2])]);
+    cup.play(2, CREATOR_WINS).await;
+
+    let state = cup.harness.tournament(cup.tournament).await;
+    assert!(state.status == TournamentStatus::Completed);
+    let pool = 4 * ENTRY_FEE;
+    let expected = [
+        (players[0], pool * 6_000 / 10_000),
+        (players[2], pool * 2_500 / 10_000),
+        (players[3], pool * 750 / 10_000),
+        (players[1], pool * 750 / 10_000),
+    ];
+    for (player, prize) in expected {
+        let keypair = cup.keypair(player);
+        let before = cup.harness.balance(player).await;
+        cup.harness
+            .claim_tournament_prize(cup.tournament, &keypair)
+            .await
+            .unwrap();
+        assert_eq!(cup.harness.balance(player).await, before + prize);
+    }
+    assert_eq!(cup.harness.balance(cup.vault()).await, 0);
+
+    let champion = cup.keypair(players[0]);
+    assert_game_error(
+        cup.harness.claim_tournament_prize(cup.tournament, &champion).await,
+        GameError::NoPrize,
+    );
+}
+
+#[tokio::test]
+async fn drawn_match_is_replayed() {
+    let mut cup = Cup::new(2, 2).await;
+    cup.register_all().await;
+    cup.harness.start_tournament(cup.tournament).await.unwrap();
+
+    let drawn = cup.play(0, DRAW).await;
+    assert!(cup.harness.game_at(drawn).await.status == GameStatus::Draw);
+    let state = cup.harness.tournament(cup.tournament).await;
+    assert!(state.status == TournamentStatus::InProgress);
+    assert_eq!(state.matches[0].game, None);
+    assert_eq!(state.matches[0].winner, None);
+    assert_game_error(
+        cup.harness.advance_tournament(cup.tournament, drawn, 0).await,
+        GameError::InvalidMatch,
+    );
+
+    let rematch = cup.play(0, OPPONENT_WINS).await;
+    assert_ne!(rematch, drawn);
+    let state = cup.harness.tournament(cup.tournament).await;
+    assert!(state.status == TournamentStatus::Completed);
+    let runner_up = cup.players[0].pubkey();
+    let champion = cup.players[1].pubkey();
+    assert_eq!(state.matches[0].winner, Some(champion));
+
+    // Without semifinals, their shares go to the champion.
+    let pool = 2 * ENTRY_FEE;
+    let expected = [(champion, pool * 7_500 / 10_000), (runner_up, pool * 2_500 / 10_000)];
+    for (player, prize) in expected {
+        let keypair = cup.keypair(player);
+        let before = cup.harness.balance(player).await;
+        cup.harness
+            .claim_tournament_prize(cup.tournament, &keypair)
+            .await
+            .unwrap();
+        assert_eq!(cup.harness.balance(player).await, before + prize);
+    }
+    assert_eq!(cup.harness.balance(cup.vault()).await, 0);
+}
+
+#[tokio::test]
+async fn cancelled_match_is_replayed() {
+    let mut cup = Cup::new(2, 2).await;
+    cup.register_all().await;
+    cup.harness.start_tournament(cup.tournament).await.unwrap();
+
+    // Neither player moves, so the timeout cancels the match game.
+    let abandoned = cup.abandon(0).await;
+    let state = cup.harness.tournament(cup.tournament).await;
+    assert_eq!((state.matches[0].game, state.matches[0].no_shows), (None, 1));
+
+    let rematch = cup.play(0, CREATOR_WINS).await;
+    assert_ne!(rematch, abandoned);
+    let state = cup.harness.tournament(cup.tournament).await;
+    assert!(state.status == TournamentStatus::Completed);
+    assert_eq!(state.matches[0].winner, Some(cup.players[0].pubkey()));
+}
+
+#[tokio::test]
+async fn repeated_no_shows_advance_the_higher_seed() {
+    let mut cup = Cup::new(2, 2).await;
+    cup.register_all().await;
+    cup.harness.start_tournament(cup.tournament).await.unwrap();
+
+    for _ in 0..MAX_NO_SHOW_REMATCHES {
+        cup.abandon(0).await;
+        let state = cup.harness.tournament(cup.tournament).await;
+        assert!(state.status == TournamentStatus::InProgress);
+    }
+
+    // Out of rematches, the walkover goes to the top seed.
+    cup.abandon(0).await;
+    let state = cup.harness.tournament(cup.tournament).await;
+    assert!(state.status == TournamentStatus::Completed);
+    assert_eq!(state.matches[0].no_shows, MAX_NO_SHOW_REMATCHES);
+    assert_eq!(state.matches[0].winner, Some(state.players[0]));
+}
+
+#[tokio::test]
+async fn top_rated_seed_gets_the_bye() {
+    let mut cup = Cup::new(4, 3).await;
+    // A ranked win lifts the harness creator above the others.
+    cup.harness.set_ranked(true);
+    cup.harness.play_to_resolution().await;
+    let [creator, opponent, third] = [0, 1, 2].map(|player| cup.players[player].pubkey());
+    assert!(cup.harness.profile(creator).await.rating > cup.harness.profile(third).await.rating);
+
+    for player in [1, 2, 0] {
+        cup.register(player).await.unwrap();
+    }
+    let deadline = cup.harness.tournament(cup.tournament).await.registration_ends_at;
+    assert_game_error(
+        cup.harness.start_tournament(cup.tournament).await,
+        GameError::InvalidTournamentState,
+    );
+    cup.harness.warp_to(deadline).await;
+    cup.harness.start_tournament(cup.tournament).await.unwrap();
+
+    let state = cup.harness.tournament(cup.tournament).await;
+    assert_eq!(state.matches[0].players, [Some(creator), None]);
+    assert_eq!(state.matches[0].winner, Some(creator));
+    assert_eq!(state.matches[1].players, [Some(third), Some(opponent)]);
+    assert_eq!(state.matches[2].players, [Some(creator), None]);
+    assert_game_error(
+        cup.harness.spawn_match_game(cup.tournament, 0).await.map(drop),
+        GameError::InvalidMatch,
+    );
+
+    cup.play(1, CREATOR_WINS).await;
+    cup.play(2, OPPONENT_WINS).await;
+
+    // The bye's semifinal has no loser, so that share goes to the champion.
+    let pool = 3 * ENTRY_FEE;
+    let expected = [
+        (third, pool - pool * 2_500 / 10_000 - pool * 750 / 10_000),
+        (creator, pool * 2_500 / 10_000),
+        (opponent, pool * 750 / 10_000),
+    ];
+    for (player, prize) in expected {
+        let keypair = cup.keypair(player);
+        let before = cup.harness.balance(player).await;
+        cup.harness
+            .claim_tournament_prize(cup.tournament, &keypair)
+            .await
+            .unwrap();
+        assert_eq!(cup.harness.balance(player).await, before + prize);
+    }
+    assert_eq!(cup.harness.balance(cup.vault()).await, 0);
+}
+
+#[tokio::test]
+async fn tournament_config_is_validated() {
+    let mut harness = Harness::new(Player::new(3, 5, 1), Player::new(2, 7, 2)).await;
+    let now = harness.now().await;
+    let ends_at = now + REGISTRATION_WINDOW;
+
+    for max_players in [0, 1, 3, 32] {
+        assert_game_error(
+            harness.create_tournament(0, ENTRY_FEE, max_players, ends_at).await,
+            GameError::InvalidTournamentConfig,
+        );
+    }
+    assert_game_error(
+        harness.create_tournament(0, ENTRY_FEE, 4, now).await,
+        GameError::InvalidTournamentConfig,
+    );
+    assert_game_error(
+        harness.create_tournament(0, MIN_BET - 1, 4, ends_at).await,
+        GameError::InvalidBetAmount,
+    );
+}
+
+#[tokio::test]
+async fn registration_rules() {
+    let mut cup = Cup::new(2, 3).await;
+    cup.register(0).await.unwrap();
+    assert_game_error(cup.register(0).await, GameError::AlreadyRegistered);
+
+    // Only one entrant once registration closes: too few to start.
+    let deadline = cup.harness.tournament(cup.tournament).await.registration_ends_at;
+    cup.harness.warp_to(deadline).await;
+    assert_game_error(cup.register(1).await, GameError::RegistrationClosed);
+    assert_game_error(
+        cup.harness.start_tournament(cup.tournament).await,
+        GameError::NotEnoughPlayers,
+    );
+
+    let mut cup = Cup::new(2, 3).await;
+    cup.register(0).await.unwrap();
+    cup.register(1).await.unwrap();
+    assert_game_error(cup.register(2).await, GameError::TournamentFull);
+    cup.harness.start_tournament(cup.tournament).await.unwrap();
+
+    let player = cup.players[0].insecure_clone();
+    assert_game_error(
+        cup.harness.leave_tournament(cup.tournament, &player).await,
+        GameError::InvalidTournamentState,
+    );
+}
+
+#[tokio::test]
+async fn leaving_refunds_the_entry_fee() {
+    let mut cup = Cup::new(4, 2).await;
+    let player = cup.players[0].insecure_clone();
+    cup.register(0).await.unwrap();
+    assert_eq!(
+        cup.harness.balance(player.pubkey()).await,
+        STARTING_BALANCE - ENTRY_FEE
+    );
+
+    cup.harness
+        .leave_tournament(cup.tournament, &player)
+        .await
+        .unwrap();
+
+    assert_eq!(cup.harness.balance(player.pubkey()).await, STARTING_BALANCE);
+    assert_eq!(cup.harness.balance(cup.vault()).await, 0);
+    let state = cup.harness.tournament(cup.tournament).await;
+    assert!(state.players.is_empty());
+    assert_eq!(state.prize_pool, 0);
+    assert_game_error(
+        cup.harness.leave_tournament(cup.tournament, &player).await,
+        GameError::NotRegistered,
+    );
+}
+
+#[tokio::test]
+async fn match_games_wait_for_both_commitments() {
+    let mut cup = Cup::new(2, 2).await;
+    cup.register_all().await;
+    cup.harness.start_tournament(cup.tournament).await.unwrap();
+    let game = cup
+        .harness
+        .spawn_match_game(cup.tournament, 0)
+        .await
+        .unwrap();
+    assert_game_error(
+        cup.harness.spawn_match_game(cup.tournament, 0).await.map(drop),
+        GameError::InvalidMatch,
+    );
+
+    let state = cup.harness.game_at(game).await;
+    assert!(state.status == GameStatus::WaitingForCommits);
+    assert_eq!(state.bet_amount, 0);
+    let creator = cup.keypair(state.creator);
+    let (card, prediction) = CREATOR_WINS[0];
+    let commitment = commitment(
+        &creator.pubkey(),
+        &game,
+        Move::new(card, prediction),
+        &[card; 32],
+    );
+
+    assert_game_error(
+        cup.harness.commit_move(game, &Keypair::new(), commitment).await,
+        GameError::InvalidPlayer,
+    );
+    cup.harness.commit_move(game, &creator, commitment).await.unwrap();
+    assert_game_error(
+        cup.harness.commit_move(game, &creator, commitment).await,
+        GameError::AlreadyCommitted,
+    );
+    assert_game_error(
+        cup.harness
+            .reveal_move_in(
+                game,
+                state.creator,
+                state.opponent,
+                &creator,
+                card,
+                prediction,
+                [card; 32],
+            )
+            .await,
+        GameError::InvalidGameState,
+    );
+    assert_game_error(
+        cup.harness.advance_tournament(cup.tournament, game, 0).await,
+        GameError::InvalidGameState,
+    );
+    assert!(cup.harness.game_at(game).await.status == GameStatus::WaitingForCommits);
+}
@@ -0,0 +1,161 @@
+use anchor_lang::error::Error;
+use anchor_lang::prelude::Pubkey;
+use morra_program::{Game, GameError, GameStatus, HouseGame, HouseGameStatus, PlayerMove};
+
+/// The legal lifecycle, spelled out independently of the shared table.
+const LEGAL: [(GameStatus, GameStatus); 10] = [
+    (GameStatus::WaitingForOpponent, GameStatus::WaitingForReveal),
+    (GameStatus::WaitingForOpponent, GameStatus::Cancelled),
+    (GameStatus::WaitingForCommits, GameStatus::WaitingForReveal),
+    (GameStatus::WaitingForReveal, GameStatus::Completed),
+    (GameStatus::WaitingForReveal, GameStatus::Draw),
+    (GameStatus::WaitingForCommits, GameStatus::Completed),
+    (GameStatus::WaitingForReveal, GameStatus::Cancelled),
+    (GameStatus::WaitingForCommits, GameStatus::Cancelled),
+    (GameStatus::Completed, GameStatus::Settled),
+    (GameStatus::Draw, GameStatus::Settled),
+];
+
+/// The house game lifecycle, likewise.
+const HOUSE_LEGAL: [(HouseGameStatus, HouseGameStatus); 5] = [
+    (HouseGameStatus::WaitingForHouse, HouseGameStatus::WaitingForPlayer),
+    (HouseGameStatus::WaitingForHouse, HouseGameStatus::Cancelled),
+    (HouseGameStatus::WaitingForPlayer, HouseGameStatus::WaitingForReveal),
+    (HouseGameStatus::WaitingForPlayer, HouseGameStatus::Settled),
+    (HouseGameStatus::WaitingForReveal, HouseGameStatus::Settled),
+];
+
+fn game_in(status: GameStatus) -> Game {
+    Game {
+        creator: Pubkey::new_unique(),
+        game_id: 0,
+        bump: 0,
+        opponent: Pubkey::new_unique(),
+        bet_amount: 1_000_000_000,
+        ranked: false,
+        lobby_page: 0,
+        status,
+        creator_move: PlayerMove::default(),
+        opponent_move: PlayerMove::default(),
+        winner: None,
+        created_at: 0,
+        last_action_at: 0,
+        creator_sponsor: None,
+        opponent_sponsor: None,
+        queue: None,
+    }
+}
+
+#[test]
+fn legal_edges_move_the_game() {
+    for (from, to) in LEGAL {
+        let mut game = game_in(from);
+        assert!(from.can_transition_to(to), "{from:?} -> {to:?}");
+        game.transition(from, to).unwrap();
+        assert_eq!(game.status, to);
+    }
+}
+
+#[test]
+fn every_illegal_edge_is_rejected() {
+    let mut rejected = 0;
+    for from in GameStatus::ALL {
+        for to in GameStatus::ALL {
+            if LEGAL.contains(&(from, to)) {
+                continue;
+            }
+            let mut game = game_in(from);
+            assert!(!from.can_transition_to(to), "{from:?} -> {to:?}");
+            assert_eq!(
+                game.transition(from, to),
+                Err(Error::from(GameError::InvalidTransition)),
+                "{from:?} -> {to:?}"
+            );
+            assert_eq!(game.status, from);
+            rejected += 1;
+        }
+    }
+    assert_eq!(rejected, GameStatus::ALL.len().pow(2) - LEGAL.len());
+}
+
+#[test]
+fn transition_from_the_wrong_status_is_rejected() {
+    for (from, to) in LEGAL {
+        for actual in GameStatus::ALL.into_iter().filter(|&status| status != from) {
+            let mut game = game_in(actual);
+            assert_eq!(
+                game.transition(from, to),
+                Err(Error::from(GameError::InvalidGameState)),
+                "{from:?} -> {to:?} while {actual:?}"
+            );
+            assert_eq!(game.status, actual);
+        }
+    }
+}
+
+#[test]
+fn forfeit_awards_whoever_moved_in_the_stalled_phase() {
+    let mut game = game_in(GameStatus::WaitingForCommits);
+    game.opponent_move = PlayerMove::committed([2; 32]);
+    game.forfeit().unwrap();
+    assert_eq!(game.status, GameStatus::Completed);
+    assert_eq!(game.winner, Some(game.opponent));
+
+    // Committing isn't enough once the game is waiting on reveals.
+    let mut game = game_in(GameStatus::WaitingForReveal);
+    game.creator_move = PlayerMove::committed([1; 32]);
+    game.opponent_move = PlayerMove::committed([2; 32]);
+    game.forfeit().unwrap();
+    assert_eq!(game.status, GameStatus::Cancelled);
+    assert_eq!(game.winner, None);
+
+    for status in [GameStatus::WaitingForOpponent, GameStatus::Draw] {
+        assert_eq!(
+            game_in(status).forfeit(),
+            Err(Error::from(GameError::InvalidGameState))
+        );
+    }
+}
+
+fn house_game_in(status: HouseGameStatus) -> HouseGame {
+    HouseGame {
+        player: Pubkey::new_unique(),
+        game_id: 0,
+        bump: 0,
+        bet_amount: 1_000_000_000,
+        status,
+        player_move: PlayerMove::default(),
+        house_move: PlayerMove::default(),
+        outcome: None,
+        created_at: 0,
+        last_action_at: 0,
+        house_edge_bps: 0,
+    }
+}
+
+#[test]
+fn house_games_only_take_legal_edges() {
+    for from in HouseGameStatus::ALL {
+        for to in HouseGameStatus::ALL {
+            let mut game = house_game_in(from);
+            let result = game.transition(from, to);
+            if HOUSE_LEGAL.contains(&(from, to)) {
+                result.unwrap();
+                assert_eq!(game.status, to);
+            } else {
+                assert_eq!(
+                    result,
+                    Err(Error::from(GameError::InvalidTransition)),
+                    "{from:?} -> {to:?}"
+                );
+                assert_eq!(game.status, from);
+            }
+        }
+    }
+
+    let mut game = house_game_in(HouseGameStatus::WaitingForPlayer);
+    assert_eq!(
+        game.transition(HouseGameStatus::WaitingForHouse, HouseGameStatus::WaitingForPlayer),
+        Err(Error::from(GameError::InvalidGameState))
+    );
+}